@@ -6,7 +6,14 @@ use serde_derive::{Deserialize};
 use std::io::{self, Write};
 use std::collections::HashMap;
 
-pub async fn twitter(mut config: config::Twitter) {
+fn auth_error<T: Into<Box<dyn std::error::Error + Send + Sync>>>(text: T) -> io::Error {
+    io::Error::new(io::ErrorKind::Other, text)
+}
+
+///Performs PIN based authorization, returning access token.
+///
+///Interactive dialogue is written to stderr, leaving stdout for result.
+pub async fn twitter(mut config: config::Twitter) -> io::Result<config::Token> {
     const REQUEST_TOKEN_URI: &str = "https://api.twitter.com/oauth/request_token";
     const ACCESS_TOKEN_URI: &str = "https://api.twitter.com/oauth/access_token";
 
@@ -40,8 +47,7 @@ pub async fn twitter(mut config: config::Twitter) {
     let req = match matsu!(req) {
         Ok(req) => req,
         Err(_) => {
-            eprintln!("Request timed out");
-            return;
+            return Err(auth_error("Request timed out"));
         }
     };
 
@@ -51,43 +57,39 @@ pub async fn twitter(mut config: config::Twitter) {
                 Ok(response) => match yukikaze::serde_urlencoded::from_str(&response) {
                     Ok(response) => response,
                     Err(error) => {
-                        eprintln!("Unable to parse response with request token. Error: {}", error);
-                        return;
+                        return Err(auth_error(format!("Unable to parse response with request token. Error: {}", error)));
                     }
                 },
                 Err(error) => {
-                    eprintln!("Failed to read response with requested token. Error: {}", error);
-                    return;
+                    return Err(auth_error(format!("Failed to read response with requested token. Error: {}", error)));
                 }
             },
             false => {
-                eprintln!("Request for token failed with {}", response.status());
-                return;
+                return Err(auth_error(format!("Request for token failed with {}", response.status())));
             }
         },
         Err(error) => {
-            eprintln!("Failed to request ouath token :( Error: {}", error);
-            return;
+            return Err(auth_error(format!("Failed to request ouath token :( Error: {}", error)));
         }
     };
 
-    println!("Please use following link to authroize fie:\nhttps://api.twitter.com/oauth/authorize?oauth_token={}", request_token.oauth_token);
-    println!("Once done please enter PIN...");
+    eprintln!("Please use following link to authroize fie:\nhttps://api.twitter.com/oauth/authorize?oauth_token={}", request_token.oauth_token);
+    eprintln!("Once done please enter PIN...");
     let pin = {
         let mut buffer = String::new();
         let stdin = io::stdin();
-        let stdout = io::stdout();
-        let mut stdout = stdout.lock();
+        let stderr = io::stderr();
+        let mut stderr = stderr.lock();
 
         loop {
             buffer.truncate(0);
 
-            let _ = stdout.write_all(b"Print: ");
-            let _ = stdout.flush();
+            let _ = stderr.write_all(b"Print: ");
+            let _ = stderr.flush();
             match stdin.read_line(&mut buffer) {
                 Ok(_) => (),
                 Err(_) => {
-                    let _ = stdout.write_all(b"Failed to read input. Try again...\n");
+                    let _ = stderr.write_all(b"Failed to read input. Try again...\n");
                     continue;
                 }
             }
@@ -96,7 +98,7 @@ pub async fn twitter(mut config: config::Twitter) {
             match pin.parse::<u32>() {
                 Ok(_) => break pin.to_owned(),
                 Err(_) => {
-                    let _ = stdout.write_all(b"Invalid PIN specified, should contain only digits. Try again...\n");
+                    let _ = stderr.write_all(b"Invalid PIN specified, should contain only digits. Try again...\n");
                     continue;
                 }
             }
@@ -120,8 +122,7 @@ pub async fn twitter(mut config: config::Twitter) {
     let req = match matsu!(req) {
         Ok(req) => req,
         Err(_) => {
-            eprintln!("Request timed out");
-            return;
+            return Err(auth_error("Request timed out"));
         }
     };
 
@@ -131,26 +132,263 @@ pub async fn twitter(mut config: config::Twitter) {
                 Ok(response) => match yukikaze::serde_urlencoded::from_str(&response) {
                     Ok(response) => response,
                     Err(error) => {
-                        eprintln!("Unable to parse response with access token. Error: {}", error);
-                        return;
+                        return Err(auth_error(format!("Unable to parse response with access token. Error: {}", error)));
                     }
                 },
                 Err(error) => {
-                    eprintln!("Failed to read response with access token. Error: {}", error);
-                    return;
+                    return Err(auth_error(format!("Failed to read response with access token. Error: {}", error)));
                 }
             },
             false => {
-                eprintln!("Request for access token failed with {}", response.status());
-                return;
+                return Err(auth_error(format!("Request for access token failed with {}", response.status())));
             }
         },
         Err(error) => {
-            eprintln!("Failed to request access token :( Error: {}", error);
-            return;
+            return Err(auth_error(format!("Failed to request access token :( Error: {}", error)));
+        }
+    };
+
+    Ok(config::Token {
+        key: access_token.oauth_token,
+        secret: access_token.oauth_token_secret,
+    })
+}
+
+///Redirect URL, used when app's configuration doesn't specify one.
+const DEFAULT_REDIRECT_URI: &str = "https://localhost/";
+
+///Extracts `code` and `state` parameters out of redirected URL, or returns input as it is when it is bare code.
+fn extract_code(input: &str) -> Option<(String, Option<String>)> {
+    let query = match input.find('?') {
+        Some(idx) => &input[idx + 1..],
+        None => return match input.contains('=') || input.contains('/') {
+            true => None,
+            false => Some((input.to_owned(), None)),
         }
     };
+    let query = query.split('#').next().unwrap_or(query);
+
+    let mut params: HashMap<String, String> = yukikaze::serde_urlencoded::from_str(query).ok()?;
+    let code = params.remove("code")?;
+    Some((code, params.remove("state")))
+}
+
+///Asks user to follow authorization link and to paste URL, to which browser was redirected.
+///
+///When `state` is sent with link, URL must contain the same one, so that code of other authorization is not accepted.
+///
+///Returns authorization code.
+fn read_code(url: &str, state: Option<&str>) -> String {
+    eprintln!("Please use following link to authroize fie:\n{}", url);
+    eprintln!("Once done please enter URL to which you were redirected...");
+
+    let mut buffer = String::new();
+    let stdin = io::stdin();
+    let stderr = io::stderr();
+    let mut stderr = stderr.lock();
+
+    loop {
+        buffer.truncate(0);
+
+        let _ = stderr.write_all(b"Print: ");
+        let _ = stderr.flush();
+        match stdin.read_line(&mut buffer) {
+            Ok(_) => (),
+            Err(_) => {
+                let _ = stderr.write_all(b"Failed to read input. Try again...\n");
+                continue;
+            }
+        }
+
+        let input = buffer.trim();
+        match extract_code(input) {
+            Some((code, url_state)) => match (state, url_state) {
+                //Bare code is trusted, as user copied it
+                (Some(state), url_state) if input.contains('?') && url_state.as_deref() != Some(state) => {
+                    let _ = stderr.write_all(b"URL's state doesn't match authorization request. Try again...\n");
+                    continue;
+                },
+                _ => break code,
+            },
+            None => {
+                let _ = stderr.write_all(b"URL doesn't contain code. Try again...\n");
+                continue;
+            }
+        }
+    }
+}
+
+///OAuth 2.0 tokens
+#[derive(Deserialize, Debug)]
+pub struct TokenRsp {
+    pub access_token: String,
+    #[serde(default)]
+    pub refresh_token: Option<String>,
+}
+
+///Reads access token out of response to token request.
+async fn access_token<E>(req: Result<yukikaze::client::RequestResult, E>) -> io::Result<String> {
+    matsu!(token(req)).map(|token| token.access_token)
+}
+
+///Reads tokens out of response to token request.
+async fn token<E>(req: Result<yukikaze::client::RequestResult, E>) -> io::Result<TokenRsp> {
+    let req = match req {
+        Ok(req) => req,
+        Err(_) => {
+            return Err(auth_error("Request timed out"));
+        }
+    };
+
+    match req {
+        Ok(mut response) => match response.is_success() {
+            true => match matsu!(response.json::<TokenRsp>()) {
+                Ok(response) => Ok(response),
+                Err(error) => Err(auth_error(format!("Unable to parse response with access token. Error: {}", error))),
+            },
+            false => Err(auth_error(format!("Request for access token failed with {}", response.status()))),
+        },
+        Err(error) => Err(auth_error(format!("Failed to request access token :( Error: {}", error))),
+    }
+}
+
+///Performs OAuth 2.0 authorization with LinkedIn, returning access token.
+///
+///Interactive dialogue is written to stderr, leaving stdout for result.
+pub async fn linkedin(config: config::LinkedIn) -> io::Result<String> {
+    const AUTHORIZE_URI: &str = "https://www.linkedin.com/oauth/v2/authorization";
+    const ACCESS_TOKEN_URI: &str = "https://www.linkedin.com/oauth/v2/accessToken";
+
+    if config.client_id.len() == 0 || config.client_secret.len() == 0 {
+        return Err(auth_error("LinkedIn's client_id and client_secret are required to authorize"));
+    }
+
+    //Company pages require organization's permission
+    let scope = match config.author.starts_with("urn:li:organization:") {
+        true => "w_organization_social",
+        false => "w_member_social",
+    };
+    let redirect_uri = config.redirect_uri.as_ref().map(|uri| uri.as_str()).unwrap_or(DEFAULT_REDIRECT_URI);
+
+    http::set_timeout(&Default::default());
+
+    let query = yukikaze::serde_urlencoded::to_string(&[("response_type", "code"), ("client_id", &config.client_id), ("redirect_uri", redirect_uri), ("scope", scope)]).expect("To serialize query");
+    let code = read_code(&format!("{}?{}", AUTHORIZE_URI, query), None);
 
-    println!("Received access token successfully.\nAdd following to your fie configuration file:");
-    println!("[api.twitter.access]\nkey = \"{}\"\nsecret = \"{}\"", access_token.oauth_token, access_token.oauth_token_secret);
+    let req = Request::post(ACCESS_TOKEN_URI).expect("To create request")
+                                             .form(&[("grant_type", "authorization_code"), ("code", &code), ("redirect_uri", redirect_uri), ("client_id", &config.client_id), ("client_secret", &config.client_secret)])
+                                             .expect("To serialize form params")
+                                             .global()
+                                             .send();
+
+    matsu!(access_token(matsu!(req)))
+}
+
+///Performs OAuth 2.0 authorization with Facebook, returning page's access token.
+///
+///User's token is exchanged for long-lived one, so that page's token doesn't expire.
+///
+///Interactive dialogue is written to stderr, leaving stdout for result.
+pub async fn facebook(config: config::Facebook) -> io::Result<String> {
+    const AUTHORIZE_URI: &str = "https://www.facebook.com/v18.0/dialog/oauth";
+    let access_token_uri = format!("{}/oauth/access_token", api::facebook::GRAPH_URL);
+
+    if config.app_id.len() == 0 || config.app_secret.len() == 0 || config.page_id.len() == 0 {
+        return Err(auth_error("Facebook's app_id, app_secret and page_id are required to authorize"));
+    }
+
+    let redirect_uri = config.redirect_uri.as_ref().map(|uri| uri.as_str()).unwrap_or(DEFAULT_REDIRECT_URI);
+
+    http::set_timeout(&Default::default());
+
+    let query = yukikaze::serde_urlencoded::to_string(&[("response_type", "code"), ("client_id", &config.app_id), ("redirect_uri", redirect_uri), ("scope", "pages_manage_posts,pages_read_engagement")]).expect("To serialize query");
+    let code = read_code(&format!("{}?{}", AUTHORIZE_URI, query), None);
+
+    let req = Request::get(&access_token_uri).expect("To create request")
+                                             .query(&[("client_id", config.app_id.as_str()), ("client_secret", &config.app_secret), ("redirect_uri", redirect_uri), ("code", &code)])
+                                             .empty()
+                                             .global()
+                                             .send();
+    let user_token = matsu!(access_token(matsu!(req)))?;
+
+    let req = Request::get(&access_token_uri).expect("To create request")
+                                             .query(&[("grant_type", "fb_exchange_token"), ("client_id", &config.app_id), ("client_secret", &config.app_secret), ("fb_exchange_token", &user_token)])
+                                             .empty()
+                                             .global()
+                                             .send();
+    let user_token = matsu!(access_token(matsu!(req)))?;
+
+    let req = Request::get(format!("{}/{}", api::facebook::GRAPH_URL, config.page_id)).map_err(|_| auth_error("Invalid page_id"))?
+                                                                                     .query(&[("fields", "access_token"), ("access_token", &user_token)])
+                                                                                     .empty()
+                                                                                     .global()
+                                                                                     .send();
+
+    matsu!(access_token(matsu!(req)))
+}
+
+///Performs OAuth 2.0 authorization with PKCE, returning user's tokens for Twitter API v2.
+///
+///Interactive dialogue is written to stderr, leaving stdout for result.
+pub async fn twitter_oauth2(config: config::TwitterOauth2) -> io::Result<TokenRsp> {
+    use data_encoding::BASE64URL_NOPAD;
+    use rand::{distributions, thread_rng, Rng};
+    use ring::digest;
+
+    const AUTHORIZE_URI: &str = "https://twitter.com/i/oauth2/authorize";
+
+    if config.client_id.len() == 0 {
+        return Err(auth_error("Twitter's oauth2.client_id is required to authorize"));
+    }
+
+    let redirect_uri = config.redirect_uri.as_ref().map(|uri| uri.as_str()).unwrap_or(DEFAULT_REDIRECT_URI);
+    let verifier: String = thread_rng().sample_iter(&distributions::Alphanumeric).take(64).collect();
+    let challenge = BASE64URL_NOPAD.encode(digest::digest(&digest::SHA256, verifier.as_bytes()).as_ref());
+    let state: String = thread_rng().sample_iter(&distributions::Alphanumeric).take(16).collect();
+
+    http::set_timeout(&Default::default());
+
+    let query = yukikaze::serde_urlencoded::to_string(&[("response_type", "code"), ("client_id", &config.client_id), ("redirect_uri", redirect_uri), ("scope", "tweet.read tweet.write users.read media.write offline.access"), ("state", &state), ("code_challenge", &challenge), ("code_challenge_method", "S256")]).expect("To serialize query");
+    let code = read_code(&format!("{}?{}", AUTHORIZE_URI, query), Some(&state));
+
+    matsu!(twitter_token(&config, &[("grant_type", "authorization_code"), ("code", &code), ("redirect_uri", redirect_uri), ("code_verifier", &verifier), ("client_id", &config.client_id)]))
+}
+
+///Exchanges refresh token for new tokens for Twitter API v2.
+pub async fn twitter_refresh(config: config::TwitterOauth2) -> io::Result<TokenRsp> {
+    if config.client_id.len() == 0 || config.refresh_token.len() == 0 {
+        return Err(auth_error("Twitter's oauth2.client_id and oauth2.refresh_token are required to refresh"));
+    }
+
+    http::set_timeout(&Default::default());
+
+    matsu!(twitter_token(&config, &[("grant_type", "refresh_token"), ("refresh_token", &config.refresh_token), ("client_id", &config.client_id)]))
+}
+
+async fn twitter_token(config: &config::TwitterOauth2, params: &[(&str, &str)]) -> io::Result<TokenRsp> {
+    const ACCESS_TOKEN_URI: &str = "https://api.twitter.com/2/oauth2/token";
+
+    let req = Request::post(ACCESS_TOKEN_URI).expect("To create request");
+    //Confidential clients must authenticate themselves
+    let req = match config.client_secret.len() {
+        0 => req,
+        _ => req.basic_auth(&config.client_id, Some(&config.client_secret)),
+    };
+    let req = req.form(&params).expect("To serialize form params").global().send();
+
+    matsu!(token(matsu!(req)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_extract_code() {
+        assert_eq!(extract_code("abc"), Some(("abc".to_owned(), None)));
+        assert_eq!(extract_code("https://localhost/?state=xyz&code=abc#_"), Some(("abc".to_owned(), Some("xyz".to_owned()))));
+        assert_eq!(extract_code("https://localhost/?code=abc"), Some(("abc".to_owned(), None)));
+        assert_eq!(extract_code("https://localhost/?error=access_denied"), None);
+        assert_eq!(extract_code("https://localhost/"), None);
+    }
 }
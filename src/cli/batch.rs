@@ -0,0 +1,315 @@
+use serde_derive::{Serialize, Deserialize};
+use yukikaze::serde_json;
+
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+use std::io::{self, BufRead};
+use std::str::FromStr;
+use std::{fs, iter};
+
+use crate::config;
+
+#[derive(Deserialize, Debug)]
+pub struct BatchPost {
+    #[serde(default)]
+    pub vars: HashMap<String, String>,
+    ///Number of seconds to wait before sending post. Overrides batch's delay.
+    #[serde(default)]
+    pub delay: Option<u64>,
+    #[serde(flatten)]
+    pub post: fie::data::Post,
+}
+
+#[derive(Deserialize, Debug)]
+struct BatchFile {
+    #[serde(default)]
+    vars: HashMap<String, String>,
+    #[serde(default)]
+    delay: u64,
+    post: Vec<BatchPost>,
+}
+
+///First line of JSON lines batch, containing its top level fields.
+#[derive(Deserialize, Debug, Default)]
+#[serde(deny_unknown_fields)]
+struct Header {
+    #[serde(default)]
+    vars: HashMap<String, String>,
+    #[serde(default)]
+    delay: u64,
+}
+
+impl Header {
+    ///Parses line as header, unless it is post, which always has `message`.
+    fn parse(line: &str) -> io::Result<Option<Self>> {
+        match serde_json::from_str::<serde_json::Value>(line) {
+            Ok(serde_json::Value::Object(ref object)) if !object.contains_key("message") => match serde_json::from_str(line) {
+                Ok(header) => Ok(Some(header)),
+                Err(error) => Err(io::Error::new(io::ErrorKind::InvalidData, format!("Invalid batch header: {}", error))),
+            },
+            //Invalid post is reported once it is read
+            _ => Ok(None),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Format {
+    Toml,
+    Json,
+    Yaml,
+    ///Newline-delimited JSON, one post per line.
+    JsonLines,
+}
+
+impl Format {
+    ///Determines format by file's extension, defaulting to TOML.
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("json") => Format::Json,
+            Some("yaml") | Some("yml") => Format::Yaml,
+            Some("jsonl") | Some("ndjson") => Format::JsonLines,
+            _ => Format::Toml,
+        }
+    }
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        match text {
+            "toml" => Ok(Format::Toml),
+            "json" => Ok(Format::Json),
+            "yaml" => Ok(Format::Yaml),
+            "jsonl" => Ok(Format::JsonLines),
+            other => Err(format!("Invalid batch format '{}'. Allowed values: toml, json, yaml, jsonl", other)),
+        }
+    }
+}
+
+///Posts of batch.
+///
+///JSON lines are read one by one as batch progresses, while other formats are loaded at once.
+pub enum Posts {
+    Loaded(std::vec::IntoIter<BatchPost>),
+    Lines(iter::Peekable<io::Lines<io::BufReader<fs::File>>>),
+}
+
+impl Iterator for Posts {
+    ///Post that cannot be parsed is returned as error of kind `InvalidData`.
+    type Item = io::Result<BatchPost>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            Posts::Loaded(posts) => posts.next().map(Ok),
+            Posts::Lines(lines) => loop {
+                let line = match lines.next()? {
+                    Ok(line) => line,
+                    Err(error) => return Some(Err(error)),
+                };
+
+                //Each line is post, so empty lines are not counted
+                if line.trim().len() == 0 {
+                    continue;
+                }
+
+                break Some(serde_json::from_str(&line).map_err(|error| io::Error::new(io::ErrorKind::InvalidData, format!("Invalid post: {}", error))));
+            },
+        }
+    }
+}
+
+pub struct Batch {
+    pub vars: HashMap<String, String>,
+    ///Number of seconds to wait between posts.
+    pub delay: u64,
+    pub posts: Posts,
+}
+
+impl From<BatchFile> for Batch {
+    fn from(file: BatchFile) -> Self {
+        Self {
+            vars: file.vars,
+            delay: file.delay,
+            posts: Posts::Loaded(file.post.into_iter()),
+        }
+    }
+}
+
+fn open_file(path: &Path) -> io::Result<io::BufReader<fs::File>> {
+    fs::File::open(path).map(io::BufReader::new).map_err(|error| io::Error::new(io::ErrorKind::Other, format!("{}: {}", path.display(), error)))
+}
+
+///Opens batch file, using format by file's extension unless it is specified.
+pub fn open_batch(path: &str, format: Option<Format>) -> io::Result<Batch> {
+    let path = Path::new(path);
+
+    let file: BatchFile = match format.unwrap_or_else(|| Format::from_path(path)) {
+        Format::Toml => config::load_from_file(path)?,
+        Format::Json => serde_json::from_reader(open_file(path)?).map_err(|error| io::Error::new(io::ErrorKind::Other, format!("Invalid batch: {}", error)))?,
+        Format::Yaml => serde_yaml::from_reader(open_file(path)?).map_err(|error| io::Error::new(io::ErrorKind::Other, format!("Invalid batch: {}", error)))?,
+        Format::JsonLines => {
+            let mut lines = open_file(path)?.lines().peekable();
+
+            while let Some(Ok(line)) = lines.peek() {
+                match line.trim().len() {
+                    0 => lines.next(),
+                    _ => break,
+                };
+            }
+
+            let header = match lines.peek() {
+                Some(Ok(line)) => Header::parse(line)?,
+                _ => None,
+            };
+            let header = match header {
+                Some(header) => {
+                    lines.next();
+                    header
+                },
+                None => Header::default(),
+            };
+
+            return Ok(Batch {
+                vars: header.vars,
+                delay: header.delay,
+                posts: Posts::Lines(lines),
+            })
+        },
+    };
+
+    Ok(file.into())
+}
+
+///Progress of batch, stored in file to allow resume.
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct State {
+    #[serde(skip)]
+    path: PathBuf,
+    ///Absolute path of batch file that progress belongs to
+    #[serde(default)]
+    batch: String,
+    ///Platforms that successfully received post, by post's index
+    #[serde(default)]
+    done: BTreeMap<String, Vec<String>>,
+}
+
+impl State {
+    ///Loads state of batch file from file, if it exists
+    ///
+    ///Fails if state belongs to other batch file, as posts are identified by index.
+    pub fn open(path: &str, batch: &str) -> io::Result<Self> {
+        let path = PathBuf::from(path);
+        let batch = fs::canonicalize(batch).map_err(|error| io::Error::new(io::ErrorKind::Other, format!("{}: {}", batch, error)))?;
+        let batch = batch.to_string_lossy().into_owned();
+
+        let mut state: Self = match path.exists() {
+            true => config::load_from_file(&path)?,
+            false => Self::default(),
+        };
+
+        if state.batch.len() == 0 {
+            state.batch = batch;
+        } else if state.batch != batch {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, format!("{}: State belongs to other batch '{}'", path.display(), state.batch)));
+        }
+
+        state.path = path;
+        Ok(state)
+    }
+
+    ///Returns platforms that already received post
+    pub fn done(&self, index: usize) -> &[String] {
+        match self.done.get(&index.to_string()) {
+            Some(done) => done.as_slice(),
+            None => &[],
+        }
+    }
+
+    ///Records platforms that received post, and stores state in file.
+    pub fn record(&mut self, index: usize, platforms: Vec<&str>) -> io::Result<()> {
+        if platforms.len() == 0 {
+            return Ok(());
+        }
+
+        let done = self.done.entry(index.to_string()).or_insert_with(Vec::new);
+        for platform in platforms {
+            if !done.iter().any(|old| old == platform) {
+                done.push(platform.to_owned());
+            }
+        }
+
+        config::save_to_file(&self.path, self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_determine_format_by_extension() {
+        assert_eq!(Format::from_path(Path::new("posts.json")), Format::Json);
+        assert_eq!(Format::from_path(Path::new("posts.yml")), Format::Yaml);
+        assert_eq!(Format::from_path(Path::new("posts.yaml")), Format::Yaml);
+        assert_eq!(Format::from_path(Path::new("posts.ndjson")), Format::JsonLines);
+        assert_eq!(Format::from_path(Path::new("posts.toml")), Format::Toml);
+        assert_eq!(Format::from_path(Path::new("posts")), Format::Toml);
+        assert!("xml".parse::<Format>().is_err());
+    }
+
+    #[test]
+    fn should_store_state() {
+        let path = std::env::temp_dir().join(format!("fie_batch_state_{}.toml", std::process::id()));
+        let path = path.to_str().expect("To have UTF-8 path");
+        let batch = std::env::temp_dir().join(format!("fie_batch_state_{}.jsonl", std::process::id()));
+        let other_batch = std::env::temp_dir().join(format!("fie_batch_state_other_{}.jsonl", std::process::id()));
+        fs::write(&batch, "").expect("To write batch");
+        fs::write(&other_batch, "").expect("To write batch");
+        let batch = batch.to_str().expect("To have UTF-8 path");
+        let other_batch = other_batch.to_str().expect("To have UTF-8 path");
+
+        let mut state = State::open(path, batch).expect("To open new state");
+        assert!(state.done(1).is_empty());
+
+        state.record(1, vec!["twitter"]).expect("To record state");
+        state.record(1, vec!["mastodon", "twitter"]).expect("To record state");
+        state.record(2, vec![]).expect("To skip empty state");
+
+        let state = State::open(path, batch).expect("To open stored state");
+        let other = State::open(path, other_batch);
+        let _ = fs::remove_file(path);
+        let _ = fs::remove_file(batch);
+        let _ = fs::remove_file(other_batch);
+
+        assert_eq!(state.done(1), ["twitter", "mastodon"]);
+        assert!(state.done(2).is_empty());
+        assert_eq!(other.expect_err("To reject other batch").kind(), io::ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    fn should_read_json_lines_header() {
+        let path = std::env::temp_dir().join(format!("fie_batch_{}.jsonl", std::process::id()));
+        fs::write(&path, "\n{\"vars\": {\"version\": \"1.0\"}, \"delay\": 60}\n{\"message\": \"{{version}}\", \"tags\": [], \"images\": []}\n\n{\"tags\": []}\n").expect("To write batch");
+
+        let batch = open_batch(path.to_str().expect("To have UTF-8 path"), None);
+        let _ = fs::remove_file(&path);
+        let batch = batch.expect("To open batch");
+
+        assert_eq!(batch.delay, 60);
+        assert_eq!(batch.vars.get("version").map(String::as_str), Some("1.0"));
+
+        let posts = batch.posts.collect::<Vec<_>>();
+        assert_eq!(posts.len(), 2);
+        assert_eq!(posts[0].as_ref().expect("To parse post").post.message, "{{version}}");
+        assert_eq!(posts[1].as_ref().expect_err("To fail post").kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn should_reject_invalid_json_lines_header() {
+        assert!(Header::parse("{\"message\": \"Hello\"}").expect("To skip post").is_none());
+        assert!(Header::parse("not json").expect("To skip invalid post").is_none());
+        assert_eq!(Header::parse("{\"delay\": 1, \"post\": []}").expect_err("To reject header").kind(), io::ErrorKind::InvalidData);
+    }
+}
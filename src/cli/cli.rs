@@ -3,30 +3,43 @@ use std::mem;
 use structopt::StructOpt;
 
 use fie::config::Platforms;
+use fie::data::Visibility;
+
+use crate::output::Format;
 
 #[derive(Debug, StructOpt)]
-#[structopt(name = "fie", setting(structopt::clap::AppSettings::ArgRequiredElseHelp), setting(structopt::clap::AppSettings::VersionlessSubcommands))]
+#[structopt(name = "fie", setting(structopt::clap::AppSettings::ArgRequiredElseHelp), setting(structopt::clap::AppSettings::VersionlessSubcommands), after_help = "Unless any platform is specified, platforms enabled in configuration are used.")]
 pub struct Args {
     #[structopt(flatten)]
     pub flags: Flags,
+    #[structopt(long = "output", global = true, default_value = "text", possible_values = &["text", "json"])]
+    ///Output format.
+    pub output: Format,
+    #[structopt(long = "strict", global = true)]
+    ///Treats failure of any platform as fatal, stopping at first one.
+    pub strict: bool,
     #[structopt(subcommand)]
     pub cmd: Command,
 }
 
 impl Args {
     #[inline]
-    pub fn new(platforms: &mut Platforms) -> Self {
-        let args = Self::from_args();
+    pub fn new() -> Self {
+        Self::from_args()
+    }
+
+    ///Overrides platforms of configuration with ones specified by user.
+    pub fn select_platforms(&self, platforms: &mut Platforms) {
+        let flags = &self.flags;
 
         //Unless user specifies manually, we use configuration defaults
-        if args.flags.twitter || args.flags.gab || args.flags.mastodon || args.flags.minds {
-            *platforms = unsafe { mem::transmute(args.flags) }
+        if flags.twitter || flags.gab || flags.mastodon || flags.minds || flags.bluesky || flags.pleroma || flags.misskey || flags.wordpress || flags.matrix || flags.telegram || flags.discord || flags.slack || flags.linkedin || flags.facebook {
+            *platforms = unsafe { mem::transmute(self.flags) }
         }
-
-        args
     }
 }
 
+//Platforms to use. Unless any is specified, platforms enabled in configuration are used.
 #[derive(Debug, Copy, Clone, StructOpt)]
 pub struct Flags {
     #[structopt(short = "t", long = "twitter")]
@@ -41,6 +54,36 @@ pub struct Flags {
     #[structopt(long = "minds")]
     ///Use minds. By default all social medias are used unless flag is specified.
     pub minds: bool,
+    #[structopt(long = "bluesky")]
+    ///Use bluesky.
+    pub bluesky: bool,
+    #[structopt(long = "pleroma")]
+    ///Use pleroma or akkoma.
+    pub pleroma: bool,
+    #[structopt(long = "misskey")]
+    ///Use misskey.
+    pub misskey: bool,
+    #[structopt(long = "wordpress")]
+    ///Use wordpress.
+    pub wordpress: bool,
+    #[structopt(long = "matrix")]
+    ///Use matrix.
+    pub matrix: bool,
+    #[structopt(long = "telegram")]
+    ///Use telegram.
+    pub telegram: bool,
+    #[structopt(long = "discord")]
+    ///Use discord.
+    pub discord: bool,
+    #[structopt(long = "slack")]
+    ///Use slack.
+    pub slack: bool,
+    #[structopt(long = "linkedin")]
+    ///Use linkedin.
+    pub linkedin: bool,
+    #[structopt(long = "facebook")]
+    ///Use facebook page.
+    pub facebook: bool,
 }
 
 #[derive(Debug, StructOpt)]
@@ -54,15 +97,44 @@ pub enum Command {
     #[structopt(name = "batch")]
     ///Load CLI arguments from file and runs it.
     Batch(Batch),
+    #[structopt(name = "feed")]
+    ///Posts new entries of RSS or Atom feed.
+    Feed(Feed),
     #[structopt(name = "auth")]
     ///Allows to perform authorization with social media.
     Auth(Auth),
+    #[structopt(name = "timeline")]
+    ///Prints newest posts of home timelines, merged across platforms.
+    ///
+    ///Minds is skipped, as its API has no documented way to read feeds.
+    Timeline(Timeline),
+    #[structopt(name = "mentions")]
+    ///Prints newest mentions, merged across platforms.
+    ///
+    ///Minds is skipped, as its API has no documented way to read notifications.
+    Mentions(Mentions),
+    #[structopt(name = "stats")]
+    ///Prints likes, reposts and replies of published post.
+    Stats(Stats),
+    #[structopt(name = "boost")]
+    ///Boosts, reposts or retweets status.
+    Boost(Status),
+    #[structopt(name = "like")]
+    ///Likes or favourites status.
+    Like(Status),
 }
 
 #[derive(Debug, StructOpt)]
 pub struct Post {
+    #[structopt(required_unless = "markdown")]
     ///Message content
-    pub message: String,
+    pub message: Option<String>,
+    #[structopt(long = "markdown", conflicts_with = "message")]
+    ///Markdown file to use as post. Front matter can set tags, images, flags and overrides.
+    pub markdown: Option<String>,
+    #[structopt(long = "title")]
+    ///Title of post. Used by WordPress, which otherwise takes first line of message.
+    pub title: Option<String>,
     #[structopt(short = "t", long = "tag")]
     ///Adds hashtag at the last line of post.
     pub tags: Vec<String>,
@@ -72,27 +144,226 @@ pub struct Post {
     #[structopt(short = "n", long = "nsfw")]
     ///Whether post is NSFW or not.
     pub nsfw: bool,
+    #[structopt(long = "dry-run")]
+    ///Prints what each platform would receive, without posting.
+    pub dry_run: bool,
+    #[structopt(long = "no-preview")]
+    ///Disables fetching of link preview. Used by Minds, Bluesky and Telegram.
+    pub no_preview: bool,
+    #[structopt(long = "cw")]
+    ///Content warning to hide post behind. Used by Mastodon, Gab, Pleroma and Misskey.
+    pub cw: Option<String>,
+    #[structopt(long = "visibility", possible_values = &["public", "unlisted", "private", "direct"])]
+    ///Who can see the post. [default: public]
+    pub visibility: Option<Visibility>,
+    #[structopt(long = "poll")]
+    ///Adds poll option. Used by Mastodon, Gab, Pleroma, Misskey and Twitter with API v2.
+    pub poll: Vec<String>,
+    #[structopt(long = "poll-duration", default_value = "86400")]
+    ///Number of seconds during which poll accepts votes.
+    pub poll_duration: u64,
+    #[structopt(long = "poll-multiple")]
+    ///Whether poll allows multiple choices.
+    pub poll_multiple: bool,
+    #[structopt(long = "quote")]
+    ///Tweet to quote, given as its ID or URL. Used by Twitter with API v2.
+    pub quote: Option<String>,
+    #[structopt(long = "override", parse(try_from_str = parse_override))]
+    ///Overrides post on single platform, given as platform.field=value. Field is message, tag or image.
+    ///Tags and images can be specified multiple times.
+    pub overrides: Vec<Override>,
 }
 
-impl Into<fie::data::Post> for Post {
-    fn into(self) -> fie::data::Post {
-        let Post { message, tags, images, nsfw } = self;
-
-        fie::data::Post {
-            message,
-            tags,
-            images,
-            flags: fie::data::PostFlags {
-                nsfw
-            }
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OverrideField {
+    Message,
+    Tag,
+    Image,
+}
+
+///Platform specific part of post, given in command line.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Override {
+    pub platform: String,
+    pub field: OverrideField,
+    pub value: String,
+}
+
+impl Override {
+    ///Sets value on overrides of platform. Tags and images are added to ones already present.
+    fn apply(self, post: &mut fie::data::Post) {
+        //Platform is validated on parsing
+        let overrides = post.overrides_mut(&self.platform).expect("Known platform");
+
+        match self.field {
+            OverrideField::Message => overrides.message = Some(self.value),
+            OverrideField::Tag => overrides.tags.get_or_insert_with(Vec::new).push(self.value),
+            OverrideField::Image => overrides.images.get_or_insert_with(Vec::new).push(self.value),
+        }
+    }
+}
+
+///Parses override in format `platform.field=value`.
+fn parse_override(text: &str) -> Result<Override, String> {
+    let (key, value) = crate::template::parse_var(text).map_err(|_| format!("Invalid override '{}'. Should be in format platform.field=value", text))?;
+
+    let mut key = key.splitn(2, '.');
+    let (platform, field) = match (key.next(), key.next()) {
+        (Some(platform), Some(field)) => (platform, field),
+        _ => return Err(format!("Invalid override '{}'. Should be in format platform.field=value", text)),
+    };
+
+    if fie::data::Post::default().overrides_mut(platform).is_none() {
+        return Err(format!("Invalid override '{}'. Unknown platform '{}'", text, platform));
+    }
+
+    let field = match field {
+        "message" => OverrideField::Message,
+        "tag" => OverrideField::Tag,
+        "image" => OverrideField::Image,
+        _ => return Err(format!("Invalid override '{}'. Field should be message, tag or image", text)),
+    };
+
+    Ok(Override {
+        platform: platform.to_owned(),
+        field,
+        value,
+    })
+}
+
+impl Post {
+    ///Applies arguments on top of post loaded from file.
+    ///
+    ///Tags and images are added, while other arguments replace file's values when specified.
+    pub fn apply(self, post: &mut fie::data::Post) {
+        let Post { message: _, markdown: _, title, tags, images, nsfw, dry_run: _, no_preview, cw, visibility, poll, poll_duration, poll_multiple, quote, overrides } = self;
+
+        if title.is_some() {
+            post.title = title;
+        }
+        post.tags.extend(tags);
+        post.images.extend(images);
+        post.flags.nsfw |= nsfw;
+        post.flags.no_preview |= no_preview;
+
+        if cw.is_some() {
+            post.flags.cw = cw;
+        }
+        if let Some(visibility) = visibility {
+            post.flags.visibility = visibility;
+        }
+        if poll.len() > 0 {
+            post.poll = Some(fie::data::Poll {
+                options: poll,
+                duration: poll_duration,
+                multiple: poll_multiple,
+            });
+        }
+        if quote.is_some() {
+            post.quote = quote;
+        }
+
+        for item in overrides {
+            item.apply(post);
         }
     }
 }
 
+impl Into<fie::data::Post> for Post {
+    fn into(mut self) -> fie::data::Post {
+        let mut post = fie::data::Post {
+            message: self.message.take().unwrap_or_default(),
+            ..Default::default()
+        };
+        self.apply(&mut post);
+        post
+    }
+}
+
 #[derive(Debug, StructOpt)]
 pub struct Batch {
-    ///TOML file that describes CLI arguments.
+    ///File that describes posts. Format is determined by extension: .toml, .json, .yaml, .jsonl
     pub file: String,
+    #[structopt(long = "format")]
+    ///Format of file, overriding extension. Allowed values: toml, json, yaml, jsonl
+    pub format: Option<crate::batch::Format>,
+    #[structopt(long = "var", parse(try_from_str = crate::template::parse_var))]
+    ///Sets template variable in format key=value. Overrides values from file.
+    pub vars: Vec<(String, String)>,
+    #[structopt(long = "dry-run")]
+    ///Prints what each platform would receive, without posting.
+    pub dry_run: bool,
+    #[structopt(long = "no-preview")]
+    ///Disables fetching of link preview for all posts. Used by Minds, Bluesky and Telegram.
+    pub no_preview: bool,
+    #[structopt(long = "stop-on-error")]
+    ///Stops at first post that failed on any platform.
+    pub stop_on_error: bool,
+    #[structopt(long = "from", default_value = "1")]
+    ///Number of post to start from.
+    pub from: usize,
+    #[structopt(long = "state")]
+    ///File to keep progress in. Platforms that already received post are skipped on next run.
+    pub state: Option<String>,
+}
+
+#[derive(Debug, StructOpt)]
+pub struct Feed {
+    ///HTTPS URL or file of RSS or Atom feed.
+    pub source: String,
+    #[structopt(long = "template", default_value = "{{title}} {{link}}")]
+    ///Template of post's message. Entry's variables: title, link, summary, id
+    pub template: String,
+    #[structopt(short = "t", long = "tag")]
+    ///Adds hashtag to each post, in addition to entry's categories.
+    pub tags: Vec<String>,
+    #[structopt(long = "no-categories")]
+    ///Disables use of entry's categories as hashtags.
+    pub no_categories: bool,
+    #[structopt(long = "state", default_value = "fie_feed.toml")]
+    ///File to keep progress in. Platforms that already received entry are skipped on next run.
+    pub state: String,
+    #[structopt(long = "limit")]
+    ///Maximum number of entries to post, starting from the oldest.
+    pub limit: Option<usize>,
+    #[structopt(long = "mark-posted")]
+    ///Records new entries as posted without posting them.
+    pub mark_posted: bool,
+    #[structopt(long = "dry-run")]
+    ///Prints what each platform would receive, without posting.
+    pub dry_run: bool,
+    #[structopt(long = "no-preview")]
+    ///Disables fetching of link preview. Used by Minds, Bluesky and Telegram.
+    pub no_preview: bool,
+}
+
+#[derive(Debug, StructOpt)]
+pub struct Timeline {
+    #[structopt(long = "own")]
+    ///Prints own posts instead of home timeline.
+    pub own: bool,
+    #[structopt(long = "limit", default_value = "20")]
+    ///Maximum number of posts to read from each platform.
+    pub limit: usize,
+}
+
+#[derive(Debug, StructOpt)]
+pub struct Mentions {
+    #[structopt(long = "limit", default_value = "20")]
+    ///Maximum number of mentions to read from each platform.
+    pub limit: usize,
+}
+
+#[derive(Debug, StructOpt)]
+pub struct Stats {
+    ///Identifier of post within history, as printed after posting.
+    pub id: usize,
+}
+
+#[derive(Debug, StructOpt)]
+pub struct Status {
+    ///URL of status. Fediverse's statuses are resolved through own instance.
+    pub url: String,
 }
 
 #[derive(Debug, StructOpt)]
@@ -106,5 +377,52 @@ pub enum Env {
 pub enum Auth {
     #[structopt(name = "twitter")]
     ///Performs authorization with twitter
-    Twitter,
+    Twitter {
+        #[structopt(long = "oauth2")]
+        ///Performs OAuth 2.0 authorization, which can be used with API v2.
+        oauth2: bool,
+        #[structopt(long = "refresh", requires = "oauth2")]
+        ///Exchanges configured refresh token for new OAuth 2.0 tokens.
+        refresh: bool,
+    },
+    #[structopt(name = "linkedin")]
+    ///Performs authorization with linkedin
+    LinkedIn,
+    #[structopt(name = "facebook")]
+    ///Performs authorization with facebook page
+    Facebook,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_parse_override() {
+        assert_eq!(parse_override("twitter.message=Hi=there"), Ok(Override {
+            platform: "twitter".to_owned(),
+            field: OverrideField::Message,
+            value: "Hi=there".to_owned(),
+        }));
+        assert_eq!(parse_override("slack.image=https://example.com/a.png").map(|item| item.field), Ok(OverrideField::Image));
+        assert!(parse_override("twitter=Hi").is_err());
+        assert!(parse_override("myspace.message=Hi").is_err());
+        assert!(parse_override("twitter.title=Hi").is_err());
+    }
+
+    #[test]
+    fn should_apply_overrides() {
+        let args = Args::from_iter(&["fie", "post", "Text", "--tag", "fie", "--override", "bluesky.message=Sky", "--override", "bluesky.tag=sky", "--override", "bluesky.tag=blue", "--override", "slack.image=https://example.com/a.png"]);
+        let post: fie::data::Post = match args.cmd {
+            Command::Post(post) => post.into(),
+            _ => unreachable!(),
+        };
+
+        assert_eq!(post.message, "Text");
+        assert_eq!(post.tags, ["fie"]);
+        assert_eq!(post.bluesky.message.as_deref(), Some("Sky"));
+        assert_eq!(post.bluesky.tags.as_deref(), Some(&["sky".to_owned(), "blue".to_owned()][..]));
+        assert_eq!(post.slack.images.as_deref(), Some(&["https://example.com/a.png".to_owned()][..]));
+        assert!(post.twitter.message.is_none());
+    }
 }
@@ -1,8 +1,9 @@
 use std::path::{Path, PathBuf};
-use std::io::{self, Read};
+use std::io::{self, Read, Write};
 use std::fs;
 use std::env;
 
+use serde::Serialize;
 use serde::de::{DeserializeOwned};
 
 pub const NAME: &str = "fie.toml";
@@ -14,6 +15,12 @@ pub fn load_from_file<T: DeserializeOwned>(path: &Path) -> io::Result<T> {
     toml::from_str(&buffer).map_err(|error| io::Error::new(io::ErrorKind::Other, format!("Invalid config: {}", error)))
 }
 
+pub fn save_to_file<T: Serialize>(path: &Path, value: &T) -> io::Result<()> {
+    let text = toml::to_string(value).map_err(|error| io::Error::new(io::ErrorKind::Other, format!("Unable to serialize {}: {}", path.display(), error)))?;
+    let mut file = fs::File::create(path).map_err(|error| io::Error::new(io::ErrorKind::Other, format!("{}: {}", path.display(), error)))?;
+    file.write_all(text.as_bytes()).map_err(|error| io::Error::new(io::ErrorKind::Other, format!("{}: {}", path.display(), error)))
+}
+
 pub trait FileSystemLoad: DeserializeOwned {
     fn path() -> io::Result<PathBuf> {
         match env::current_exe() {
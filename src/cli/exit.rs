@@ -0,0 +1,147 @@
+use std::io;
+
+///All platforms succeeded.
+pub const SUCCESS: i32 = 0;
+///All platforms failed or generic error happened.
+pub const FAILURE: i32 = 1;
+///Some platforms failed.
+pub const PARTIAL_FAILURE: i32 = 2;
+///Configuration is invalid or no platform can be used.
+pub const CONFIG_ERROR: i32 = 3;
+
+pub enum Failure {
+    Config(io::Error),
+    Other(io::Error),
+}
+
+impl Failure {
+    pub fn config<T: Into<Box<dyn std::error::Error + Send + Sync>>>(text: T) -> Self {
+        Failure::Config(io::Error::new(io::ErrorKind::Other, text))
+    }
+
+    pub fn code(&self) -> i32 {
+        match self {
+            Failure::Config(_) => CONFIG_ERROR,
+            Failure::Other(_) => FAILURE,
+        }
+    }
+
+    ///Returns short identifier of failure, used in JSON output.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Failure::Config(_) => "config",
+            Failure::Other(_) => "failure",
+        }
+    }
+
+    pub fn error(&self) -> &io::Error {
+        match self {
+            Failure::Config(error) => error,
+            Failure::Other(error) => error,
+        }
+    }
+}
+
+impl From<io::Error> for Failure {
+    fn from(error: io::Error) -> Self {
+        Failure::Other(error)
+    }
+}
+
+///Tracks results of platforms over the whole run.
+pub struct Outcome {
+    strict: bool,
+    succeeded: usize,
+    failed: usize,
+    misconfigured: usize,
+}
+
+impl Outcome {
+    pub fn new(strict: bool) -> Self {
+        Self {
+            strict,
+            succeeded: 0,
+            failed: 0,
+            misconfigured: 0,
+        }
+    }
+
+    pub fn success(&mut self) {
+        self.succeeded += 1;
+    }
+
+    pub fn failure(&mut self) {
+        self.failed += 1;
+    }
+
+    ///Records platform that is skipped, because it cannot be configured.
+    pub fn misconfiguration(&mut self) {
+        self.misconfigured += 1;
+    }
+
+    pub fn failures(&self) -> usize {
+        self.failed
+    }
+
+    pub fn is_strict(&self) -> bool {
+        self.strict
+    }
+
+    ///Returns whether processing should stop, which happens on first failure in strict mode.
+    pub fn is_fatal(&self) -> bool {
+        self.strict && self.failed > 0
+    }
+
+    pub fn code(&self) -> i32 {
+        match (self.succeeded, self.failed, self.misconfigured) {
+            (_, 0, 0) => SUCCESS,
+            (0, 0, _) => CONFIG_ERROR,
+            (0, _, _) => FAILURE,
+            _ if self.strict => FAILURE,
+            _ => PARTIAL_FAILURE,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn outcome(strict: bool, succeeded: usize, failed: usize) -> Outcome {
+        let mut outcome = Outcome::new(strict);
+        (0..succeeded).for_each(|_| outcome.success());
+        (0..failed).for_each(|_| outcome.failure());
+        outcome
+    }
+
+    #[test]
+    fn should_count_misconfigured_platforms() {
+        let mut partial = outcome(false, 1, 0);
+        partial.misconfiguration();
+        assert_eq!(partial.code(), PARTIAL_FAILURE);
+
+        let mut failed = outcome(false, 0, 1);
+        failed.misconfiguration();
+        assert_eq!(failed.code(), FAILURE);
+
+        let mut unused = outcome(false, 0, 0);
+        unused.misconfiguration();
+        assert_eq!(unused.code(), CONFIG_ERROR);
+    }
+
+    #[test]
+    fn should_return_exit_code() {
+        assert_eq!(outcome(false, 0, 0).code(), SUCCESS);
+        assert_eq!(outcome(false, 2, 0).code(), SUCCESS);
+        assert_eq!(outcome(false, 0, 2).code(), FAILURE);
+        assert_eq!(outcome(false, 1, 1).code(), PARTIAL_FAILURE);
+        assert_eq!(outcome(true, 1, 1).code(), FAILURE);
+    }
+
+    #[test]
+    fn should_be_fatal_only_in_strict_mode() {
+        assert!(!outcome(false, 0, 1).is_fatal());
+        assert!(!outcome(true, 1, 0).is_fatal());
+        assert!(outcome(true, 1, 1).is_fatal());
+    }
+}
@@ -0,0 +1,527 @@
+use serde_derive::{Serialize, Deserialize};
+use quick_xml::Reader;
+use quick_xml::events::{Event, BytesStart};
+use fie::api::http::{GlobalRequest, Request, matsu};
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::io::{self, Read};
+use std::fs;
+
+use crate::config;
+use crate::template::{self, Vars};
+
+///Entry of RSS or Atom feed.
+#[derive(Debug, Default)]
+pub struct Entry {
+    ///Guid of RSS item or id of Atom entry, falling back to link.
+    pub id: String,
+    pub title: String,
+    pub link: String,
+    ///Description or summary, without HTML markup.
+    pub summary: String,
+    pub categories: Vec<String>,
+    ///Time of last update or, without it, of publication as UNIX timestamp.
+    pub updated: Option<u64>,
+}
+
+impl Entry {
+    ///Creates post with message out of template and entry's categories as hashtags.
+    ///
+    ///Template can use variables `title`, `link`, `summary` and `id`.
+    pub fn into_post(self, template: &str, tags: &[String], use_categories: bool) -> io::Result<fie::data::Post> {
+        let title = match self.title.len() {
+            0 => None,
+            _ => Some(self.title.clone()),
+        };
+        let mut vars = Vars::new();
+        vars.insert("title".to_owned(), self.title);
+        vars.insert("link".to_owned(), self.link);
+        vars.insert("summary".to_owned(), self.summary);
+        vars.insert("id".to_owned(), self.id);
+
+        let mut post_tags = tags.to_vec();
+        if use_categories {
+            //Categories are free form, so ones that cannot be hashtag are skipped
+            for category in fie::data::normalize_hash_tags(self.categories) {
+                if fie::data::tags::is_word_hash_tag(&category) {
+                    post_tags.push(category);
+                }
+            }
+        }
+
+        Ok(fie::data::Post {
+            message: template::render(template, &vars)?,
+            title,
+            tags: post_tags,
+            ..Default::default()
+        })
+    }
+}
+
+#[derive(Copy, Clone)]
+enum Field {
+    Title,
+    Link,
+    Id,
+    Summary,
+    Content,
+    Category,
+    Updated,
+    Published,
+}
+
+impl Field {
+    fn from_name(name: &[u8]) -> Option<Self> {
+        match name {
+            b"title" => Some(Field::Title),
+            b"link" => Some(Field::Link),
+            b"guid" | b"id" => Some(Field::Id),
+            b"description" | b"summary" => Some(Field::Summary),
+            b"content" => Some(Field::Content),
+            b"category" => Some(Field::Category),
+            b"updated" | b"pubDate" | b"dc:date" => Some(Field::Updated),
+            b"published" => Some(Field::Published),
+            _ => None,
+        }
+    }
+
+    fn push(self, entry: &mut Entry, texts: &mut Texts, text: &str) {
+        match self {
+            Field::Title => entry.title.push_str(text),
+            Field::Link => entry.link.push_str(text),
+            Field::Id => entry.id.push_str(text),
+            Field::Summary => entry.summary.push_str(text),
+            Field::Content => texts.content.push_str(text),
+            Field::Updated => texts.updated.push_str(text),
+            Field::Published => texts.published.push_str(text),
+            Field::Category => if let Some(category) = entry.categories.last_mut() {
+                category.push_str(text);
+            },
+        }
+    }
+}
+
+///Text of fields, that are converted before being stored in entry.
+#[derive(Default)]
+struct Texts {
+    ///Content of Atom entry, used when there is no summary
+    content: String,
+    updated: String,
+    published: String,
+}
+
+///Whether text fields of entry contain escaped HTML.
+#[derive(Default)]
+struct Markup {
+    title: bool,
+    summary: bool,
+    content: bool,
+}
+
+impl Markup {
+    ///Records markup of field by Atom's `type` or, without it, by RSS, where only description is HTML.
+    fn set<B: io::BufRead>(&mut self, reader: &Reader<B>, tag: &BytesStart, field: Field, is_atom: bool) {
+        let is_html = match attribute(reader, tag, b"type").as_ref().map(|typ| typ.as_str()) {
+            Some("html") | Some("text/html") => true,
+            Some(_) => false,
+            None => !is_atom && tag.name() == b"description",
+        };
+
+        match field {
+            Field::Title => self.title = is_html,
+            Field::Summary => self.summary = is_html,
+            Field::Content => self.content = is_html,
+            _ => (),
+        }
+    }
+}
+
+fn invalid_feed<T: std::fmt::Display>(position: usize, error: T) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, format!("Invalid feed at position {}: {}", position, error))
+}
+
+fn attribute<B: io::BufRead>(reader: &Reader<B>, tag: &BytesStart, name: &[u8]) -> Option<String> {
+    tag.attributes().filter_map(|attr| attr.ok())
+                    .find(|attr| attr.key == name)
+                    .and_then(|attr| attr.unescape_and_decode_value(reader).ok())
+}
+
+///Returns link of Atom entry, which is only alternate link, or `None` for RSS's link.
+fn atom_link<B: io::BufRead>(reader: &Reader<B>, tag: &BytesStart) -> Option<String> {
+    match attribute(reader, tag, b"rel").as_ref().map(|rel| rel.as_str()) {
+        None | Some("alternate") => attribute(reader, tag, b"href"),
+        Some(_) => None,
+    }
+}
+
+///Collapses whitespace of plain text.
+fn collapse_whitespace(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+///Converts text of field to plain one, removing markup if it is HTML.
+fn field_text(text: &str, is_html: bool) -> String {
+    match is_html {
+        true => strip_html(text),
+        false => collapse_whitespace(text),
+    }
+}
+
+///Removes HTML tags, decodes entities and collapses whitespace.
+///
+///Text must be already decoded from XML, as HTML's own entities are decoded here.
+fn strip_html(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut is_tag = false;
+    let mut chars = text.chars().peekable();
+
+    while let Some(ch) = chars.next() {
+        match ch {
+            //Lone `<` is just text
+            '<' if !is_tag && chars.peek().map(|next| next.is_ascii_alphabetic() || *next == '/' || *next == '!').unwrap_or(false) => {
+                is_tag = true;
+                result.push(' ');
+            },
+            '>' if is_tag => is_tag = false,
+            ch if !is_tag => result.push(ch),
+            _ => (),
+        }
+    }
+
+    let result = match quick_xml::escape::unescape(result.as_bytes()) {
+        Ok(unescaped) => String::from_utf8_lossy(&unescaped).into_owned(),
+        Err(_) => result,
+    };
+
+    collapse_whitespace(&result)
+}
+
+///Parses RSS or Atom feed, returning entries in order of appearance.
+pub fn parse(text: &str) -> io::Result<Vec<Entry>> {
+    let mut reader = Reader::from_str(text);
+    let mut buffer = Vec::new();
+
+    let mut entries = Vec::new();
+    let mut entry: Option<Entry> = None;
+    let mut is_atom = false;
+    let mut markup = Markup::default();
+    let mut texts = Texts::default();
+    let mut field: Option<(Field, usize)> = None;
+    //Depth of Atom's `source`, which describes feed entry is taken from, rather than entry itself
+    let mut source: Option<usize> = None;
+    let mut depth = 0;
+
+    loop {
+        match reader.read_event(&mut buffer) {
+            Ok(Event::Start(ref tag)) => {
+                depth += 1;
+
+                match tag.name() {
+                    _ if source.is_some() => (),
+                    b"item" | b"entry" if entry.is_none() => {
+                        entry = Some(Entry::default());
+                        is_atom = tag.name() == b"entry";
+                        markup = Markup::default();
+                        texts = Texts::default();
+                    },
+                    b"source" if is_atom && entry.is_some() && field.is_none() => source = Some(depth),
+                    name => if let (Some(entry), None) = (entry.as_mut(), field) {
+                        field = Field::from_name(name).map(|field| (field, depth));
+
+                        match field {
+                            Some((Field::Link, _)) => if let Some(href) = atom_link(&reader, tag) {
+                                entry.link = href;
+                            },
+                            //RSS's category is text, while Atom's is attribute
+                            Some((Field::Category, _)) => entry.categories.push(attribute(&reader, tag, b"term").unwrap_or_default()),
+                            Some((field, _)) => markup.set(&reader, tag, field, is_atom),
+                            None => (),
+                        }
+                    }
+                }
+            },
+            Ok(Event::Empty(_)) if source.is_some() => (),
+            Ok(Event::Empty(ref tag)) => if let (Some(entry), None) = (entry.as_mut(), field) {
+                match Field::from_name(tag.name()) {
+                    Some(Field::Link) => if let Some(href) = atom_link(&reader, tag) {
+                        entry.link = href;
+                    },
+                    Some(Field::Category) => if let Some(term) = attribute(&reader, tag, b"term") {
+                        entry.categories.push(term);
+                    },
+                    _ => (),
+                }
+            },
+            //CDATA is kept escaped by reader, so it is decoded the same way as text
+            Ok(Event::Text(ref text)) | Ok(Event::CData(ref text)) => if let (Some(entry), Some((field, _))) = (entry.as_mut(), field) {
+                let text = text.unescape_and_decode(&reader).map_err(|error| invalid_feed(reader.buffer_position(), error))?;
+                field.push(entry, &mut texts, &text);
+            },
+            Ok(Event::End(ref tag)) => {
+                match (field, source) {
+                    (Some((_, field_depth)), _) if field_depth == depth => field = None,
+                    (_, Some(source_depth)) if source_depth == depth => source = None,
+                    _ => (),
+                }
+
+                depth -= 1;
+
+                match tag.name() {
+                    b"item" | b"entry" if field.is_none() => if let Some(mut entry) = entry.take() {
+                        entry.categories.retain(|category| category.trim().len() > 0);
+                        entry.title = field_text(&entry.title, markup.title);
+                        entry.link = entry.link.trim().to_owned();
+                        entry.id = entry.id.trim().to_owned();
+                        entry.summary = match entry.summary.trim().len() {
+                            0 => field_text(&texts.content, markup.content),
+                            _ => field_text(&entry.summary, markup.summary),
+                        };
+                        entry.updated = fie::data::time::parse(&texts.updated).or_else(|| fie::data::time::parse(&texts.published));
+
+                        if entry.id.len() == 0 {
+                            entry.id = entry.link.clone();
+                        }
+
+                        if entry.id.len() > 0 {
+                            entries.push(entry);
+                        }
+                    },
+                    _ => (),
+                }
+            },
+            Ok(Event::Eof) => break,
+            Ok(_) => (),
+            Err(error) => return Err(invalid_feed(reader.buffer_position(), error)),
+        }
+
+        buffer.clear();
+    }
+
+    Ok(entries)
+}
+
+///Orders entries from the oldest to the newest by their time.
+///
+///Entries without time are considered older than the rest, and keep reversed order of feed, which lists newest entries first.
+pub fn sort(entries: &mut Vec<Entry>) {
+    entries.reverse();
+    entries.sort_by_key(|entry| entry.updated);
+}
+
+///Loads feed from HTTPS URL or file.
+pub async fn load(source: &str) -> io::Result<String> {
+    if !source.starts_with("https://") && !source.starts_with("http://") {
+        let path = Path::new(source);
+        let mut file = fs::File::open(path).map_err(|error| io::Error::new(io::ErrorKind::Other, format!("{}: {}", path.display(), error)))?;
+        let mut buffer = String::new();
+        file.read_to_string(&mut buffer).map_err(|error| io::Error::new(io::ErrorKind::Other, format!("{}: {}", path.display(), error)))?;
+        return Ok(buffer);
+    }
+
+    let req = match Request::get(source) {
+        Ok(req) => req.empty().global().send_redirect(),
+        Err(error) => return Err(io::Error::new(io::ErrorKind::Other, format!("{}: {}", source, error))),
+    };
+
+    let mut resp = match matsu!(req) {
+        Ok(Ok(resp)) => resp,
+        Ok(Err(error)) => return Err(io::Error::new(io::ErrorKind::Other, format!("{}: {}", source, error))),
+        Err(_) => return Err(io::Error::new(io::ErrorKind::Other, format!("{}: Request timed out", source))),
+    };
+
+    if !resp.is_success() {
+        return Err(io::Error::new(io::ErrorKind::Other, format!("{}: Request failed with {}", source, resp.status())));
+    }
+
+    matsu!(resp.text()).map_err(|error| io::Error::new(io::ErrorKind::Other, format!("{}: {}", source, error)))
+}
+
+///Progress of feed, stored in file.
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct State {
+    #[serde(skip)]
+    path: PathBuf,
+    ///Ids of entries that are marked as posted on every platform
+    #[serde(default)]
+    posted: Vec<String>,
+    ///Platforms that successfully received entry, by entry's id
+    #[serde(default)]
+    done: BTreeMap<String, Vec<String>>,
+}
+
+impl State {
+    ///Loads state from file, if it exists
+    pub fn open(path: &str) -> io::Result<Self> {
+        let path = PathBuf::from(path);
+
+        let mut state: Self = match path.exists() {
+            true => config::load_from_file(&path)?,
+            false => Self::default(),
+        };
+
+        state.path = path;
+        Ok(state)
+    }
+
+    pub fn is_posted(&self, id: &str) -> bool {
+        self.posted.iter().any(|posted| posted == id)
+    }
+
+    ///Returns platforms that already received entry
+    pub fn done(&self, id: &str) -> &[String] {
+        match self.done.get(id) {
+            Some(done) => done.as_slice(),
+            None => &[],
+        }
+    }
+
+    ///Marks entry as posted on every platform, and stores state in file.
+    pub fn mark(&mut self, id: String) -> io::Result<()> {
+        if !self.is_posted(&id) {
+            self.posted.push(id);
+        }
+
+        config::save_to_file(&self.path, self)
+    }
+
+    ///Records platforms that received entry, and stores state in file.
+    pub fn record(&mut self, id: &str, platforms: Vec<&str>) -> io::Result<()> {
+        if platforms.len() == 0 {
+            return Ok(());
+        }
+
+        let done = self.done.entry(id.to_owned()).or_insert_with(Vec::new);
+        for platform in platforms {
+            if !done.iter().any(|old| old == platform) {
+                done.push(platform.to_owned());
+            }
+        }
+
+        config::save_to_file(&self.path, self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_parse_rss() {
+        let entries = parse(r#"<?xml version="1.0"?>
+<rss version="2.0"><channel>
+    <title>Blog</title>
+    <item>
+        <title>Tom &amp;amp; Jerry</title>
+        <link> https://example.com/1 </link>
+        <description>&lt;p&gt;Cats &amp;amp;amp; &lt;b&gt;mice&lt;/b&gt;&lt;/p&gt;</description>
+        <category>rust lang</category>
+        <category></category>
+    </item>
+    <item>
+        <guid>2</guid>
+        <description><![CDATA[<p>Escaped &amp;lt;tag&amp;gt;</p>]]></description>
+    </item>
+    <item>
+        <title>Without id and link</title>
+    </item>
+</channel></rss>"#).expect("To parse RSS");
+
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].title, "Tom &amp; Jerry");
+        assert_eq!(entries[0].link, "https://example.com/1");
+        assert_eq!(entries[0].id, "https://example.com/1");
+        assert_eq!(entries[0].summary, "Cats &amp; mice");
+        assert_eq!(entries[0].categories, ["rust lang"]);
+        assert_eq!(entries[1].id, "2");
+        assert_eq!(entries[1].summary, "Escaped &lt;tag&gt;");
+    }
+
+    #[test]
+    fn should_parse_atom() {
+        let entries = parse(r#"<?xml version="1.0"?>
+<feed xmlns="http://www.w3.org/2005/Atom">
+    <title>Blog</title>
+    <entry>
+        <id>urn:1</id>
+        <title type="html">&lt;i&gt;Hello&lt;/i&gt;</title>
+        <link rel="edit" href="https://example.com/edit/1"/>
+        <link href="https://example.com/1"/>
+        <category term="rust"/>
+        <content type="html">&lt;p&gt;First&lt;/p&gt;</content>
+        <source>
+            <id>urn:source</id>
+            <title>Other blog</title>
+            <link href="https://example.org"/>
+        </source>
+    </entry>
+    <entry>
+        <id>urn:2</id>
+        <title>1 &lt; 2</title>
+        <summary>Plain &amp;lt;text&amp;gt;</summary>
+    </entry>
+</feed>"#).expect("To parse Atom");
+
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].id, "urn:1");
+        assert_eq!(entries[0].title, "Hello");
+        assert_eq!(entries[0].link, "https://example.com/1");
+        assert_eq!(entries[0].summary, "First");
+        assert_eq!(entries[0].categories, ["rust"]);
+        assert_eq!(entries[1].title, "1 < 2");
+        assert_eq!(entries[1].summary, "Plain &lt;text&gt;");
+    }
+
+    #[test]
+    fn should_sort_entries_by_time() {
+        let mut entries = parse(r#"<?xml version="1.0"?>
+<feed xmlns="http://www.w3.org/2005/Atom">
+    <entry>
+        <id>urn:edited</id>
+        <published>2020-01-01T00:00:00Z</published>
+        <updated>2020-01-03T00:00:00Z</updated>
+    </entry>
+    <entry>
+        <id>urn:newest</id>
+        <published>2020-01-02T00:00:00Z</published>
+    </entry>
+    <entry>
+        <id>urn:undated</id>
+    </entry>
+</feed>"#).expect("To parse Atom");
+        entries.extend(parse(r#"<?xml version="1.0"?>
+<rss version="2.0"><channel>
+    <item>
+        <guid>oldest</guid>
+        <pubDate>Tue, 31 Dec 2019 00:00:00 GMT</pubDate>
+    </item>
+</channel></rss>"#).expect("To parse RSS"));
+
+        assert_eq!(entries[0].updated, Some(1578009600));
+        assert_eq!(entries[1].updated, Some(1577923200));
+
+        sort(&mut entries);
+        let ids = entries.iter().map(|entry| entry.id.as_str()).collect::<Vec<_>>();
+        assert_eq!(ids, ["urn:undated", "oldest", "urn:newest", "urn:edited"]);
+    }
+
+    #[test]
+    fn should_store_state() {
+        let path = std::env::temp_dir().join(format!("fie_feed_state_{}.toml", std::process::id()));
+        let path = path.to_str().expect("To have UTF-8 path");
+
+        let mut state = State::open(path).expect("To open new state");
+        assert!(state.done("urn:1").is_empty());
+
+        state.record("urn:1", vec!["twitter"]).expect("To record state");
+        state.record("urn:1", vec!["mastodon", "twitter"]).expect("To record state");
+        state.mark("urn:2".to_owned()).expect("To mark entry");
+
+        let state = State::open(path).expect("To open stored state");
+        let _ = fs::remove_file(path);
+
+        assert_eq!(state.done("urn:1"), ["twitter", "mastodon"]);
+        assert!(!state.is_posted("urn:1"));
+        assert!(state.is_posted("urn:2"));
+    }
+}
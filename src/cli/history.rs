@@ -0,0 +1,82 @@
+use serde_derive::{Serialize, Deserialize};
+
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::io;
+
+use crate::config::{self, FileSystemLoad};
+
+pub const NAME: &str = "fie_history.toml";
+
+///Post published by fie.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Entry {
+    ///Identifier within history, starting from 1
+    pub id: usize,
+    ///Time of posting
+    pub created_at: String,
+    ///Post's ID by platform
+    #[serde(default)]
+    pub ids: BTreeMap<String, String>,
+}
+
+impl Entry {
+    ///Returns IDs of platforms that support statistics.
+    pub fn post_ids(&self) -> fie::data::PostIds {
+        let get = |platform: &str| self.ids.get(platform).cloned();
+
+        fie::data::PostIds {
+            twitter: get("twitter"),
+            gab: get("gab"),
+            mastodon: get("mastodon"),
+            minds: get("minds"),
+            bluesky: get("bluesky"),
+            pleroma: get("pleroma"),
+            misskey: get("misskey"),
+        }
+    }
+}
+
+///Posts published by fie, stored next to configuration file.
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct History {
+    #[serde(skip)]
+    path: PathBuf,
+    #[serde(default)]
+    posts: Vec<Entry>,
+}
+
+impl History {
+    ///Loads history, if it exists
+    pub fn open() -> io::Result<Self> {
+        let mut path = fie::config::Config::path()?;
+        path.set_file_name(NAME);
+
+        let mut history: Self = match path.exists() {
+            true => config::load_from_file(&path)?,
+            false => Self::default(),
+        };
+
+        history.path = path;
+        Ok(history)
+    }
+
+    pub fn get(&self, id: usize) -> Option<&Entry> {
+        self.posts.iter().find(|entry| entry.id == id)
+    }
+
+    ///Records IDs of published post, and stores history in file.
+    ///
+    ///Returns identifier of post within history.
+    pub fn record(&mut self, ids: BTreeMap<String, String>) -> io::Result<usize> {
+        let id = self.posts.last().map(|entry| entry.id).unwrap_or(0) + 1;
+
+        self.posts.push(Entry {
+            id,
+            created_at: fie::data::time::now(),
+            ids,
+        });
+
+        config::save_to_file(&self.path, self).map(|_| id)
+    }
+}
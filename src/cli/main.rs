@@ -1,82 +1,305 @@
 #![cfg_attr(feature = "cargo-clippy", allow(clippy::style))]
 
-use serde_derive::Deserialize;
-
 mod config;
 mod cli;
 mod auth;
+mod template;
+mod output;
+mod exit;
+mod batch;
+mod feed;
+mod markdown;
+mod history;
 
 use fie::config::Config;
 use config::FileSystemLoad;
+use exit::{Failure, Outcome};
+use batch::{BatchPost, State};
+use history::History;
+use output::{Format, ErrorOutput, Accounts, PlatformResult, PostOutput, PreviewOutput, PreviewsOutput, EnvOutput, AuthOutput, StatusOutput, PlatformError, TimelineOutput, PlatformStats, StatsOutput, InteractionResult, InteractionOutput};
 
+use std::cell::{Cell, RefCell};
+use std::collections::BTreeMap;
 use std::io;
-use std::path::Path;
+use std::time::Duration;
+use std::thread;
 
-async fn create_api(config: Config) -> io::Result<fie::API> {
+///Creates API out of enabled platforms.
+///
+///When `offline`, no request is made, which makes API suitable only for preview.
+async fn create_api(config: Config, offline: bool, format: Format, outcome: &mut Outcome) -> Result<fie::API, Failure> {
     let mut any_enabled = false;
     let mut api = fie::API::new(config.settings);
+    api.set_people(config.people);
 
-    if config.platforms.gab {
-        if let Err(error) = api.configure_gab(config.api.gab) {
-            eprintln!("{}", error);
-        } else {
-            any_enabled = true
+    //Failure to configure platform is fatal only in strict mode.
+    //Otherwise platform is skipped, while still affecting exit code.
+    macro_rules! handle_configure {
+        ($platform:expr, $result:expr) => {
+            match $result {
+                Ok(_) => any_enabled = true,
+                Err(error) => {
+                    if outcome.is_strict() {
+                        return Err(Failure::config(error));
+                    }
+                    outcome.misconfiguration();
+                    match format {
+                        Format::Text => eprintln!("{}", error),
+                        Format::Json => output::print_json(&ErrorOutput::platform($platform, error.kind(), error.to_string())),
+                    }
+                }
+            }
         }
     }
 
+    if config.platforms.gab {
+        handle_configure!("gab", api.configure_gab(config.api.gab));
+    }
+
     if config.platforms.twitter {
-        if let Err(error) = api.configure_twitter(config.api.twitter) {
-            eprintln!("{}", error);
-        } else {
-            any_enabled = true
-        }
+        handle_configure!("twitter", api.configure_twitter(config.api.twitter));
     }
 
     if config.platforms.mastodon {
-        if let Err(error) = api.configure_mastodon(config.api.mastodon) {
-            eprintln!("{}", error);
-        } else {
+        handle_configure!("mastodon", api.configure_mastodon(config.api.mastodon));
+    }
+
+    if config.platforms.minds {
+        if offline {
+            api.configure_minds_offline(config.api.minds);
             any_enabled = true
+        } else {
+            handle_configure!("minds", yukikaze::matsu!(api.configure_minds(config.api.minds)));
         }
     }
 
-    if config.platforms.minds {
-        if let Err(error) = yukikaze::matsu!(api.configure_minds(config.api.minds)) {
-            eprintln!("{}", error);
+    if config.platforms.bluesky {
+        if offline {
+            handle_configure!("bluesky", api.configure_bluesky_offline(config.api.bluesky));
         } else {
-            any_enabled = true
+            handle_configure!("bluesky", yukikaze::matsu!(api.configure_bluesky(config.api.bluesky)));
+        }
+    }
+
+    if config.platforms.pleroma {
+        handle_configure!("pleroma", api.configure_pleroma(config.api.pleroma));
+    }
+
+    if config.platforms.misskey {
+        handle_configure!("misskey", api.configure_misskey(config.api.misskey));
+    }
+
+    if config.platforms.wordpress {
+        if config.api.wordpress.host.starts_with("http://") {
+            eprintln!("WordPress host uses plain HTTP, so username and password are sent unencrypted");
         }
+        handle_configure!("wordpress", api.configure_wordpress(config.api.wordpress));
+    }
+
+    if config.platforms.matrix {
+        handle_configure!("matrix", api.configure_matrix(config.api.matrix));
+    }
+
+    if config.platforms.telegram {
+        handle_configure!("telegram", api.configure_telegram(config.api.telegram));
+    }
+
+    if config.platforms.discord {
+        handle_configure!("discord", api.configure_discord(config.api.discord));
+    }
+
+    if config.platforms.slack {
+        handle_configure!("slack", api.configure_slack(config.api.slack));
+    }
+
+    if config.platforms.linkedin {
+        handle_configure!("linkedin", api.configure_linkedin(config.api.linkedin));
+    }
+
+    if config.platforms.facebook {
+        handle_configure!("facebook", api.configure_facebook(config.api.facebook));
     }
 
     match any_enabled {
         true => Ok(api),
-        false => Err(io::Error::new(io::ErrorKind::Other, "No API is enabled :(")),
+        false => Err(Failure::config("No API is enabled :(")),
     }
 }
 
-fn handle_post_result(result: fie::api::PostResult) {
-    let (twitter, gab, mastodon, minds) = result.into_parts();
+///Records IDs of published post in history, returning its identifier.
+///
+///Failure to record is not fatal as post is already published.
+fn record_history(history: &mut History, ids: BTreeMap<String, String>) -> Option<usize> {
+    if ids.len() == 0 {
+        return None;
+    }
 
-    let handle_inner = |prefix, result| if let Some(result) = result {
-        match result {
-            Ok(id) => println!("{}(Id={})", prefix, id),
-            Err(error) => eprintln!("{}", error)
+    match history.record(ids) {
+        Ok(id) => Some(id),
+        Err(error) => {
+            eprintln!("Unable to record post in history: {}", error);
+            None
         }
-    };
+    }
+}
+
+///Prints result, returning platforms that succeeded
+fn handle_post_result(result: fie::api::PostResult, accounts: &Accounts, format: Format, index: Option<usize>, history: &mut History, outcome: &mut Outcome) -> Vec<&'static str> {
+    let (twitter, gab, mastodon, minds, bluesky, pleroma, misskey, wordpress, matrix, telegram, discord, slack, linkedin, facebook) = result.into_parts();
+    let mut succeeded = vec![];
+    let mut ids = BTreeMap::new();
+
+    match format {
+        Format::Text => {
+            let mut handle_inner = |platform: &'static str, prefix, result: Option<Result<fie::data::PublishedPost, fie::api::ApiError>>| if let Some(result) = result {
+                match result {
+                    Ok(post) => {
+                        outcome.success();
+                        succeeded.push(platform);
+                        ids.insert(platform.to_owned(), post.id.to_string());
+                        println!("{}(Id={}) {}", prefix, post.id, post.url)
+                    },
+                    Err(error) => {
+                        outcome.failure();
+                        eprintln!("{}", error)
+                    }
+                }
+            };
+
+            handle_inner("twitter", "Twitter", twitter);
+            handle_inner("gab", "Gab", gab);
+            handle_inner("mastodon", "Mastodon", mastodon);
+            handle_inner("minds", "Minds", minds);
+            handle_inner("bluesky", "Bluesky", bluesky);
+            handle_inner("pleroma", "Pleroma", pleroma);
+            handle_inner("misskey", "Misskey", misskey);
+            handle_inner("wordpress", "WordPress", wordpress);
+            handle_inner("matrix", "Matrix", matrix);
+            handle_inner("telegram", "Telegram", telegram);
+            handle_inner("discord", "Discord", discord);
+            handle_inner("slack", "Slack", slack);
+            handle_inner("linkedin", "LinkedIn", linkedin);
+            handle_inner("facebook", "Facebook", facebook);
+
+            if let Some(id) = record_history(history, ids) {
+                println!("History(Id={})", id);
+            }
+        },
+        Format::Json => {
+            let mut results = vec![];
+            let mut handle_inner = |platform: &'static str, account: &Option<String>, result: Option<Result<fie::data::PublishedPost, fie::api::ApiError>>| if let Some(result) = result {
+                match result {
+                    Ok(ref post) => {
+                        outcome.success();
+                        succeeded.push(platform);
+                        ids.insert(platform.to_owned(), post.id.to_string());
+                    },
+                    Err(_) => outcome.failure(),
+                }
+                results.push(PlatformResult::new(platform, account.clone(), result));
+            };
 
-    handle_inner("Twitter", twitter);
-    handle_inner("Gab", gab);
-    handle_inner("Mastodon", mastodon);
-    handle_inner("Minds", minds);
+            handle_inner("twitter", &None, twitter);
+            handle_inner("gab", &None, gab);
+            handle_inner("mastodon", &accounts.mastodon, mastodon);
+            handle_inner("minds", &accounts.minds, minds);
+            handle_inner("bluesky", &accounts.bluesky, bluesky);
+            handle_inner("pleroma", &accounts.pleroma, pleroma);
+            handle_inner("misskey", &accounts.misskey, misskey);
+            handle_inner("wordpress", &accounts.wordpress, wordpress);
+            handle_inner("matrix", &accounts.matrix, matrix);
+            handle_inner("telegram", &accounts.telegram, telegram);
+            handle_inner("discord", &None, discord);
+            handle_inner("slack", &None, slack);
+            handle_inner("linkedin", &accounts.linkedin, linkedin);
+            handle_inner("facebook", &accounts.facebook, facebook);
+
+            let mut output = PostOutput::new(index, results);
+            output.history_id = record_history(history, ids);
+            output::print_json(&output);
+        }
+    }
+
+    succeeded
 }
 
-#[derive(Deserialize, Debug)]
-pub struct Batch {
-    post: Vec<fie::data::Post>,
+fn handle_post_error(error: &dyn std::fmt::Display, kind: &'static str, format: Format, index: Option<usize>, outcome: &mut Outcome) {
+    outcome.failure();
+
+    match format {
+        Format::Text => eprintln!("{}", error),
+        Format::Json => output::print_json(&PostOutput::error(index, kind, error.to_string())),
+    }
 }
 
-fn open_batch(path: &str) -> io::Result<Batch> {
-    config::load_from_file(Path::new(path))
+fn handle_preview(preview: fie::api::preview::Preview, format: Format, index: Option<usize>, outcome: &mut Outcome) {
+    match format {
+        Format::Text => {
+            let mut handle_inner = |prefix, preview: Option<fie::api::preview::PlatformPreview>| if let Some(preview) = preview {
+                match preview.max_len {
+                    Some(max_len) => println!("[{}] {}/{} characters", prefix, preview.len, max_len),
+                    None => println!("[{}] {} characters", prefix, preview.len),
+                }
+                println!("{}", preview.message);
+                if preview.tags.len() > 0 {
+                    println!("Tags: {}", preview.tags.join(", "));
+                }
+                if preview.images.len() > 0 {
+                    println!("Images: {}", preview.images.join(", "));
+                }
+                println!("Flags: nsfw={}, visibility={}, cw={}", preview.flags.nsfw, preview.flags.visibility, preview.flags.cw.as_ref().map(|cw| cw.as_str()).unwrap_or("none"));
+                match preview.error {
+                    Some(error) => {
+                        outcome.failure();
+                        eprintln!("{}", error);
+                    },
+                    None => outcome.success(),
+                }
+            };
+
+            handle_inner("Twitter", preview.twitter);
+            handle_inner("Gab", preview.gab);
+            handle_inner("Mastodon", preview.mastodon);
+            handle_inner("Minds", preview.minds);
+            handle_inner("Bluesky", preview.bluesky);
+            handle_inner("Pleroma", preview.pleroma);
+            handle_inner("Misskey", preview.misskey);
+            handle_inner("WordPress", preview.wordpress);
+            handle_inner("Matrix", preview.matrix);
+            handle_inner("Telegram", preview.telegram);
+            handle_inner("Discord", preview.discord);
+            handle_inner("Slack", preview.slack);
+            handle_inner("LinkedIn", preview.linkedin);
+            handle_inner("Facebook", preview.facebook);
+        },
+        Format::Json => {
+            let mut previews = vec![];
+            let mut handle_inner = |platform, preview: Option<fie::api::preview::PlatformPreview>| if let Some(preview) = preview {
+                match preview.error.is_some() {
+                    true => outcome.failure(),
+                    false => outcome.success(),
+                }
+                previews.push(PreviewOutput::new(platform, preview));
+            };
+
+            handle_inner("twitter", preview.twitter);
+            handle_inner("gab", preview.gab);
+            handle_inner("mastodon", preview.mastodon);
+            handle_inner("minds", preview.minds);
+            handle_inner("bluesky", preview.bluesky);
+            handle_inner("pleroma", preview.pleroma);
+            handle_inner("misskey", preview.misskey);
+            handle_inner("wordpress", preview.wordpress);
+            handle_inner("matrix", preview.matrix);
+            handle_inner("telegram", preview.telegram);
+            handle_inner("discord", preview.discord);
+            handle_inner("slack", preview.slack);
+            handle_inner("linkedin", preview.linkedin);
+            handle_inner("facebook", preview.facebook);
+
+            output::print_json(&PreviewsOutput { index, previews });
+        }
+    }
 }
 
 fn use_twitter_builtin_consumer(twitter: &mut fie::config::Twitter) {
@@ -96,64 +319,635 @@ fn use_twitter_builtin_consumer(twitter: &mut fie::config::Twitter) {
     }
 }
 
+///Platforms that already received post, taken out of API while it is sent to the rest.
+struct Done {
+    twitter: Option<fie::api::twitter::Twitter>,
+    gab: Option<fie::api::gab::Gab>,
+    mastodon: Option<fie::api::mastodon::Mastodon>,
+    minds: Option<fie::api::minds::Minds>,
+    bluesky: Option<fie::api::bluesky::Bluesky>,
+    pleroma: Option<fie::api::mastodon::Mastodon>,
+    misskey: Option<fie::api::misskey::Misskey>,
+    wordpress: Option<fie::api::wordpress::WordPress>,
+    matrix: Option<fie::api::matrix::Matrix>,
+    telegram: Option<fie::api::telegram::Telegram>,
+    discord: Option<fie::api::discord::Discord>,
+    slack: Option<fie::api::slack::Slack>,
+    linkedin: Option<fie::api::linkedin::LinkedIn>,
+    facebook: Option<fie::api::facebook::Facebook>,
+}
+
+impl Done {
+    ///Returns whether every enabled platform of API is among done ones.
+    fn is_complete(api: &fie::API, done: &[String]) -> bool {
+        api.platforms().iter().all(|platform| done.iter().any(|done| done == platform))
+    }
+
+    fn disable(api: &mut fie::API, done: &[String]) -> Self {
+        let is_done = |platform| done.iter().any(|done| done == platform);
+
+        Self {
+            twitter: if is_done("twitter") { api.disable_twitter() } else { None },
+            gab: if is_done("gab") { api.disable_gab() } else { None },
+            mastodon: if is_done("mastodon") { api.disable_mastodon() } else { None },
+            minds: if is_done("minds") { api.disable_minds() } else { None },
+            bluesky: if is_done("bluesky") { api.disable_bluesky() } else { None },
+            pleroma: if is_done("pleroma") { api.disable_pleroma() } else { None },
+            misskey: if is_done("misskey") { api.disable_misskey() } else { None },
+            wordpress: if is_done("wordpress") { api.disable_wordpress() } else { None },
+            matrix: if is_done("matrix") { api.disable_matrix() } else { None },
+            telegram: if is_done("telegram") { api.disable_telegram() } else { None },
+            discord: if is_done("discord") { api.disable_discord() } else { None },
+            slack: if is_done("slack") { api.disable_slack() } else { None },
+            linkedin: if is_done("linkedin") { api.disable_linkedin() } else { None },
+            facebook: if is_done("facebook") { api.disable_facebook() } else { None },
+        }
+    }
+
+    ///Puts platforms back into API.
+    fn enable(self, api: &mut fie::API) {
+        if self.twitter.is_some() {
+            api.enable_twitter(self.twitter);
+        }
+        if self.gab.is_some() {
+            api.enable_gab(self.gab);
+        }
+        if self.mastodon.is_some() {
+            api.enable_mastodon(self.mastodon);
+        }
+        if self.minds.is_some() {
+            api.enable_minds(self.minds);
+        }
+        if self.bluesky.is_some() {
+            api.enable_bluesky(self.bluesky);
+        }
+        if self.pleroma.is_some() {
+            api.enable_pleroma(self.pleroma);
+        }
+        if self.misskey.is_some() {
+            api.enable_misskey(self.misskey);
+        }
+        if self.wordpress.is_some() {
+            api.enable_wordpress(self.wordpress);
+        }
+        if self.matrix.is_some() {
+            api.enable_matrix(self.matrix);
+        }
+        if self.telegram.is_some() {
+            api.enable_telegram(self.telegram);
+        }
+        if self.discord.is_some() {
+            api.enable_discord(self.discord);
+        }
+        if self.slack.is_some() {
+            api.enable_slack(self.slack);
+        }
+        if self.linkedin.is_some() {
+            api.enable_linkedin(self.linkedin);
+        }
+        if self.facebook.is_some() {
+            api.enable_facebook(self.facebook);
+        }
+    }
+}
+
 #[inline(always)]
 fn runtime() -> tokio::runtime::Runtime {
     tokio::runtime::Builder::new().basic_scheduler().enable_io().build().expect("To create async runtime")
 }
 
-fn command_post(config: Config, post: cli::Post) -> io::Result<()> {
+fn command_post(config: Config, mut post: cli::Post, format: Format, outcome: &mut Outcome) -> Result<(), Failure> {
+    let mut runtime = runtime();
+
+    let post_file = match post.markdown.take() {
+        Some(path) => Some(markdown::open(&path)?),
+        None => None,
+    };
+
+    let accounts = Accounts::new(&config);
+    let dry_run = post.dry_run;
+    let api = runtime.block_on(create_api(config, dry_run, format, outcome))?;
+    let post = match post_file {
+        Some(mut post_file) => {
+            post.apply(&mut post_file);
+            post_file
+        },
+        None => post.into(),
+    };
+
+    match dry_run {
+        true => match api.preview(&post) {
+            Ok(preview) => handle_preview(preview, format, None, outcome),
+            Err(error) => handle_post_error(&error, error.kind(), format, None, outcome),
+        },
+        false => {
+            let mut history = History::open()?;
+            match runtime.block_on(api.send(post)) {
+                Ok(result) => {
+                    handle_post_result(result, &accounts, format, None, &mut history, outcome);
+                },
+                Err(error) => handle_post_error(&error, error.kind(), format, None, outcome),
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn command_batch(config: Config, batch: cli::Batch, format: Format, outcome: &mut Outcome) -> Result<(), Failure> {
+    let mut runtime = runtime();
+
+    let accounts = Accounts::new(&config);
+    let mut api = runtime.block_on(create_api(config, batch.dry_run, format, outcome))?;
+
+    let file = batch::open_batch(&batch.file, batch.format)?;
+    let state = match batch.state {
+        Some(ref path) => Some(RefCell::new(State::open(path, &batch.file)?)),
+        None => None,
+    };
+    let mut history = History::open()?;
+    let mut is_first = true;
+
+    for (idx, batch_post) in file.posts.enumerate() {
+        let index = idx + 1;
+
+        if outcome.is_fatal() {
+            break;
+        } else if index < batch.from {
+            continue;
+        }
+
+        //Post that every platform already received is skipped without waiting for its delay
+        if let Some(ref state) = state {
+            if Done::is_complete(&api, state.borrow().done(index)) {
+                continue;
+            }
+        }
+
+        if format == Format::Text {
+            println!(">>>Post #{}:", index);
+        }
+
+        let failures = outcome.failures();
+
+        let BatchPost { vars, delay, mut post } = match batch_post {
+            Ok(batch_post) => batch_post,
+            Err(ref error) if error.kind() == io::ErrorKind::InvalidData => {
+                handle_post_error(error, "invalid_post", format, Some(index), outcome);
+                if batch.stop_on_error {
+                    break;
+                }
+                continue;
+            },
+            Err(error) => return Err(error.into()),
+        };
+
+        //Priority: command line, post, batch
+        let mut post_vars = file.vars.clone();
+        post_vars.extend(vars);
+        post_vars.extend(batch.vars.iter().cloned());
+
+        if let Err(error) = template::render_post(&mut post, &post_vars) {
+            handle_post_error(&error, "template", format, Some(index), outcome);
+        } else if batch.dry_run {
+            post.flags.no_preview |= batch.no_preview;
+
+            match api.preview(&post) {
+                Ok(preview) => handle_preview(preview, format, Some(index), outcome),
+                Err(error) => handle_post_error(&error, error.kind(), format, Some(index), outcome),
+            }
+        } else {
+            post.flags.no_preview |= batch.no_preview;
+
+            match delay.unwrap_or(file.delay) {
+                0 => (),
+                _ if is_first => (),
+                delay => thread::sleep(Duration::from_secs(delay)),
+            }
+            is_first = false;
+
+            //Platforms that already received post are skipped
+            let done = match state {
+                Some(ref state) => Done::disable(&mut api, state.borrow().done(index)),
+                None => Done::disable(&mut api, &[]),
+            };
+
+            //Platform is recorded as soon as it receives post, so it is skipped on resume even if fie stops midway
+            let record_error = Cell::new(None);
+            let on_result = |platform: &'static str, result: &Result<fie::data::PublishedPost, fie::api::ApiError>| match (state.as_ref(), result) {
+                (Some(state), Ok(_)) => if let Err(error) = state.borrow_mut().record(index, vec![platform]) {
+                    record_error.set(Some(error));
+                },
+                _ => (),
+            };
+
+            match runtime.block_on(api.send_with(post, on_result)) {
+                Ok(result) => {
+                    handle_post_result(result, &accounts, format, Some(index), &mut history, outcome);
+                },
+                Err(error) => handle_post_error(&error, error.kind(), format, Some(index), outcome),
+            }
+
+            done.enable(&mut api);
+
+            if let Some(error) = record_error.take() {
+                return Err(error.into());
+            }
+        }
+
+        if batch.stop_on_error && outcome.failures() > failures {
+            break;
+        }
+    }
+
+    Ok(())
+}
+
+fn command_feed(config: Config, feed: cli::Feed, format: Format, outcome: &mut Outcome) -> Result<(), Failure> {
     let mut runtime = runtime();
 
-    let api = runtime.block_on(create_api(config))?;
-    let result = runtime.block_on(api.send(post.into())).map_err(|error| io::Error::new(io::ErrorKind::Other, error))?;
-    handle_post_result(result);
+    fie::api::http::set_timeout(&config.settings);
+    let mut entries = feed::parse(&runtime.block_on(feed::load(&feed.source))?)?;
+    feed::sort(&mut entries);
+    let state = RefCell::new(feed::State::open(&feed.state)?);
+    let mut history = History::open()?;
+    let limit = feed.limit.unwrap_or(usize::max_value());
+
+    if feed.mark_posted {
+        for entry in entries.into_iter().filter(|entry| !state.borrow().is_posted(&entry.id)).take(limit) {
+            if format == Format::Text {
+                println!("Marked as posted: {}", entry.id);
+            }
+            state.borrow_mut().mark(entry.id)?;
+        }
+
+        return Ok(());
+    }
+
+    let accounts = Accounts::new(&config);
+    let mut api = runtime.block_on(create_api(config, feed.dry_run, format, outcome))?;
+
+    //Entry is new until every platform received it
+    let entries: Vec<_> = entries.into_iter().filter(|entry| {
+        let state = state.borrow();
+        !state.is_posted(&entry.id) && !Done::is_complete(&api, state.done(&entry.id))
+    }).take(limit).collect();
+
+    for (idx, entry) in entries.into_iter().enumerate() {
+        let index = idx + 1;
+
+        if outcome.is_fatal() {
+            break;
+        }
+
+        if format == Format::Text {
+            println!(">>>Post #{}: {}", index, entry.id);
+        }
+
+        let id = entry.id.clone();
+        let mut post = match entry.into_post(&feed.template, &feed.tags, !feed.no_categories) {
+            Ok(post) => post,
+            Err(error) => {
+                handle_post_error(&error, "template", format, Some(index), outcome);
+                continue;
+            }
+        };
+        post.flags.no_preview = feed.no_preview;
+
+        if feed.dry_run {
+            match api.preview(&post) {
+                Ok(preview) => handle_preview(preview, format, Some(index), outcome),
+                Err(error) => handle_post_error(&error, error.kind(), format, Some(index), outcome),
+            }
+            continue;
+        }
+
+        //Platforms that already received entry are skipped
+        let done = Done::disable(&mut api, state.borrow().done(&id));
+
+        //Platform is recorded as soon as it receives entry, so failed ones get it on next run without duplicates
+        let record_error = Cell::new(None);
+        let on_result = |platform: &'static str, result: &Result<fie::data::PublishedPost, fie::api::ApiError>| if result.is_ok() {
+            if let Err(error) = state.borrow_mut().record(&id, vec![platform]) {
+                record_error.set(Some(error));
+            }
+        };
+
+        match runtime.block_on(api.send_with(post, on_result)) {
+            Ok(result) => {
+                handle_post_result(result, &accounts, format, Some(index), &mut history, outcome);
+            },
+            Err(error) => handle_post_error(&error, error.kind(), format, Some(index), outcome),
+        }
+
+        done.enable(&mut api);
+
+        if let Some(error) = record_error.take() {
+            return Err(error.into());
+        }
+    }
+
     Ok(())
 }
 
-fn command_batch(config: Config, batch: cli::Batch) -> io::Result<()> {
+fn command_timeline(mut config: Config, timeline: fie::api::timeline::Timeline, limit: usize, format: Format, outcome: &mut Outcome) -> Result<(), Failure> {
     let mut runtime = runtime();
 
-    let api = runtime.block_on(create_api(config))?;
+    //Platforms without timeline are not configured at all
+    config.platforms.minds = false;
+    config.platforms.wordpress = false;
+    config.platforms.matrix = false;
+    config.platforms.telegram = false;
+    config.platforms.discord = false;
+    config.platforms.slack = false;
+    config.platforms.linkedin = false;
+    config.platforms.facebook = false;
 
-    for (idx, post) in open_batch(&batch.file)?.post.drain(..).enumerate() {
-        println!(">>>Post #{}:", idx + 1);
-        match runtime.block_on(api.send(post)) {
-            Ok(result) => handle_post_result(result),
-            Err(error) => eprintln!("{}", error),
+    let api = runtime.block_on(create_api(config, false, format, outcome))?;
+    let fie::api::timeline::Statuses { twitter, gab, mastodon, bluesky, pleroma, misskey } = runtime.block_on(api.timeline(timeline, limit));
+
+    let mut statuses = vec![];
+    let mut errors = vec![];
+    let mut handle_inner = |platform, prefix, result: Option<Result<Vec<fie::data::Status>, fie::api::ApiError>>| if let Some(result) = result {
+        match result {
+            Ok(result) => {
+                outcome.success();
+                statuses.extend(result.into_iter().map(|status| (platform, prefix, status)));
+            },
+            Err(error) => {
+                outcome.failure();
+                errors.push((platform, error));
+            }
+        }
+    };
+
+    handle_inner("twitter", "Twitter", twitter);
+    handle_inner("gab", "Gab", gab);
+    handle_inner("mastodon", "Mastodon", mastodon);
+    handle_inner("bluesky", "Bluesky", bluesky);
+    handle_inner("pleroma", "Pleroma", pleroma);
+    handle_inner("misskey", "Misskey", misskey);
+
+    //Newest first, while posts without time go last
+    statuses.sort_by(|(_, _, left), (_, _, right)| right.timestamp.cmp(&left.timestamp));
+
+    match format {
+        Format::Text => {
+            for (_, error) in errors {
+                eprintln!("{}", error);
+            }
+
+            for (_, prefix, status) in statuses {
+                match status.timestamp {
+                    Some(timestamp) => println!("[{}] {} @{}", prefix, fie::data::time::from_timestamp(timestamp), status.author),
+                    None => println!("[{}] @{}", prefix, status.author),
+                }
+                println!("{}", status.text);
+                println!("{}\n", status.url);
+            }
+        },
+        Format::Json => output::print_json(&TimelineOutput {
+            statuses: statuses.into_iter().map(|(platform, _, status)| StatusOutput::new(platform, status)).collect(),
+            errors: errors.into_iter().map(|(platform, error)| PlatformError {
+                platform,
+                error_kind: error.kind(),
+                error_message: error.to_string(),
+            }).collect(),
+        }),
+    }
+
+    Ok(())
+}
+
+fn command_stats(mut config: Config, stats: cli::Stats, format: Format, outcome: &mut Outcome) -> Result<(), Failure> {
+    let mut runtime = runtime();
+
+    let history = History::open()?;
+    let entry = match history.get(stats.id) {
+        Some(entry) => entry,
+        None => return Err(io::Error::new(io::ErrorKind::Other, format!("Post {} is not in history", stats.id)).into()),
+    };
+    let ids = entry.post_ids();
+
+    //Only platforms, that received post and report statistics, are used
+    config.platforms.twitter = ids.twitter.is_some();
+    config.platforms.gab = ids.gab.is_some();
+    config.platforms.mastodon = ids.mastodon.is_some();
+    config.platforms.minds = ids.minds.is_some();
+    config.platforms.bluesky = ids.bluesky.is_some();
+    config.platforms.pleroma = ids.pleroma.is_some();
+    config.platforms.misskey = ids.misskey.is_some();
+    config.platforms.wordpress = false;
+    config.platforms.matrix = false;
+    config.platforms.telegram = false;
+    config.platforms.discord = false;
+    config.platforms.slack = false;
+    config.platforms.linkedin = false;
+    config.platforms.facebook = false;
+
+    let api = runtime.block_on(create_api(config, false, format, outcome))?;
+    let fie::api::stats::Stats { twitter, gab, mastodon, minds, bluesky, pleroma, misskey } = runtime.block_on(api.stats(&ids));
+
+    let mut results = vec![];
+    let mut handle_inner = |platform, prefix, id: &Option<String>, result: Option<Result<fie::data::PostStats, fie::api::ApiError>>| if let (Some(id), Some(result)) = (id, result) {
+        match result.is_ok() {
+            true => outcome.success(),
+            false => outcome.failure(),
+        }
+        results.push((prefix, PlatformStats::new(platform, id.clone(), result)));
+    };
+
+    handle_inner("twitter", "Twitter", &ids.twitter, twitter);
+    handle_inner("gab", "Gab", &ids.gab, gab);
+    handle_inner("mastodon", "Mastodon", &ids.mastodon, mastodon);
+    handle_inner("minds", "Minds", &ids.minds, minds);
+    handle_inner("bluesky", "Bluesky", &ids.bluesky, bluesky);
+    handle_inner("pleroma", "Pleroma", &ids.pleroma, pleroma);
+    handle_inner("misskey", "Misskey", &ids.misskey, misskey);
+
+    match format {
+        Format::Text => {
+            println!("{:<10} {:>8} {:>8} {:>8}", "Platform", "Likes", "Reposts", "Replies");
+            for (prefix, result) in results {
+                match result.error_message {
+                    Some(error) => eprintln!("{}", error),
+                    None => {
+                        let count = |count: Option<u64>| count.map(|count| count.to_string()).unwrap_or_else(|| "-".to_owned());
+                        println!("{:<10} {:>8} {:>8} {:>8}", prefix, count(result.likes), count(result.reposts), count(result.replies));
+                    }
+                }
+            }
+        },
+        Format::Json => output::print_json(&StatsOutput {
+            id: entry.id,
+            created_at: entry.created_at.clone(),
+            results: results.into_iter().map(|(_, result)| result).collect(),
+        }),
+    }
+
+    Ok(())
+}
+
+fn command_interaction(mut config: Config, url: String, interaction: fie::api::interaction::Interaction, format: Format, outcome: &mut Outcome) -> Result<(), Failure> {
+    let mut runtime = runtime();
+
+    //Platforms without statuses are not configured at all
+    config.platforms.minds = false;
+    config.platforms.wordpress = false;
+    config.platforms.matrix = false;
+    config.platforms.telegram = false;
+    config.platforms.discord = false;
+    config.platforms.slack = false;
+    config.platforms.linkedin = false;
+    config.platforms.facebook = false;
+
+    let api = runtime.block_on(create_api(config, false, format, outcome))?;
+    let result = runtime.block_on(api.interact(&url, interaction)).map_err(|error| io::Error::new(io::ErrorKind::Other, error))?;
+    let fie::api::interaction::Interactions { twitter, gab, mastodon, bluesky, pleroma, misskey } = result;
+
+    let (action, done) = match interaction {
+        fie::api::interaction::Interaction::Boost => ("boost", "Boosted"),
+        fie::api::interaction::Interaction::Like => ("like", "Liked"),
+    };
+
+    let mut results = vec![];
+    let mut handle_inner = |platform, prefix, result: Option<Result<fie::data::PostId, fie::api::ApiError>>| if let Some(result) = result {
+        match result {
+            Ok(_) => outcome.success(),
+            Err(_) => outcome.failure(),
         }
+        results.push((prefix, InteractionResult::new(platform, result)));
+    };
+
+    handle_inner("twitter", "Twitter", twitter);
+    handle_inner("gab", "Gab", gab);
+    handle_inner("mastodon", "Mastodon", mastodon);
+    handle_inner("bluesky", "Bluesky", bluesky);
+    handle_inner("pleroma", "Pleroma", pleroma);
+    handle_inner("misskey", "Misskey", misskey);
+
+    if results.len() == 0 {
+        return Err(io::Error::new(io::ErrorKind::Other, format!("No enabled platform can open '{}'", url)).into());
+    }
+
+    match format {
+        Format::Text => for (prefix, result) in results {
+            match result.id {
+                Some(id) => println!("{} on {}(Id={})", done, prefix, id),
+                None => eprintln!("{}", result.error_message.unwrap_or_default()),
+            }
+        },
+        Format::Json => output::print_json(&InteractionOutput {
+            url,
+            action,
+            results: results.into_iter().map(|(_, result)| result).collect(),
+        }),
     }
 
     Ok(())
 }
 
-fn run() -> io::Result<()> {
-    let mut config = Config::load()?;
+fn run(args: cli::Args) -> Result<Outcome, Failure> {
+    let mut config = Config::load().map_err(Failure::Config)?;
     use_twitter_builtin_consumer(&mut config.api.twitter);
 
-    let args = cli::Args::new(&mut config.platforms);
+    args.select_platforms(&mut config.platforms);
+    let format = args.output;
+    let mut outcome = Outcome::new(args.strict);
 
     match args.cmd {
-        cli::Command::Post(post) => command_post(config, post)?,
-        cli::Command::Batch(batch) => command_batch(config, batch)?,
+        cli::Command::Post(post) => command_post(config, post, format, &mut outcome)?,
+        cli::Command::Batch(batch) => command_batch(config, batch, format, &mut outcome)?,
+        cli::Command::Feed(feed) => command_feed(config, feed, format, &mut outcome)?,
+        cli::Command::Timeline(timeline) => {
+            let kind = match timeline.own {
+                true => fie::api::timeline::Timeline::Own,
+                false => fie::api::timeline::Timeline::Home,
+            };
+            command_timeline(config, kind, timeline.limit, format, &mut outcome)?
+        },
+        cli::Command::Mentions(mentions) => command_timeline(config, fie::api::timeline::Timeline::Mentions, mentions.limit, format, &mut outcome)?,
+        cli::Command::Stats(stats) => command_stats(config, stats, format, &mut outcome)?,
+        cli::Command::Boost(status) => command_interaction(config, status.url, fie::api::interaction::Interaction::Boost, format, &mut outcome)?,
+        cli::Command::Like(status) => command_interaction(config, status.url, fie::api::interaction::Interaction::Like, format, &mut outcome)?,
         cli::Command::Env(env) => match env {
-            cli::Env::Config => println!("{}", Config::path()?.display())
+            cli::Env::Config => {
+                let path = Config::path().map_err(Failure::Config)?;
+                match format {
+                    Format::Text => println!("{}", path.display()),
+                    Format::Json => output::print_json(&EnvOutput { config: path.display().to_string() }),
+                }
+            }
         },
         cli::Command::Auth(typ) => match typ {
-            cli::Auth::Twitter => {
-                runtime().block_on(auth::twitter(config.api.twitter));
+            cli::Auth::Twitter { oauth2: true, refresh } => {
+                let token = match refresh {
+                    true => runtime().block_on(auth::twitter_refresh(config.api.twitter.oauth2))?,
+                    false => runtime().block_on(auth::twitter_oauth2(config.api.twitter.oauth2))?,
+                };
+                match format {
+                    Format::Text => {
+                        println!("Received access token successfully.\nAdd following to your fie configuration file:");
+                        println!("[api.twitter.oauth2]\naccess_token = \"{}\"", token.access_token);
+                        if let Some(refresh_token) = token.refresh_token.as_ref() {
+                            println!("refresh_token = \"{}\"", refresh_token);
+                        }
+                    },
+                    Format::Json => {
+                        let mut output = AuthOutput::token("twitter", token.access_token);
+                        output.refresh_token = token.refresh_token;
+                        output::print_json(&output);
+                    }
+                }
+            },
+            cli::Auth::Twitter { .. } => {
+                let access = runtime().block_on(auth::twitter(config.api.twitter))?;
+                match format {
+                    Format::Text => {
+                        println!("Received access token successfully.\nAdd following to your fie configuration file:");
+                        println!("[api.twitter.access]\nkey = \"{}\"\nsecret = \"{}\"", access.key, access.secret);
+                    },
+                    Format::Json => output::print_json(&AuthOutput { platform: "twitter", status: "ok", key: Some(access.key), secret: Some(access.secret), access_token: None, refresh_token: None }),
+                }
+            },
+            cli::Auth::LinkedIn => {
+                let access_token = runtime().block_on(auth::linkedin(config.api.linkedin))?;
+                match format {
+                    Format::Text => {
+                        println!("Received access token successfully.\nAdd following to your fie configuration file:");
+                        println!("[api.linkedin]\naccess_token = \"{}\"", access_token);
+                    },
+                    Format::Json => output::print_json(&AuthOutput::token("linkedin", access_token)),
+                }
+            },
+            cli::Auth::Facebook => {
+                let access_token = runtime().block_on(auth::facebook(config.api.facebook))?;
+                match format {
+                    Format::Text => {
+                        println!("Received page's access token successfully.\nAdd following to your fie configuration file:");
+                        println!("[api.facebook]\naccess_token = \"{}\"", access_token);
+                    },
+                    Format::Json => output::print_json(&AuthOutput::token("facebook", access_token)),
+                }
             }
         }
     }
 
-    Ok(())
+    Ok(outcome)
 }
 
 fn main() {
-    let result = match run() {
-        Ok(_) => 0,
-        Err(error) => {
-            eprintln!("{}", error);
-            1
+    let args = cli::Args::new();
+    let format = args.output;
+
+    let result = match run(args) {
+        Ok(outcome) => outcome.code(),
+        Err(failure) => {
+            match format {
+                Format::Text => eprintln!("{}", failure.error()),
+                Format::Json => output::print_json(&ErrorOutput::new(failure.kind(), failure.error().to_string())),
+            }
+            failure.code()
         }
     };
 
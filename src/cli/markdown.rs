@@ -0,0 +1,248 @@
+use serde_derive::Deserialize;
+use serde::de::IgnoredAny;
+use pulldown_cmark::{Parser, Event, Tag};
+use fie::data::{Visibility, Poll, PostFlags, PostOverride};
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::io::{self, Read};
+use std::fs;
+
+///Settings of post, written before text.
+#[derive(Deserialize, Debug, Default)]
+struct FrontMatter {
+    #[serde(default)]
+    title: Option<String>,
+    #[serde(default)]
+    tags: Vec<String>,
+    #[serde(default)]
+    images: Vec<String>,
+    #[serde(default)]
+    nsfw: bool,
+    #[serde(default)]
+    cw: Option<String>,
+    #[serde(default)]
+    visibility: Visibility,
+    #[serde(default)]
+    poll: Option<Poll>,
+    #[serde(default)]
+    quote: Option<String>,
+    ///Platforms' overrides, along with any other setting, which is ignored.
+    #[serde(flatten)]
+    extra: HashMap<String, Extra>,
+}
+
+///Value of front matter that is not common setting of post.
+#[derive(Deserialize, Debug)]
+#[serde(untagged)]
+enum Extra {
+    Override(PostOverride),
+    Other(IgnoredAny),
+}
+
+///Splits front matter, delimited by `---` for YAML or `+++` for TOML, from the rest of text.
+fn split_front_matter(text: &str) -> Option<(&'static str, &str, &str)> {
+    let delimiter = match text.lines().next()?.trim_end() {
+        "---" => "---",
+        "+++" => "+++",
+        _ => return None,
+    };
+
+    let front_start = text.find('\n')? + 1;
+    let mut start = front_start;
+    loop {
+        let end = text[start..].find('\n').map(|idx| start + idx + 1).unwrap_or(text.len());
+
+        if text[start..end].trim_end() == delimiter {
+            return Some((delimiter, &text[front_start..start], &text[end..]));
+        } else if end == text.len() {
+            return None;
+        }
+
+        start = end;
+    }
+}
+
+///Ensures that text ends with at least `count` line breaks, unless it is empty.
+fn new_line(text: &mut String, count: usize) {
+    if text.len() == 0 {
+        return;
+    }
+
+    let existing = text.chars().rev().take_while(|ch| *ch == '\n').count();
+    for _ in existing..count {
+        text.push('\n');
+    }
+}
+
+///Converts Markdown to plain text.
+///
+///Emphasis is removed, links are written with their address and images are skipped.
+pub fn to_text(markdown: &str) -> String {
+    let mut text = String::new();
+    //Start of link's text and its address
+    let mut links = Vec::new();
+    //Number of next item for ordered lists
+    let mut lists: Vec<Option<u64>> = Vec::new();
+    let mut images = 0;
+
+    for event in Parser::new(markdown) {
+        match event {
+            Event::Start(Tag::Image(..)) => {
+                //Space before skipped image is not needed
+                if images == 0 {
+                    text.truncate(text.trim_end_matches(' ').len());
+                }
+                images += 1;
+            },
+            Event::End(Tag::Image(..)) => images -= 1,
+            _ if images > 0 => (),
+            Event::Text(part) | Event::Code(part) => text.push_str(&part),
+            Event::SoftBreak | Event::HardBreak => text.push('\n'),
+            Event::Rule => new_line(&mut text, 2),
+            Event::Start(Tag::Link(_, url, _)) => links.push((text.len(), url)),
+            Event::End(Tag::Link(..)) => if let Some((start, url)) = links.pop() {
+                let title = &text[start..];
+                if title != url.as_ref() && title != url.trim_start_matches("mailto:") {
+                    text.push_str(" (");
+                    text.push_str(&url);
+                    text.push(')');
+                }
+            },
+            Event::Start(Tag::List(start)) => {
+                new_line(&mut text, 1);
+                lists.push(start);
+            },
+            Event::End(Tag::List(_)) => {
+                lists.pop();
+                new_line(&mut text, if lists.len() > 0 { 1 } else { 2 });
+            },
+            Event::Start(Tag::Item) => {
+                new_line(&mut text, 1);
+                for _ in 1..lists.len() {
+                    text.push_str("  ");
+                }
+
+                match lists.last_mut() {
+                    Some(Some(number)) => {
+                        text.push_str(&format!("{}. ", number));
+                        *number += 1;
+                    },
+                    _ => text.push_str("- "),
+                }
+            },
+            Event::End(Tag::Paragraph) | Event::End(Tag::Heading(_)) | Event::End(Tag::CodeBlock(_)) | Event::End(Tag::BlockQuote) => new_line(&mut text, 2),
+            _ => (),
+        }
+    }
+
+    text.trim().to_owned()
+}
+
+///Converts Markdown to HTML.
+pub fn to_html(markdown: &str) -> String {
+    let mut html = String::new();
+    pulldown_cmark::html::push_html(&mut html, Parser::new(markdown));
+    html.trim().to_owned()
+}
+
+///Converts override's message out of Markdown.
+fn convert_override(overrides: &mut PostOverride, convert: fn(&str) -> String) {
+    if let Some(message) = overrides.message.as_mut() {
+        *message = convert(message);
+    }
+}
+
+///Loads post out of Markdown file with optional YAML or TOML front matter.
+///
+///Message is converted to plain text, except for WordPress which receives HTML.
+///Minds receives plain text as well, because its activities show HTML as it is,
+///while its blogs, which render HTML, cannot be published.
+pub fn open(path: &str) -> io::Result<fie::data::Post> {
+    let path = Path::new(path);
+    let mut file = fs::File::open(path).map_err(|error| io::Error::new(io::ErrorKind::Other, format!("{}: {}", path.display(), error)))?;
+    let mut text = String::new();
+    file.read_to_string(&mut text).map_err(|error| io::Error::new(io::ErrorKind::Other, format!("{}: {}", path.display(), error)))?;
+
+    let (front, body) = match split_front_matter(&text) {
+        Some(("+++", front, body)) => (toml::from_str(front).map_err(|error| io::Error::new(io::ErrorKind::Other, format!("Invalid front matter: {}", error)))?, body),
+        Some((_, front, body)) => match front.trim().len() {
+            0 => (FrontMatter::default(), body),
+            _ => (serde_yaml::from_str(front).map_err(|error| io::Error::new(io::ErrorKind::Other, format!("Invalid front matter: {}", error)))?, body),
+        },
+        None => (FrontMatter::default(), text.as_str()),
+    };
+
+    let FrontMatter { title, tags, images, nsfw, cw, visibility, poll, quote, extra } = front;
+
+    let mut post = fie::data::Post {
+        message: to_text(body),
+        title,
+        tags,
+        images,
+        flags: PostFlags {
+            nsfw,
+            cw,
+            visibility,
+            no_preview: false,
+        },
+        poll,
+        quote,
+        ..Default::default()
+    };
+    post.wordpress.message = Some(to_html(body));
+
+    for (name, value) in extra {
+        let (mut overrides, platform) = match (value, post.overrides_mut(&name)) {
+            (Extra::Override(overrides), Some(platform)) => (overrides, platform),
+            _ => continue,
+        };
+
+        match name.as_str() {
+            "wordpress" => convert_override(&mut overrides, to_html),
+            _ => convert_override(&mut overrides, to_text),
+        }
+        if overrides.message.is_none() {
+            overrides.message = platform.message.take();
+        }
+        *platform = overrides;
+    }
+
+    Ok(post)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_split_front_matter() {
+        assert_eq!(split_front_matter("---\ntitle: Hi\n---\nText"), Some(("---", "title: Hi\n", "Text")));
+        assert_eq!(split_front_matter("+++\r\ntitle = 'Hi'\r\n+++\r\nText"), Some(("+++", "title = 'Hi'\r\n", "Text")));
+        assert_eq!(split_front_matter("---\n---\n"), Some(("---", "", "")));
+        assert_eq!(split_front_matter("---\ntitle: Hi\nText"), None);
+        assert_eq!(split_front_matter("Text\n---\n"), None);
+    }
+
+    #[test]
+    fn should_convert_to_text() {
+        let text = to_text("# Title\n\nSome *emphasis* and [link](https://example.com), <https://example.org> ![image](image.png)\n\n1. One\n2. Two\n   - Nested\n\n---\n\n`code`");
+        assert_eq!(text, "Title\n\nSome emphasis and link (https://example.com), https://example.org\n\n1. One\n2. Two\n  - Nested\n\ncode");
+        assert_eq!(to_text("Before ![image](image.png) after"), "Before after");
+    }
+
+    #[test]
+    fn should_open_with_overrides() {
+        let path = std::env::temp_dir().join(format!("fie_markdown_overrides_{}.md", std::process::id()));
+        fs::write(&path, "---\nlayout: post\nauthor:\n  name: Fie\ntags: [rust]\nminds:\n  message: '*Minds*'\nwordpress:\n  tags: [blog]\n---\n*Text*").expect("To write post");
+        let post = open(path.to_str().unwrap()).expect("To open post");
+        let _ = fs::remove_file(&path);
+
+        assert_eq!(post.message, "Text");
+        assert_eq!(post.tags, ["rust"]);
+        assert_eq!(post.minds.message.as_deref(), Some("Minds"));
+        assert_eq!(post.wordpress.message.as_deref(), Some("<p><em>Text</em></p>"));
+        assert_eq!(post.wordpress.tags.as_deref(), Some(&["blog".to_owned()][..]));
+        assert!(post.twitter.message.is_none());
+    }
+}
@@ -0,0 +1,369 @@
+use serde_derive::Serialize;
+use yukikaze::serde_json;
+
+use std::str::FromStr;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        match text {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            other => Err(format!("Invalid output format '{}'. Allowed values: text, json", other)),
+        }
+    }
+}
+
+///Prints value as single line of JSON to stdout
+pub fn print_json<T: serde::Serialize>(value: &T) {
+    match serde_json::to_string(value) {
+        Ok(json) => println!("{}", json),
+        Err(error) => eprintln!("Unable to serialize output: {}", error),
+    }
+}
+
+///Error that stops command or prevents use of platform
+#[derive(Serialize, Debug)]
+pub struct ErrorOutput {
+    ///Platform that cannot be configured
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub platform: Option<&'static str>,
+    pub status: &'static str,
+    pub error_kind: &'static str,
+    pub error_message: String,
+}
+
+impl ErrorOutput {
+    pub fn new(kind: &'static str, message: String) -> Self {
+        Self {
+            platform: None,
+            status: "error",
+            error_kind: kind,
+            error_message: message,
+        }
+    }
+
+    pub fn platform(platform: &'static str, kind: &'static str, message: String) -> Self {
+        Self {
+            platform: Some(platform),
+            ..Self::new(kind, message)
+        }
+    }
+}
+
+///Accounts used for each platform, as far as configuration tells
+///
+///Configuration of Twitter, Gab, Discord and Slack does not identify account, so they have none.
+#[derive(Debug, Default, Clone)]
+pub struct Accounts {
+    pub mastodon: Option<String>,
+    pub minds: Option<String>,
+    pub bluesky: Option<String>,
+    pub pleroma: Option<String>,
+    pub misskey: Option<String>,
+    pub wordpress: Option<String>,
+    pub matrix: Option<String>,
+    pub telegram: Option<String>,
+    pub linkedin: Option<String>,
+    pub facebook: Option<String>,
+}
+
+impl Accounts {
+    pub fn new(config: &fie::config::Config) -> Self {
+        Self {
+            mastodon: Some(config.api.mastodon.host.clone()),
+            minds: Some(config.api.minds.username.clone()),
+            bluesky: Some(config.api.bluesky.handle.clone()),
+            pleroma: Some(config.api.pleroma.host.clone()),
+            misskey: Some(config.api.misskey.host.clone()),
+            wordpress: Some(config.api.wordpress.host.clone()),
+            matrix: Some(config.api.matrix.room.clone()),
+            telegram: Some(config.api.telegram.chat.clone()),
+            linkedin: Some(config.api.linkedin.author.clone()),
+            facebook: Some(config.api.facebook.page_id.clone()),
+        }
+    }
+}
+
+#[derive(Serialize, Debug)]
+pub struct PlatformResult {
+    pub platform: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account: Option<String>,
+    pub status: &'static str,
+    pub id: Option<String>,
+    pub url: Option<String>,
+    pub created_at: Option<String>,
+    pub error_kind: Option<&'static str>,
+    pub error_message: Option<String>,
+}
+
+impl PlatformResult {
+    pub fn new(platform: &'static str, account: Option<String>, result: Result<fie::data::PublishedPost, fie::api::ApiError>) -> Self {
+        match result {
+            Ok(post) => Self {
+                platform,
+                account,
+                status: "ok",
+                id: Some(post.id.to_string()),
+                url: Some(post.url),
+                created_at: post.created_at,
+                error_kind: None,
+                error_message: None,
+            },
+            Err(error) => Self {
+                platform,
+                account,
+                status: "error",
+                id: None,
+                url: None,
+                created_at: None,
+                error_kind: Some(error.kind()),
+                error_message: Some(error.to_string()),
+            }
+        }
+    }
+}
+
+#[derive(Serialize, Debug)]
+pub struct PostOutput {
+    ///Index of post within batch, starting from 1
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub index: Option<usize>,
+    pub status: &'static str,
+    pub results: Vec<PlatformResult>,
+    pub error_kind: Option<&'static str>,
+    pub error_message: Option<String>,
+    ///Identifier of post within history, used by `stats`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub history_id: Option<usize>,
+}
+
+impl PostOutput {
+    pub fn new(index: Option<usize>, results: Vec<PlatformResult>) -> Self {
+        let status = match results.iter().all(|result| result.status == "ok") {
+            true => "ok",
+            false => "error",
+        };
+
+        Self {
+            index,
+            status,
+            results,
+            error_kind: None,
+            error_message: None,
+            history_id: None,
+        }
+    }
+
+    pub fn error(index: Option<usize>, kind: &'static str, message: String) -> Self {
+        Self {
+            index,
+            status: "error",
+            results: Vec::new(),
+            error_kind: Some(kind),
+            error_message: Some(message),
+            history_id: None,
+        }
+    }
+}
+
+#[derive(Serialize, Debug)]
+pub struct PreviewOutput {
+    pub platform: &'static str,
+    pub message: String,
+    pub len: usize,
+    pub max_len: Option<usize>,
+    pub tags: Vec<String>,
+    pub images: Vec<String>,
+    pub nsfw: bool,
+    pub visibility: String,
+    pub cw: Option<String>,
+    pub error_kind: Option<&'static str>,
+    pub error_message: Option<String>,
+}
+
+impl PreviewOutput {
+    pub fn new(platform: &'static str, preview: fie::api::preview::PlatformPreview) -> Self {
+        Self {
+            platform,
+            message: preview.message,
+            len: preview.len,
+            max_len: preview.max_len,
+            tags: preview.tags,
+            images: preview.images,
+            nsfw: preview.flags.nsfw,
+            visibility: preview.flags.visibility.to_string(),
+            cw: preview.flags.cw,
+            error_kind: preview.error.as_ref().map(|error| error.kind()),
+            error_message: preview.error.as_ref().map(|error| error.to_string()),
+        }
+    }
+}
+
+#[derive(Serialize, Debug)]
+pub struct PreviewsOutput {
+    ///Index of post within batch, starting from 1
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub index: Option<usize>,
+    pub previews: Vec<PreviewOutput>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct EnvOutput {
+    pub config: String,
+}
+
+#[derive(Serialize, Debug)]
+pub struct AuthOutput {
+    pub platform: &'static str,
+    pub status: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secret: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub access_token: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refresh_token: Option<String>,
+}
+
+impl AuthOutput {
+    pub fn token(platform: &'static str, access_token: String) -> Self {
+        Self {
+            platform,
+            status: "ok",
+            key: None,
+            secret: None,
+            access_token: Some(access_token),
+            refresh_token: None,
+        }
+    }
+}
+
+#[derive(Serialize, Debug)]
+pub struct StatusOutput {
+    pub platform: &'static str,
+    pub id: String,
+    pub url: String,
+    pub author: String,
+    pub text: String,
+    pub created_at: Option<String>,
+}
+
+impl StatusOutput {
+    pub fn new(platform: &'static str, status: fie::data::Status) -> Self {
+        Self {
+            platform,
+            id: status.id.to_string(),
+            url: status.url,
+            author: status.author,
+            text: status.text,
+            created_at: status.timestamp.map(fie::data::time::from_timestamp),
+        }
+    }
+}
+
+#[derive(Serialize, Debug)]
+pub struct PlatformError {
+    pub platform: &'static str,
+    pub error_kind: &'static str,
+    pub error_message: String,
+}
+
+#[derive(Serialize, Debug)]
+pub struct TimelineOutput {
+    pub statuses: Vec<StatusOutput>,
+    pub errors: Vec<PlatformError>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct PlatformStats {
+    pub platform: &'static str,
+    pub id: String,
+    pub status: &'static str,
+    pub likes: Option<u64>,
+    pub reposts: Option<u64>,
+    pub replies: Option<u64>,
+    pub error_kind: Option<&'static str>,
+    pub error_message: Option<String>,
+}
+
+impl PlatformStats {
+    pub fn new(platform: &'static str, id: String, result: Result<fie::data::PostStats, fie::api::ApiError>) -> Self {
+        match result {
+            Ok(stats) => Self {
+                platform,
+                id,
+                status: "ok",
+                likes: Some(stats.likes),
+                reposts: Some(stats.reposts),
+                replies: stats.replies,
+                error_kind: None,
+                error_message: None,
+            },
+            Err(error) => Self {
+                platform,
+                id,
+                status: "error",
+                likes: None,
+                reposts: None,
+                replies: None,
+                error_kind: Some(error.kind()),
+                error_message: Some(error.to_string()),
+            }
+        }
+    }
+}
+
+#[derive(Serialize, Debug)]
+pub struct StatsOutput {
+    ///Identifier of post within history
+    pub id: usize,
+    pub created_at: String,
+    pub results: Vec<PlatformStats>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct InteractionResult {
+    pub platform: &'static str,
+    pub status: &'static str,
+    pub id: Option<String>,
+    pub error_kind: Option<&'static str>,
+    pub error_message: Option<String>,
+}
+
+impl InteractionResult {
+    pub fn new(platform: &'static str, result: Result<fie::data::PostId, fie::api::ApiError>) -> Self {
+        match result {
+            Ok(id) => Self {
+                platform,
+                status: "ok",
+                id: Some(id.to_string()),
+                error_kind: None,
+                error_message: None,
+            },
+            Err(error) => Self {
+                platform,
+                status: "error",
+                id: None,
+                error_kind: Some(error.kind()),
+                error_message: Some(error.to_string()),
+            }
+        }
+    }
+}
+
+#[derive(Serialize, Debug)]
+pub struct InteractionOutput {
+    pub url: String,
+    pub action: &'static str,
+    pub results: Vec<InteractionResult>,
+}
@@ -0,0 +1,137 @@
+use std::collections::HashMap;
+use std::io;
+use std::env;
+
+///Variables available to templates
+pub type Vars = HashMap<String, String>;
+
+fn lookup(name: &str, vars: &Vars) -> io::Result<String> {
+    if name == "date" {
+        return Ok(fie::data::time::today());
+    } else if name.starts_with("env.") {
+        let key = &name[4..];
+        return env::var(key).map_err(|_| io::Error::new(io::ErrorKind::Other, format!("Template uses environment variable '{}', but it is not set", key)));
+    }
+
+    match vars.get(name) {
+        Some(value) => Ok(value.clone()),
+        None => Err(io::Error::new(io::ErrorKind::Other, format!("Template uses unknown variable '{}'", name))),
+    }
+}
+
+///Substitutes each `{{name}}` in text.
+///
+///Besides user's variables following are available:
+///
+///- `date` - Current UTC date as `YYYY-MM-DD`;
+///- `env.<NAME>` - Value of environment variable `NAME`.
+pub fn render(text: &str, vars: &Vars) -> io::Result<String> {
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find("{{") {
+        result.push_str(&rest[..start]);
+        rest = &rest[start + 2..];
+
+        let end = match rest.find("}}") {
+            Some(end) => end,
+            None => return Err(io::Error::new(io::ErrorKind::Other, format!("Template has unterminated variable in '{}'", text))),
+        };
+
+        result.push_str(&lookup(rest[..end].trim(), vars)?);
+        rest = &rest[end + 2..];
+    }
+
+    result.push_str(rest);
+    Ok(result)
+}
+
+fn render_override(overrides: &mut fie::data::PostOverride, vars: &Vars) -> io::Result<()> {
+    if let Some(message) = overrides.message.as_mut() {
+        *message = render(message, vars)?;
+    }
+
+    if let Some(tags) = overrides.tags.as_mut() {
+        for tag in tags.iter_mut() {
+            *tag = render(tag, vars)?;
+        }
+    }
+
+    Ok(())
+}
+
+///Substitutes variables in post's message and tags, including platform overrides.
+pub fn render_post(post: &mut fie::data::Post, vars: &Vars) -> io::Result<()> {
+    post.message = render(&post.message, vars)?;
+    for tag in post.tags.iter_mut() {
+        *tag = render(tag, vars)?;
+    }
+
+    for overrides in post.all_overrides_mut().iter_mut() {
+        render_override(overrides, vars)?;
+    }
+
+    Ok(())
+}
+
+///Parses `key=value` pair.
+pub fn parse_var(text: &str) -> Result<(String, String), String> {
+    let mut split = text.splitn(2, '=');
+
+    match (split.next(), split.next()) {
+        (Some(key), Some(value)) if key.len() > 0 => Ok((key.to_owned(), value.to_owned())),
+        _ => Err(format!("Invalid variable '{}'. Should be in format key=value", text)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_render_template() {
+        let mut vars = Vars::new();
+        vars.insert("name".to_owned(), "fie".to_owned());
+        env::set_var("FIE_TEMPLATE_TEST", "env");
+
+        assert_eq!(render("Hello {{name}}, {{ name }}!", &vars).unwrap(), "Hello fie, fie!");
+        assert_eq!(render("From {{env.FIE_TEMPLATE_TEST}}", &vars).unwrap(), "From env");
+        assert_eq!(render("No vars", &vars).unwrap(), "No vars");
+
+        let date = render("{{date}}", &vars).unwrap();
+        assert_eq!(date.len(), 10);
+        assert_eq!(&date[4..5], "-");
+        assert_eq!(&date[7..8], "-");
+
+        assert!(render("{{unknown}}", &vars).is_err());
+        assert!(render("{{env.FIE_TEMPLATE_TEST_UNSET}}", &vars).is_err());
+        assert!(render("Hello {{name", &vars).is_err());
+    }
+
+    #[test]
+    fn should_render_post_overrides() {
+        let mut vars = Vars::new();
+        vars.insert("name".to_owned(), "fie".to_owned());
+
+        let mut post = fie::data::Post {
+            message: "{{name}}".to_owned(),
+            tags: vec!["{{name}}".to_owned()],
+            ..Default::default()
+        };
+        post.facebook.message = Some("Facebook {{name}}".to_owned());
+
+        render_post(&mut post, &vars).unwrap();
+        assert_eq!(post.message, "fie");
+        assert_eq!(post.tags, ["fie"]);
+        assert_eq!(post.facebook.message.as_deref(), Some("Facebook fie"));
+    }
+
+    #[test]
+    fn should_parse_var() {
+        assert_eq!(parse_var("key=value"), Ok(("key".to_owned(), "value".to_owned())));
+        assert_eq!(parse_var("key=a=b"), Ok(("key".to_owned(), "a=b".to_owned())));
+        assert_eq!(parse_var("key="), Ok(("key".to_owned(), "".to_owned())));
+        assert!(parse_var("=value").is_err());
+        assert!(parse_var("key").is_err());
+    }
+}
@@ -0,0 +1,382 @@
+//! Bluesky API data types
+
+use serde_derive::{Serialize, Deserialize};
+use yukikaze::serde_json;
+
+use super::super::opengraph::OpenGraph;
+
+///Payload to create session.
+#[derive(Serialize, Debug)]
+pub struct CreateSession<'a> {
+    identifier: &'a str,
+    password: &'a str,
+}
+
+impl<'a> CreateSession<'a> {
+    ///Creates new instance
+    pub fn new(identifier: &'a str, password: &'a str) -> Self {
+        Self {
+            identifier,
+            password,
+        }
+    }
+}
+
+///Created session
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct Session {
+    ///Access token
+    pub access_jwt: String,
+    ///Account's DID
+    pub did: String,
+    ///Account's handle
+    pub handle: String,
+}
+
+///Response to blob upload.
+#[derive(Deserialize, Debug)]
+pub struct UploadedBlob {
+    ///Reference to blob, which is passed as it is to embed.
+    pub blob: serde_json::Value,
+}
+
+///Response to resolving handle.
+#[derive(Deserialize, Debug)]
+pub struct ResolvedHandle {
+    ///Account's DID
+    pub did: String,
+}
+
+///Range of facet in UTF-8 bytes of text.
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ByteSlice {
+    byte_start: usize,
+    byte_end: usize,
+}
+
+///Rich text feature
+#[derive(Serialize, Debug)]
+#[serde(tag = "$type")]
+pub enum Feature {
+    ///Link to URI
+    #[serde(rename = "app.bsky.richtext.facet#link")]
+    Link {
+        ///URI
+        uri: String,
+    },
+    ///Mention of account
+    #[serde(rename = "app.bsky.richtext.facet#mention")]
+    Mention {
+        ///Account's DID
+        did: String,
+    },
+    ///Hashtag
+    #[serde(rename = "app.bsky.richtext.facet#tag")]
+    Tag {
+        ///Tag without `#`
+        tag: String,
+    },
+}
+
+///Annotation of text's part.
+#[derive(Serialize, Debug)]
+pub struct Facet {
+    index: ByteSlice,
+    features: Vec<Feature>,
+}
+
+impl Facet {
+    ///Creates new instance for bytes `start..end` of text.
+    pub fn new(start: usize, end: usize, feature: Feature) -> Self {
+        Self {
+            index: ByteSlice {
+                byte_start: start,
+                byte_end: end,
+            },
+            features: vec![feature],
+        }
+    }
+}
+
+///Attached image.
+#[derive(Serialize, Debug)]
+pub struct EmbedImage {
+    alt: &'static str,
+    image: serde_json::Value,
+}
+
+///Link's card.
+#[derive(Serialize, Debug)]
+pub struct External<'a> {
+    uri: &'a str,
+    title: &'a str,
+    description: &'a str,
+}
+
+///Embedded content of post.
+#[derive(Serialize, Debug)]
+#[serde(tag = "$type")]
+pub enum Embed<'a> {
+    ///Images
+    #[serde(rename = "app.bsky.embed.images")]
+    Images {
+        ///List of images
+        images: Vec<EmbedImage>,
+    },
+    ///Link's card
+    #[serde(rename = "app.bsky.embed.external")]
+    External {
+        ///Card
+        external: External<'a>,
+    },
+}
+
+impl<'a> Embed<'a> {
+    ///Creates embed of images out of uploaded blobs.
+    pub fn images(blobs: &[serde_json::Value]) -> Self {
+        Embed::Images {
+            images: blobs.iter().map(|blob| EmbedImage { alt: "", image: blob.clone() }).collect(),
+        }
+    }
+
+    ///Creates link's card out of OpenGraph metadata.
+    pub fn external(preview: &'a OpenGraph) -> Self {
+        Embed::External {
+            external: External {
+                uri: &preview.url,
+                title: preview.title.as_ref().map(|title| title.as_str()).unwrap_or(""),
+                description: preview.description.as_ref().map(|description| description.as_str()).unwrap_or(""),
+            }
+        }
+    }
+}
+
+///Label's value
+#[derive(Serialize, Debug)]
+pub struct Label {
+    val: &'static str,
+}
+
+///Labels that author puts on own post.
+#[derive(Serialize, Debug)]
+#[serde(tag = "$type", rename = "com.atproto.label.defs#selfLabels")]
+pub struct SelfLabels {
+    values: Vec<Label>,
+}
+
+impl SelfLabels {
+    ///Creates label that marks post as NSFW.
+    pub fn nsfw() -> Self {
+        Self {
+            values: vec![Label { val: "sexual" }],
+        }
+    }
+}
+
+///Post's record
+#[derive(Serialize, Debug)]
+#[serde(tag = "$type", rename = "app.bsky.feed.post", rename_all = "camelCase")]
+pub struct NewPost<'a> {
+    text: &'a str,
+    created_at: &'a str,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    facets: Vec<Facet>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    embed: Option<Embed<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    labels: Option<SelfLabels>,
+}
+
+impl<'a> NewPost<'a> {
+    ///Creates new instance
+    pub fn new(text: &'a str, created_at: &'a str, facets: Vec<Facet>, embed: Option<Embed<'a>>, nsfw: bool) -> Self {
+        Self {
+            text,
+            created_at,
+            facets,
+            embed,
+            labels: match nsfw {
+                true => Some(SelfLabels::nsfw()),
+                false => None,
+            },
+        }
+    }
+}
+
+///Reference to particular version of record.
+#[derive(Serialize, Debug, Clone)]
+pub struct StrongRef {
+    ///AT URI of record
+    pub uri: String,
+    ///Content's hash
+    pub cid: String,
+}
+
+///Record that refers to post, like repost or like.
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SubjectRecord<'a> {
+    #[serde(rename = "$type")]
+    kind: &'static str,
+    subject: &'a StrongRef,
+    created_at: String,
+}
+
+///Payload to create record in repository.
+#[derive(Serialize, Debug)]
+pub struct CreateRecord<'a, R> {
+    repo: &'a str,
+    collection: &'static str,
+    record: R,
+}
+
+impl<'a> CreateRecord<'a, NewPost<'a>> {
+    ///Creates new instance
+    pub fn new(repo: &'a str, record: NewPost<'a>) -> Self {
+        Self {
+            repo,
+            collection: "app.bsky.feed.post",
+            record,
+        }
+    }
+}
+
+impl<'a> CreateRecord<'a, SubjectRecord<'a>> {
+    ///Creates new instance with record of `collection` (e.g. `app.bsky.feed.like`) that refers to `subject`.
+    pub fn subject(repo: &'a str, collection: &'static str, subject: &'a StrongRef) -> Self {
+        Self {
+            repo,
+            collection,
+            record: SubjectRecord {
+                kind: collection,
+                subject,
+                created_at: crate::data::time::now(),
+            },
+        }
+    }
+}
+
+///Created record
+#[derive(Deserialize, Debug)]
+pub struct Record {
+    ///AT URI of record
+    pub uri: String,
+    ///Content's hash
+    pub cid: String,
+}
+
+///Author of post
+#[derive(Deserialize, Debug)]
+pub struct Author {
+    ///Account's handle
+    pub handle: String,
+}
+
+///Record of post, as it is read
+#[derive(Deserialize, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct PostRecord {
+    ///Text
+    #[serde(default)]
+    pub text: String,
+    ///Time of creation in ISO 8601 format
+    #[serde(default)]
+    pub created_at: Option<String>,
+}
+
+///Post, as it is read
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct PostView {
+    ///AT URI of record
+    pub uri: String,
+    ///Content's hash
+    #[serde(default)]
+    pub cid: String,
+    ///Author
+    pub author: Author,
+    ///Content
+    #[serde(default)]
+    pub record: PostRecord,
+    ///Number of likes
+    #[serde(default)]
+    pub like_count: u64,
+    ///Number of reposts
+    #[serde(default)]
+    pub repost_count: u64,
+    ///Number of quotes
+    #[serde(default)]
+    pub quote_count: u64,
+    ///Number of replies
+    #[serde(default)]
+    pub reply_count: u64,
+}
+
+impl PostView {
+    ///Returns engagement statistics, counting quotes as reposts.
+    pub fn stats(&self) -> crate::data::PostStats {
+        crate::data::PostStats {
+            likes: self.like_count,
+            reposts: self.repost_count + self.quote_count,
+            replies: Some(self.reply_count),
+        }
+    }
+
+    ///Converts into common status
+    pub fn into_status(self) -> crate::data::Status {
+        crate::data::Status {
+            url: super::post_url(&self.author.handle, &self.uri),
+            id: self.uri.into(),
+            author: self.author.handle,
+            timestamp: self.record.created_at.as_ref().and_then(|created_at| crate::data::time::parse(created_at)),
+            text: self.record.text,
+        }
+    }
+}
+
+///Item of feed
+#[derive(Deserialize, Debug)]
+pub struct FeedItem {
+    ///Post
+    pub post: PostView,
+}
+
+///Feed of posts
+#[derive(Deserialize, Debug)]
+pub struct Feed {
+    ///Posts, newest first
+    pub feed: Vec<FeedItem>,
+}
+
+///List of posts
+#[derive(Deserialize, Debug)]
+pub struct Posts {
+    ///Posts
+    pub posts: Vec<PostView>,
+}
+
+///Notification, which is post when it is about mention, reply or quote.
+#[derive(Deserialize, Debug)]
+pub struct Notification {
+    ///AT URI of record
+    pub uri: String,
+    ///Content's hash
+    #[serde(default)]
+    pub cid: String,
+    ///Author of record
+    pub author: Author,
+    ///Reason of notification, e.g. `mention`
+    pub reason: String,
+    ///Record
+    #[serde(default)]
+    pub record: PostRecord,
+}
+
+///List of notifications
+#[derive(Deserialize, Debug)]
+pub struct Notifications {
+    ///Notifications, newest first
+    pub notifications: Vec<Notification>,
+}
@@ -0,0 +1,125 @@
+use std::error::Error;
+use std::fmt;
+
+#[repr(u8)]
+#[derive(Debug)]
+///Bluesky errors
+pub enum BlueskyError {
+    ///Handle or app password is empty.
+    InvalidCredentials,
+    ///Creation of session has failed.
+    LoginFailed,
+    ///Failed to send request to upload image.
+    ImageUploadSendError,
+    ///Server rejected image upload.
+    ImageUploadServerReject,
+    ///Server responded with invalid data.
+    ///
+    ///Should contain `blob`
+    ImageUploadInvalidResponse,
+    ///Failed to send request to perform text post.
+    PostUploadSendError,
+    ///Server rejected posting.
+    PostUploadServerReject,
+    ///Server responded with invalid data
+    ///
+    ///Should contain `uri`
+    PostUploadInvalidResponse,
+    ///Platform cannot attach poll to post.
+    PollUnsupported,
+    ///Text exceeds platform's limit.
+    MessageTooLong,
+    ///More images than post can embed.
+    TooManyImages,
+    ///Failed to send request to read timeline.
+    ReadSendError,
+    ///Server rejected reading of timeline.
+    ReadServerReject,
+    ///Server responded with invalid data to reading of timeline.
+    ReadInvalidResponse,
+    ///Failed to send request to read statistics of post.
+    StatsSendError,
+    ///Server rejected reading of post's statistics.
+    StatsServerReject,
+    ///Server responded with invalid statistics of post.
+    StatsInvalidResponse,
+    ///URL is not link to status.
+    InvalidStatusUrl,
+    ///Failed to send request to resolve status.
+    ResolveSendError,
+    ///Server rejected resolving of status.
+    ResolveServerReject,
+    ///Status cannot be found.
+    StatusNotFound,
+    ///Failed to send request to boost or like status.
+    InteractionSendError,
+    ///Server rejected boost or like of status.
+    InteractionServerReject,
+}
+
+impl BlueskyError {
+    ///Returns short identifier of error's kind.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            &BlueskyError::InvalidCredentials => "invalid_config",
+            &BlueskyError::LoginFailed => "login_failed",
+            &BlueskyError::ImageUploadSendError => "image_upload_send",
+            &BlueskyError::ImageUploadServerReject => "image_upload_rejected",
+            &BlueskyError::ImageUploadInvalidResponse => "image_upload_invalid_response",
+            &BlueskyError::PostUploadSendError => "post_send",
+            &BlueskyError::PostUploadServerReject => "post_rejected",
+            &BlueskyError::PostUploadInvalidResponse => "post_invalid_response",
+            &BlueskyError::PollUnsupported => "unsupported",
+            &BlueskyError::MessageTooLong => "message_too_long",
+            &BlueskyError::TooManyImages => "too_many_images",
+            &BlueskyError::ReadSendError => "read_send",
+            &BlueskyError::ReadServerReject => "read_rejected",
+            &BlueskyError::ReadInvalidResponse => "read_invalid_response",
+            &BlueskyError::StatsSendError => "stats_send",
+            &BlueskyError::StatsServerReject => "stats_rejected",
+            &BlueskyError::StatsInvalidResponse => "stats_invalid_response",
+            &BlueskyError::InvalidStatusUrl => "invalid_url",
+            &BlueskyError::ResolveSendError => "resolve_send",
+            &BlueskyError::ResolveServerReject => "resolve_rejected",
+            &BlueskyError::StatusNotFound => "status_not_found",
+            &BlueskyError::InteractionSendError => "interaction_send",
+            &BlueskyError::InteractionServerReject => "interaction_rejected",
+        }
+    }
+}
+
+impl fmt::Display for BlueskyError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl Error for BlueskyError {
+    fn description(&self) -> &str {
+        match self {
+            &BlueskyError::InvalidCredentials => "Handle or app password is empty",
+            &BlueskyError::LoginFailed => "Login has failed",
+            &BlueskyError::ImageUploadSendError => "Failed to send request to upload image",
+            &BlueskyError::ImageUploadServerReject => "Server rejected upload of image",
+            &BlueskyError::ImageUploadInvalidResponse => "Server sent invalid response. Doesn't contain field blob",
+            &BlueskyError::PostUploadSendError => "Failed to send request to perform text post",
+            &BlueskyError::PostUploadServerReject => "Server rejected posting",
+            &BlueskyError::PostUploadInvalidResponse => "Server sent invalid response. Doesn't contain field uri",
+            &BlueskyError::PollUnsupported => "Polls are not supported",
+            &BlueskyError::MessageTooLong => "Post exceeds 300 characters",
+            &BlueskyError::TooManyImages => "Post has more than 4 images",
+            &BlueskyError::ReadSendError => "Failed to send request to read timeline",
+            &BlueskyError::ReadServerReject => "Server rejected reading of timeline",
+            &BlueskyError::ReadInvalidResponse => "Server sent invalid timeline",
+            &BlueskyError::StatsSendError => "Failed to send request to read statistics of post",
+            &BlueskyError::StatsServerReject => "Server rejected reading of post's statistics",
+            &BlueskyError::StatsInvalidResponse => "Server sent invalid statistics of post",
+            &BlueskyError::InvalidStatusUrl => "URL is not link to status",
+            &BlueskyError::ResolveSendError => "Failed to send request to resolve status",
+            &BlueskyError::ResolveServerReject => "Server rejected resolving of status",
+            &BlueskyError::StatusNotFound => "Status cannot be found",
+            &BlueskyError::InteractionSendError => "Failed to send request to boost or like status",
+            &BlueskyError::InteractionServerReject => "Server rejected boost or like of status",
+        }
+    }
+}
@@ -0,0 +1,433 @@
+//!Bluesky API
+
+use crate::data::PostFlags;
+use crate::data::url::find_urls;
+use super::opengraph::OpenGraph;
+use super::http::{header, GlobalRequest, Mime, Request, matsu};
+use yukikaze::serde_json;
+
+pub mod data;
+mod error;
+
+use data::*;
+pub use error::BlueskyError;
+
+///Default host of accounts.
+pub const HOST: &str = "bsky.social";
+///Maximum number of characters in post.
+pub const MAX_LEN: usize = 300;
+///Maximum number of images in post.
+pub const MAX_IMAGES: usize = 4;
+///Maximum number of characters in hashtag.
+pub const MAX_TAG_LEN: usize = 64;
+
+///Checks whether hashtag is recognized by Bluesky.
+///
+///Hashtag can contain anything but whitespace, as long as it does not end with punctuation and is not only digits.
+pub fn is_valid_hash_tag(tag: &str) -> bool {
+    tag.chars().count() <= MAX_TAG_LEN
+        && !tag.contains(char::is_whitespace)
+        && !tag.ends_with(|ch: char| ch.is_ascii_punctuation())
+        && tag.chars().any(|ch| !ch.is_numeric() && !ch.is_ascii_punctuation())
+}
+
+///Returns position of `part` within `text`, which must be slice of it.
+fn offset(text: &str, part: &str) -> usize {
+    part.as_ptr() as usize - text.as_ptr() as usize
+}
+
+///Finds words within text, that start with `prefix` and consist of characters accepted by `is_char`.
+///
+///Word must be at the beginning of text or after whitespace, and it is returned with prefix.
+fn find_prefixed<F: Fn(char) -> bool>(text: &str, prefix: char, is_char: F) -> Vec<&str> {
+    let mut result = Vec::new();
+
+    for (idx, _) in text.match_indices(prefix) {
+        if idx > 0 && !text[..idx].ends_with(char::is_whitespace) {
+            continue;
+        }
+
+        let rest = &text[idx + prefix.len_utf8()..];
+        let len = rest.find(|ch| !is_char(ch)).unwrap_or(rest.len());
+        let word = rest[..len].trim_end_matches(|ch| ch == '.' || ch == '-');
+
+        if word.len() > 0 {
+            result.push(&text[idx..idx + prefix.len_utf8() + word.len()]);
+        }
+    }
+
+    result
+}
+
+///Finds mentions in form `@handle.domain`
+fn find_mentions(text: &str) -> Vec<&str> {
+    find_prefixed(text, '@', |ch| ch.is_ascii_alphanumeric() || ch == '.' || ch == '-').into_iter().filter(|mention| mention.contains('.')).collect()
+}
+
+///Finds hashtags
+fn find_tags(text: &str) -> Vec<&str> {
+    find_prefixed(text, '#', |ch| !ch.is_whitespace()).into_iter()
+                                                      .map(|tag| tag.trim_end_matches(|ch: char| ch.is_ascii_punctuation()))
+                                                      .filter(|tag| tag.len() > 1 && is_valid_hash_tag(&tag[1..]))
+                                                      .collect()
+}
+
+///Splits URL of post (e.g. `https://bsky.app/profile/<handle>/post/<key>`) into author's handle or DID and record's key.
+fn parse_post_url(url: &str) -> Option<(&str, &str)> {
+    let path = url.split(|ch| ch == '?' || ch == '#').next().unwrap_or(url);
+    let mut parts = path.split("/profile/").nth(1)?.trim_end_matches('/').split('/');
+
+    match (parts.next(), parts.next(), parts.next(), parts.next()) {
+        (Some(actor), Some("post"), Some(key), None) if actor.len() > 0 && key.len() > 0 => Some((actor, key)),
+        _ => None,
+    }
+}
+
+///Returns URL of post with AT URI `uri`.
+fn post_url(handle: &str, uri: &str) -> String {
+    //URI is in format at://<did>/app.bsky.feed.post/<key>
+    let key = uri.rsplit('/').next().unwrap_or("");
+    format!("https://bsky.app/profile/{}/post/{}", handle, key)
+}
+
+///Bluesky API
+pub struct Bluesky {
+    config: crate::config::Bluesky,
+    session: Option<Session>,
+}
+
+impl Bluesky {
+    ///Verifies configuration and creates new instance without session.
+    ///
+    ///Use `login` to create session before posting.
+    pub fn new(mut config: crate::config::Bluesky) -> Result<Self, BlueskyError> {
+        if config.handle.len() == 0 || config.password.len() == 0 {
+            return Err(BlueskyError::InvalidCredentials);
+        }
+
+        if config.host.len() == 0 {
+            config.host.push_str(HOST);
+        }
+
+        Ok(Self {
+            config,
+            session: None,
+        })
+    }
+
+    fn session(&self) -> Result<&Session, BlueskyError> {
+        self.session.as_ref().ok_or(BlueskyError::LoginFailed)
+    }
+
+    ///Creates session using app password.
+    pub async fn login(&mut self) -> Result<(), BlueskyError> {
+        let url = format!("https://{}/xrpc/com.atproto.server.createSession", self.config.host);
+        let req = Request::post(url).map_err(|_| BlueskyError::LoginFailed)?
+                                    .json(&CreateSession::new(&self.config.handle, &self.config.password))
+                                    .expect("To serialize json")
+                                    .global()
+                                    .send();
+
+        let mut resp = match matsu!(req) {
+            Ok(Ok(resp)) => resp,
+            _ => return Err(BlueskyError::LoginFailed),
+        };
+
+        if !resp.is_success() {
+            return Err(BlueskyError::LoginFailed);
+        }
+
+        match matsu!(resp.json::<Session>()) {
+            Ok(session) => {
+                self.session = Some(session);
+                Ok(())
+            },
+            Err(_) => Err(BlueskyError::LoginFailed),
+        }
+    }
+
+    ///Uploads image as blob.
+    ///
+    ///Result is reference to blob, which is used to attach image.
+    pub async fn upload_image(&self, mime: &Mime, data: &[u8]) -> Result<serde_json::Value, BlueskyError> {
+        let session = self.session()?;
+        let url = format!("https://{}/xrpc/com.atproto.repo.uploadBlob", self.config.host);
+        let req = Request::post(url).expect("To create request")
+                                    .bearer_auth(&session.access_jwt)
+                                    .set_header(header::CONTENT_TYPE, mime.as_ref())
+                                    .body(Some(data.to_vec()))
+                                    .global()
+                                    .send();
+
+        // For image we wait twice of time
+        // just to be sure
+        let mut resp = match matsu!(req) {
+            Ok(resp) => resp,
+            Err(err) => match matsu!(matsu!(err)) {
+                Ok(resp) => resp,
+                Err(_) => return Err(BlueskyError::ImageUploadSendError)
+            }
+        }.map_err(|_| BlueskyError::ImageUploadSendError)?;
+
+        if !resp.is_success() {
+            return Err(BlueskyError::ImageUploadServerReject)
+        }
+
+        match matsu!(resp.json::<UploadedBlob>()) {
+            Ok(data) => Ok(data.blob),
+            Err(_) => Err(BlueskyError::ImageUploadInvalidResponse),
+        }
+    }
+
+    ///Resolves handle into DID, returning `None` if handle is unknown.
+    async fn resolve_handle(&self, handle: &str) -> Option<String> {
+        let url = format!("https://{}/xrpc/com.atproto.identity.resolveHandle", self.config.host);
+        let req = Request::get(url).expect("To create request")
+                                   .query(&[("handle", handle)])
+                                   .empty()
+                                   .global()
+                                   .send();
+
+        let mut resp = match matsu!(req) {
+            Ok(Ok(resp)) => resp,
+            _ => return None,
+        };
+
+        if !resp.is_success() {
+            return None;
+        }
+
+        matsu!(resp.json::<ResolvedHandle>()).ok().map(|resolved| resolved.did)
+    }
+
+    ///Annotates links, mentions and hashtags within text.
+    ///
+    ///Mentions of unknown accounts are left as plain text.
+    async fn facets(&self, text: &str) -> Vec<Facet> {
+        let mut facets = Vec::new();
+
+        for url in find_urls(text) {
+            let start = offset(text, url);
+            facets.push(Facet::new(start, start + url.len(), Feature::Link { uri: url.to_owned() }));
+        }
+
+        for mention in find_mentions(text) {
+            if let Some(did) = matsu!(self.resolve_handle(&mention[1..])) {
+                let start = offset(text, mention);
+                facets.push(Facet::new(start, start + mention.len(), Feature::Mention { did }));
+            }
+        }
+
+        for tag in find_tags(text) {
+            let start = offset(text, tag);
+            facets.push(Facet::new(start, start + tag.len(), Feature::Tag { tag: tag[1..].to_owned() }));
+        }
+
+        facets
+    }
+
+    ///Prepares post upload request.
+    ///
+    ///Link's card is attached using `preview` unless there are images.
+    pub async fn post(&self, message: &str, images: &[serde_json::Value], preview: Option<&OpenGraph>, flags: &PostFlags) -> Result<crate::data::PublishedPost, BlueskyError> {
+        if message.chars().count() > MAX_LEN {
+            return Err(BlueskyError::MessageTooLong);
+        }
+
+        let session = self.session()?;
+        let facets = matsu!(self.facets(message));
+        let embed = match (images.len(), preview) {
+            (0, Some(preview)) => Some(Embed::external(preview)),
+            (0, None) => None,
+            _ => Some(Embed::images(images)),
+        };
+        let created_at = crate::data::time::now();
+
+        let url = format!("https://{}/xrpc/com.atproto.repo.createRecord", self.config.host);
+        let req = Request::post(url).expect("To create request")
+                                    .bearer_auth(&session.access_jwt)
+                                    .json(&CreateRecord::new(&session.did, NewPost::new(message, &created_at, facets, embed, flags.nsfw)))
+                                    .expect("To serialize post data")
+                                    .global()
+                                    .send();
+
+        let mut resp = match matsu!(req) {
+            Ok(resp) => resp,
+            Err(err) => match matsu!(matsu!(err)) {
+                Ok(resp) => resp,
+                Err(_) => return Err(BlueskyError::PostUploadSendError)
+            }
+        }.map_err(|_| BlueskyError::PostUploadSendError)?;
+
+        if !resp.is_success() {
+            return Err(BlueskyError::PostUploadServerReject)
+        }
+
+        let record = match matsu!(resp.json::<Record>()) {
+            Ok(record) => record,
+            Err(_) => return Err(BlueskyError::PostUploadInvalidResponse),
+        };
+
+        let url = post_url(&session.handle, &record.uri);
+
+        Ok(crate::data::PublishedPost {
+            id: record.uri.into(),
+            url,
+            created_at: Some(created_at),
+        })
+    }
+
+    ///Performs request to read data from XRPC's `method`.
+    async fn read<T: serde::de::DeserializeOwned>(&self, method: &str, actor: Option<&str>, limit: usize) -> Result<T, BlueskyError> {
+        let session = self.session()?;
+        let url = format!("https://{}/xrpc/{}", self.config.host, method);
+        let limit = limit.to_string();
+        let query = match actor {
+            Some(actor) => vec![("actor", actor), ("limit", limit.as_str())],
+            None => vec![("limit", limit.as_str())],
+        };
+        let req = Request::get(url).map_err(|_| BlueskyError::ReadSendError)?
+                                   .bearer_auth(&session.access_jwt)
+                                   .query(&query)
+                                   .empty()
+                                   .global()
+                                   .send();
+
+        let mut resp = match matsu!(req) {
+            Ok(Ok(resp)) => resp,
+            _ => return Err(BlueskyError::ReadSendError),
+        };
+
+        if !resp.is_success() {
+            return Err(BlueskyError::ReadServerReject)
+        }
+
+        matsu!(resp.json::<T>()).map_err(|_| BlueskyError::ReadInvalidResponse)
+    }
+
+    ///Reads home timeline, newest first.
+    pub async fn home_timeline(&self, limit: usize) -> Result<Vec<crate::data::Status>, BlueskyError> {
+        let feed = matsu!(self.read::<Feed>("app.bsky.feed.getTimeline", None, limit))?;
+        Ok(feed.feed.into_iter().map(|item| item.post.into_status()).collect())
+    }
+
+    ///Reads own posts, newest first.
+    pub async fn own_posts(&self, limit: usize) -> Result<Vec<crate::data::Status>, BlueskyError> {
+        let did = self.session()?.did.clone();
+        let feed = matsu!(self.read::<Feed>("app.bsky.feed.getAuthorFeed", Some(&did), limit))?;
+        Ok(feed.feed.into_iter().map(|item| item.post.into_status()).collect())
+    }
+
+    ///Reads posts that mention, reply to or quote user, newest first.
+    pub async fn mentions(&self, limit: usize) -> Result<Vec<crate::data::Status>, BlueskyError> {
+        let notifications = matsu!(self.read::<Notifications>("app.bsky.notification.listNotifications", None, limit))?;
+        Ok(notifications.notifications.into_iter().filter(|notification| match notification.reason.as_str() {
+            "mention" | "reply" | "quote" => true,
+            _ => false,
+        }).map(|notification| PostView {
+            uri: notification.uri,
+            cid: notification.cid,
+            author: notification.author,
+            record: notification.record,
+            like_count: 0,
+            repost_count: 0,
+            quote_count: 0,
+            reply_count: 0,
+        }.into_status()).collect())
+    }
+
+    ///Reads engagement statistics of post with AT URI `uri`.
+    pub async fn stats(&self, uri: &str) -> Result<crate::data::PostStats, BlueskyError> {
+        let session = self.session()?;
+        let url = format!("https://{}/xrpc/app.bsky.feed.getPosts", self.config.host);
+        let req = Request::get(url).map_err(|_| BlueskyError::StatsSendError)?
+                                   .bearer_auth(&session.access_jwt)
+                                   .query(&[("uris", uri)])
+                                   .empty()
+                                   .global()
+                                   .send();
+
+        let mut resp = match matsu!(req) {
+            Ok(Ok(resp)) => resp,
+            _ => return Err(BlueskyError::StatsSendError),
+        };
+
+        if !resp.is_success() {
+            return Err(BlueskyError::StatsServerReject)
+        }
+
+        match matsu!(resp.json::<Posts>()) {
+            Ok(posts) => match posts.posts.first() {
+                Some(post) => Ok(post.stats()),
+                //Deleted post is simply absent
+                None => Err(BlueskyError::StatsInvalidResponse),
+            },
+            Err(_) => Err(BlueskyError::StatsInvalidResponse),
+        }
+    }
+
+    ///Resolves post's `url` into reference to its record.
+    pub async fn resolve(&self, url: &str) -> Result<StrongRef, BlueskyError> {
+        let session = self.session()?;
+        let (actor, key) = parse_post_url(url).ok_or(BlueskyError::InvalidStatusUrl)?;
+        let did = match actor.starts_with("did:") {
+            true => actor.to_owned(),
+            false => matsu!(self.resolve_handle(actor)).ok_or(BlueskyError::StatusNotFound)?,
+        };
+        let uri = format!("at://{}/app.bsky.feed.post/{}", did, key);
+
+        let posts_url = format!("https://{}/xrpc/app.bsky.feed.getPosts", self.config.host);
+        let req = Request::get(posts_url).map_err(|_| BlueskyError::ResolveSendError)?
+                                         .bearer_auth(&session.access_jwt)
+                                         .query(&[("uris", uri.as_str())])
+                                         .empty()
+                                         .global()
+                                         .send();
+
+        let mut resp = match matsu!(req) {
+            Ok(Ok(resp)) => resp,
+            _ => return Err(BlueskyError::ResolveSendError),
+        };
+
+        if !resp.is_success() {
+            return Err(BlueskyError::ResolveServerReject)
+        }
+
+        match matsu!(resp.json::<Posts>()) {
+            Ok(posts) => posts.posts.into_iter().next().map(|post| StrongRef { uri: post.uri, cid: post.cid }).ok_or(BlueskyError::StatusNotFound),
+            Err(_) => Err(BlueskyError::StatusNotFound),
+        }
+    }
+
+    ///Creates record of `collection` that refers to `subject`.
+    async fn interact(&self, collection: &'static str, subject: &StrongRef) -> Result<(), BlueskyError> {
+        let session = self.session()?;
+        let url = format!("https://{}/xrpc/com.atproto.repo.createRecord", self.config.host);
+        let req = Request::post(url).map_err(|_| BlueskyError::InteractionSendError)?
+                                    .bearer_auth(&session.access_jwt)
+                                    .json(&CreateRecord::subject(&session.did, collection, subject))
+                                    .expect("To serialize json")
+                                    .global()
+                                    .send();
+
+        let resp = match matsu!(req) {
+            Ok(Ok(resp)) => resp,
+            _ => return Err(BlueskyError::InteractionSendError),
+        };
+
+        match resp.is_success() {
+            true => Ok(()),
+            false => Err(BlueskyError::InteractionServerReject),
+        }
+    }
+
+    ///Reposts post, referred by `subject`.
+    pub async fn boost(&self, subject: &StrongRef) -> Result<(), BlueskyError> {
+        matsu!(self.interact("app.bsky.feed.repost", subject))
+    }
+
+    ///Likes post, referred by `subject`.
+    pub async fn like(&self, subject: &StrongRef) -> Result<(), BlueskyError> {
+        matsu!(self.interact("app.bsky.feed.like", subject))
+    }
+}
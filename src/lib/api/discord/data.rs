@@ -0,0 +1,67 @@
+//! Discord API data types
+
+use serde_derive::{Serialize, Deserialize};
+
+///Sent message.
+#[derive(Deserialize, Debug)]
+pub struct Message {
+    ///Identifier
+    pub id: String,
+    ///Channel's identifier
+    pub channel_id: String,
+    ///Server's identifier, if known
+    #[serde(default)]
+    pub guild_id: Option<String>,
+    ///Time of sending in ISO 8601 format
+    #[serde(default)]
+    pub timestamp: Option<String>,
+}
+
+///Information about webhook.
+#[derive(Deserialize, Debug)]
+pub struct Webhook {
+    ///Server's identifier
+    #[serde(default)]
+    pub guild_id: Option<String>,
+}
+
+///Image of embed.
+#[derive(Serialize, Debug)]
+pub struct EmbedImage {
+    url: String,
+}
+
+///Rich content of message.
+#[derive(Serialize, Debug)]
+pub struct Embed {
+    image: EmbedImage,
+}
+
+impl Embed {
+    ///Creates embed, showing attached file.
+    pub fn attachment(name: &str) -> Self {
+        Self {
+            image: EmbedImage {
+                url: format!("attachment://{}", name),
+            }
+        }
+    }
+}
+
+///Message to execute webhook with.
+#[derive(Serialize, Debug)]
+pub struct NewMessage<'a> {
+    content: &'a str,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    embeds: Vec<Embed>,
+}
+
+impl<'a> NewMessage<'a> {
+    ///Creates new instance
+    pub fn new(content: &'a str, embeds: Vec<Embed>) -> Self {
+        Self {
+            content,
+            embeds,
+        }
+    }
+}
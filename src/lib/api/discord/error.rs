@@ -0,0 +1,55 @@
+use std::error::Error;
+use std::fmt;
+
+#[repr(u8)]
+#[derive(Debug)]
+///Discord errors
+pub enum DiscordError {
+    ///Webhook's URL is not valid HTTPS URI.
+    InvalidWebhook,
+    ///Failed to send request to perform post.
+    PostUploadSendError,
+    ///Server rejected posting.
+    PostUploadServerReject,
+    ///Server responded with invalid data
+    ///
+    ///Should contain `id`
+    PostUploadInvalidResponse,
+    ///Platform cannot attach poll to post.
+    PollUnsupported,
+    ///Text exceeds platform's limit.
+    MessageTooLong,
+}
+
+impl DiscordError {
+    ///Returns short identifier of error's kind.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            &DiscordError::InvalidWebhook => "invalid_config",
+            &DiscordError::PostUploadSendError => "post_send",
+            &DiscordError::PostUploadServerReject => "post_rejected",
+            &DiscordError::PostUploadInvalidResponse => "post_invalid_response",
+            &DiscordError::PollUnsupported => "unsupported",
+            &DiscordError::MessageTooLong => "message_too_long",
+        }
+    }
+}
+
+impl fmt::Display for DiscordError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl Error for DiscordError {
+    fn description(&self) -> &str {
+        match self {
+            &DiscordError::InvalidWebhook => "Webhook is not valid HTTPS URI",
+            &DiscordError::PostUploadSendError => "Failed to send request to perform post",
+            &DiscordError::PostUploadServerReject => "Server rejected posting",
+            &DiscordError::PostUploadInvalidResponse => "Server sent invalid response. Doesn't contain field id",
+            &DiscordError::PollUnsupported => "Polls are not supported",
+            &DiscordError::MessageTooLong => "Message exceeds 2000 characters",
+        }
+    }
+}
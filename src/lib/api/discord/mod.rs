@@ -0,0 +1,122 @@
+//!Discord webhook API
+
+use super::http::{multipart, Uri, GlobalRequest, Request, matsu};
+use crate::data::{Image, PostFlags};
+use yukikaze::serde_json;
+
+pub mod data;
+mod error;
+
+pub use error::DiscordError;
+
+///Maximum number of characters in message.
+pub const MAX_LEN: usize = 2000;
+///Maximum number of images in message.
+pub const MAX_IMAGES: usize = 10;
+
+///Discord API
+pub struct Discord {
+    config: crate::config::Discord,
+}
+
+impl Discord {
+    ///Verifies configuration and creates new instances.
+    pub fn new(config: crate::config::Discord) -> Result<Self, DiscordError> {
+        match config.webhook.parse::<Uri>() {
+            Ok(ref uri) if uri.scheme_str() == Some("https") => (),
+            _ => {
+                return Err(DiscordError::InvalidWebhook);
+            }
+        }
+
+        Ok(Self {
+            config
+        })
+    }
+
+    ///Retrieves ID of webhook's server, which is needed for link to message.
+    async fn guild_id(&self) -> Option<String> {
+        let req = Request::get(&self.config.webhook).expect("To create request").empty().global().send();
+
+        let mut resp = match matsu!(req) {
+            Ok(Ok(resp)) => resp,
+            _ => return None,
+        };
+
+        if !resp.is_success() {
+            return None;
+        }
+
+        matsu!(resp.json::<data::Webhook>()).ok().and_then(|webhook| webhook.guild_id)
+    }
+
+    ///Prepares post request, attaching images as files.
+    ///
+    ///Images are shown as embeds, unless post is NSFW, in which case they are attached as spoilers.
+    pub async fn post(&self, message: &str, images: &[Image], flags: &PostFlags) -> Result<crate::data::PublishedPost, DiscordError> {
+        if message.chars().count() > MAX_LEN {
+            return Err(DiscordError::MessageTooLong);
+        }
+
+        let mut form = multipart::Form::new();
+        let mut embeds = vec![];
+
+        for (idx, image) in images.iter().take(MAX_IMAGES).enumerate() {
+            //Embed refers to attachment by name, which must be unique even if files share it
+            let name = match std::path::Path::new(&image.name).extension().and_then(|ext| ext.to_str()) {
+                Some(ext) => format!("image{}.{}", idx, ext),
+                None => format!("image{}.{}", idx, image.mime.subtype()),
+            };
+
+            //Embed would reveal spoiler
+            let name = match flags.nsfw {
+                true => format!("SPOILER_{}", name),
+                false => {
+                    embeds.push(data::Embed::attachment(&name));
+                    name
+                },
+            };
+
+            form.add_file_field(format!("files[{}]", idx), name, &image.mime, &image.mmap[..]);
+        }
+
+        let payload = serde_json::to_string(&data::NewMessage::new(message, embeds)).expect("To serialize post data");
+        form.add_field("payload_json".to_string(), payload.as_bytes());
+
+        //Without wait, Discord doesn't return message
+        let url = match self.config.webhook.contains('?') {
+            true => format!("{}&wait=true", self.config.webhook),
+            false => format!("{}?wait=true", self.config.webhook),
+        };
+        let req = Request::post(url).expect("To create request").multipart(form).global().send();
+
+        let mut resp = match matsu!(req) {
+            Ok(resp) => resp,
+            Err(err) => match matsu!(matsu!(err)) {
+                Ok(resp) => resp,
+                Err(_) => return Err(DiscordError::PostUploadSendError)
+            }
+        }.map_err(|_| DiscordError::PostUploadSendError)?;
+
+        if !resp.is_success() {
+            return Err(DiscordError::PostUploadServerReject)
+        }
+
+        let message = match matsu!(resp.json::<data::Message>()) {
+            Ok(message) => message,
+            Err(_) => return Err(DiscordError::PostUploadInvalidResponse),
+        };
+
+        let guild_id = match message.guild_id {
+            Some(guild_id) => Some(guild_id),
+            None => matsu!(self.guild_id()),
+        };
+        let url = format!("https://discord.com/channels/{}/{}/{}", guild_id.as_ref().map(|id| id.as_str()).unwrap_or("@me"), message.channel_id, message.id);
+
+        Ok(crate::data::PublishedPost {
+            id: message.id.into(),
+            url,
+            created_at: message.timestamp,
+        })
+    }
+}
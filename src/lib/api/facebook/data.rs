@@ -0,0 +1,60 @@
+//! Facebook Graph API data types
+
+use serde_derive::{Serialize, Deserialize};
+
+///Generic payload for response that contains entity's ID.
+#[derive(Deserialize, Debug)]
+pub struct EntityId {
+    ///Identifier
+    pub id: String,
+}
+
+///Attached photo.
+#[derive(Serialize, Debug)]
+pub struct AttachedMedia<'a> {
+    media_fbid: &'a str,
+}
+
+impl<'a> AttachedMedia<'a> {
+    ///Creates new instance out of uploaded photo's ID.
+    pub fn new(media_fbid: &'a str) -> Self {
+        Self {
+            media_fbid
+        }
+    }
+}
+
+///Payload to create post on page's feed.
+#[derive(Serialize, Debug)]
+pub struct NewPost<'a> {
+    access_token: &'a str,
+    message: &'a str,
+    ///JSON array of `AttachedMedia`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    attached_media: Option<String>,
+}
+
+impl<'a> NewPost<'a> {
+    ///Creates new instance
+    pub fn new(access_token: &'a str, message: &'a str, attached_media: Option<String>) -> Self {
+        Self {
+            access_token,
+            message,
+            attached_media,
+        }
+    }
+}
+
+///Page's information
+#[derive(Deserialize, Debug)]
+pub struct Page {
+    ///Page's access token
+    pub access_token: String,
+}
+
+///Access token
+#[derive(Deserialize, Debug)]
+pub struct AccessToken {
+    ///Token
+    pub access_token: String,
+}
@@ -0,0 +1,65 @@
+use std::error::Error;
+use std::fmt;
+
+#[repr(u8)]
+#[derive(Debug)]
+///Facebook errors
+pub enum FacebookError {
+    ///Access token or page is empty.
+    InvalidToken,
+    ///Failed to send request to upload image.
+    ImageUploadSendError,
+    ///Server rejected image upload.
+    ImageUploadServerReject,
+    ///Server responded with invalid data.
+    ///
+    ///Should contain `id`
+    ImageUploadInvalidResponse,
+    ///Failed to send request to perform text post.
+    PostUploadSendError,
+    ///Server rejected posting.
+    PostUploadServerReject,
+    ///Server responded with invalid data
+    ///
+    ///Should contain `id`
+    PostUploadInvalidResponse,
+    ///Platform cannot attach poll to post.
+    PollUnsupported,
+}
+
+impl FacebookError {
+    ///Returns short identifier of error's kind.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            &FacebookError::InvalidToken => "invalid_config",
+            &FacebookError::ImageUploadSendError => "image_upload_send",
+            &FacebookError::ImageUploadServerReject => "image_upload_rejected",
+            &FacebookError::ImageUploadInvalidResponse => "image_upload_invalid_response",
+            &FacebookError::PostUploadSendError => "post_send",
+            &FacebookError::PostUploadServerReject => "post_rejected",
+            &FacebookError::PostUploadInvalidResponse => "post_invalid_response",
+            &FacebookError::PollUnsupported => "unsupported",
+        }
+    }
+}
+
+impl fmt::Display for FacebookError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl Error for FacebookError {
+    fn description(&self) -> &str {
+        match self {
+            &FacebookError::InvalidToken => "Token or page is not valid(empty)",
+            &FacebookError::ImageUploadSendError => "Failed to send request to upload image",
+            &FacebookError::ImageUploadServerReject => "Server rejected upload of image",
+            &FacebookError::ImageUploadInvalidResponse => "Server sent invalid response. Doesn't contain field id",
+            &FacebookError::PostUploadSendError => "Failed to send request to perform text post",
+            &FacebookError::PostUploadServerReject => "Server rejected posting",
+            &FacebookError::PostUploadInvalidResponse => "Server sent invalid response. Doesn't contain field id",
+            &FacebookError::PollUnsupported => "Polls are not supported",
+        }
+    }
+}
@@ -0,0 +1,105 @@
+//!Facebook Graph API
+
+use super::http::{multipart, GlobalRequest, Mime, Request, matsu};
+use yukikaze::serde_json;
+
+pub mod data;
+mod error;
+
+pub use error::FacebookError;
+
+///Base URL of Graph API, including its version.
+pub const GRAPH_URL: &str = "https://graph.facebook.com/v18.0";
+
+///Facebook API
+pub struct Facebook {
+    config: crate::config::Facebook,
+}
+
+impl Facebook {
+    ///Verifies configuration and creates new instances.
+    pub fn new(config: crate::config::Facebook) -> Result<Self, FacebookError> {
+        if config.access_token.len() == 0 || config.page_id.len() == 0 {
+            return Err(FacebookError::InvalidToken);
+        }
+
+        Ok(Self {
+            config
+        })
+    }
+
+    ///Prepares photo upload request.
+    ///
+    ///Photo is not published on its own, but attached to post later.
+    ///
+    ///Result contains `id` from `EntityId`
+    pub async fn upload_image(&self, name: &str, mime: &Mime, data: &[u8]) -> Result<String, FacebookError> {
+        let url = format!("{}/{}/photos", GRAPH_URL, self.config.page_id);
+        let mut form = multipart::Form::new();
+
+        form.add_field("access_token".to_string(), self.config.access_token.as_bytes());
+        form.add_field("published".to_string(), b"false");
+        form.add_file_field("source".to_string(), name.to_string(), mime, data);
+
+        let req = Request::post(url).expect("To create request").multipart(form).global().send();
+
+        // For image we wait twice of time
+        // just to be sure
+        let mut resp = match matsu!(req) {
+            Ok(resp) => resp,
+            Err(err) => match matsu!(matsu!(err)) {
+                Ok(resp) => resp,
+                Err(_) => return Err(FacebookError::ImageUploadSendError)
+            }
+        }.map_err(|_| FacebookError::ImageUploadSendError)?;
+
+        if !resp.is_success() {
+            return Err(FacebookError::ImageUploadServerReject)
+        }
+
+        match matsu!(resp.json::<data::EntityId>()) {
+            Ok(data) => Ok(data.id),
+            Err(_) => Err(FacebookError::ImageUploadInvalidResponse),
+        }
+    }
+
+    ///Prepares post upload request.
+    pub async fn post(&self, message: &str, photos: &[String]) -> Result<crate::data::PublishedPost, FacebookError> {
+        let url = format!("{}/{}/feed", GRAPH_URL, self.config.page_id);
+        let attached_media = match photos.len() {
+            0 => None,
+            _ => {
+                let media = photos.iter().map(|id| data::AttachedMedia::new(id)).collect::<Vec<_>>();
+                Some(serde_json::to_string(&media).expect("To serialize attached media"))
+            }
+        };
+
+        let req = Request::post(url).expect("To create request")
+                                    .form(&data::NewPost::new(&self.config.access_token, message, attached_media))
+                                    .expect("To serialzie post data")
+                                    .global()
+                                    .send();
+
+        let mut resp = match matsu!(req) {
+            Ok(resp) => resp,
+            Err(err) => match matsu!(matsu!(err)) {
+                Ok(resp) => resp,
+                Err(_) => return Err(FacebookError::PostUploadSendError)
+            }
+        }.map_err(|_| FacebookError::PostUploadSendError)?;
+
+        if !resp.is_success() {
+            return Err(FacebookError::PostUploadServerReject)
+        }
+
+        //ID is in form <page id>_<post id>
+        match matsu!(resp.json::<data::EntityId>()) {
+            Ok(data) => Ok(crate::data::PublishedPost {
+                url: format!("https://www.facebook.com/{}", data.id),
+                id: data.id.into(),
+                created_at: None,
+            }),
+            Err(_) => Err(FacebookError::PostUploadInvalidResponse),
+        }
+    }
+}
@@ -21,7 +21,50 @@ pub enum GabError {
     ///
     ///Should contain `id`
     PostUploadInvalidResponse,
+    ///Platform cannot attach poll together with images.
+    PollWithImagesUnsupported,
+    ///Failed to send request to read timeline.
+    ReadSendError,
+    ///Server rejected reading of timeline.
+    ReadServerReject,
+    ///Server responded with invalid data to reading of timeline.
+    ReadInvalidResponse,
+    ///Failed to send request to read statistics of post.
+    StatsSendError,
+    ///Server rejected reading of post's statistics.
+    StatsServerReject,
+    ///Server responded with invalid statistics of post.
+    StatsInvalidResponse,
+    ///URL is not link to status.
+    InvalidStatusUrl,
+    ///Failed to send request to boost or like status.
+    InteractionSendError,
+    ///Server rejected boost or like of status.
+    InteractionServerReject,
+}
 
+impl GabError {
+    ///Returns short identifier of error's kind.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            &GabError::ImageUploadSendError => "image_upload_send",
+            &GabError::ImageUploadServerReject => "image_upload_rejected",
+            &GabError::ImageUploadInvalidResponse => "image_upload_invalid_response",
+            &GabError::PostUploadSendError => "post_send",
+            &GabError::PostUploadServerReject => "post_rejected",
+            &GabError::PostUploadInvalidResponse => "post_invalid_response",
+            &GabError::PollWithImagesUnsupported => "unsupported",
+            &GabError::ReadSendError => "read_send",
+            &GabError::ReadServerReject => "read_rejected",
+            &GabError::ReadInvalidResponse => "read_invalid_response",
+            &GabError::StatsSendError => "stats_send",
+            &GabError::StatsServerReject => "stats_rejected",
+            &GabError::StatsInvalidResponse => "stats_invalid_response",
+            &GabError::InvalidStatusUrl => "invalid_url",
+            &GabError::InteractionSendError => "interaction_send",
+            &GabError::InteractionServerReject => "interaction_rejected",
+        }
+    }
 }
 
 impl fmt::Display for GabError {
@@ -39,6 +82,16 @@ impl Error for GabError {
             &GabError::PostUploadSendError => "Failed to send request to perform text post",
             &GabError::PostUploadServerReject => "Server rejected posting",
             &GabError::PostUploadInvalidResponse => "Server sent invalid response. Doesn't contain field id",
+            &GabError::PollWithImagesUnsupported => "Poll cannot be attached together with images",
+            &GabError::ReadSendError => "Failed to send request to read timeline",
+            &GabError::ReadServerReject => "Server rejected reading of timeline",
+            &GabError::ReadInvalidResponse => "Server sent invalid timeline",
+            &GabError::StatsSendError => "Failed to send request to read statistics of post",
+            &GabError::StatsServerReject => "Server rejected reading of post's statistics",
+            &GabError::StatsInvalidResponse => "Server sent invalid statistics of post",
+            &GabError::InvalidStatusUrl => "URL is not link to status",
+            &GabError::InteractionSendError => "Failed to send request to boost or like status",
+            &GabError::InteractionServerReject => "Server rejected boost or like of status",
         }
     }
 }
@@ -3,15 +3,30 @@
 pub mod data;
 mod error;
 
+const HOST: &'static str = "gab.com";
 const IMAGES_URL: &'static str = "https://gab.com/api/v1/media";
 const POST_URL: &'static str = "https://gab.com/api/v1/statuses";
 
-use crate::data::PostFlags;
+///Maximum number of characters in status.
+pub const MAX_LEN: usize = 3000;
+
+use crate::data::{PostFlags, Poll};
 use data::*;
 pub use error::GabError;
 
 use super::http::{multipart, GlobalRequest, Mime, Request, matsu};
 
+///Extracts ID of status out of its URL (e.g. `https://gab.com/user/posts/123`).
+pub fn status_id(url: &str) -> Option<&str> {
+    let path = url.split(|ch| ch == '?' || ch == '#').next().unwrap_or(url);
+    let mut parts = path.trim_end_matches('/').rsplit('/');
+    let id = parts.next()?;
+    match parts.next() {
+        Some("posts") if id.len() > 0 && id.bytes().all(|byte| byte.is_ascii_digit()) => Some(id),
+        _ => None,
+    }
+}
+
 ///Gab API
 pub struct Gab {
     token: String,
@@ -56,10 +71,10 @@ impl Gab {
     }
 
     ///Prepares post upload request.
-    pub async fn post(&self, message: &str, media_attachments: &[String], flags: &PostFlags) -> Result<crate::data::PostId, GabError> {
+    pub async fn post(&self, message: &str, media_attachments: &[String], flags: &PostFlags, poll: Option<&Poll>) -> Result<crate::data::PublishedPost, GabError> {
         let req = Request::post(POST_URL).expect("To create request")
                                          .bearer_auth(self.token.as_str())
-                                         .json(&NewStatus::new(&message, &media_attachments, &flags))
+                                         .json(&NewStatus::new(&message, &media_attachments, &flags, poll, None))
                                          .expect("To serialzie post data")
                                          .global()
                                          .send();
@@ -76,9 +91,108 @@ impl Gab {
             return Err(GabError::PostUploadServerReject)
         }
 
-        match matsu!(resp.json::<EntityId>()) {
-            Ok(data) => Ok(data.id.into()),
+        match matsu!(resp.json::<Status>()) {
+            Ok(data) => Ok(data.into_published(HOST)),
             Err(_) => Err(GabError::PostUploadInvalidResponse),
         }
     }
+
+    ///Performs request to read statuses from API's `path`.
+    async fn read<T: serde::de::DeserializeOwned>(&self, path: &str, limit: usize) -> Result<T, GabError> {
+        let url = format!("https://{}{}", HOST, path);
+        let req = Request::get(url).map_err(|_| GabError::ReadSendError)?
+                                   .bearer_auth(self.token.as_str())
+                                   .query(&[("limit", limit)])
+                                   .empty()
+                                   .global()
+                                   .send();
+
+        let mut resp = match matsu!(req) {
+            Ok(Ok(resp)) => resp,
+            _ => return Err(GabError::ReadSendError),
+        };
+
+        if !resp.is_success() {
+            return Err(GabError::ReadServerReject)
+        }
+
+        matsu!(resp.json::<T>()).map_err(|_| GabError::ReadInvalidResponse)
+    }
+
+    ///Reads home timeline, newest first.
+    pub async fn home_timeline(&self, limit: usize) -> Result<Vec<crate::data::Status>, GabError> {
+        let statuses = matsu!(self.read::<Vec<Status>>("/api/v1/timelines/home", limit))?;
+        Ok(statuses.into_iter().map(|status| status.into_status(HOST)).collect())
+    }
+
+    ///Reads own statuses, newest first.
+    pub async fn own_posts(&self, limit: usize) -> Result<Vec<crate::data::Status>, GabError> {
+        let account = matsu!(self.read::<Account>("/api/v1/accounts/verify_credentials", limit))?;
+        let path = format!("/api/v1/accounts/{}/statuses", account.id);
+        let statuses = matsu!(self.read::<Vec<Status>>(&path, limit))?;
+        Ok(statuses.into_iter().map(|status| status.into_status(HOST)).collect())
+    }
+
+    ///Reads statuses that mention user, newest first.
+    pub async fn mentions(&self, limit: usize) -> Result<Vec<crate::data::Status>, GabError> {
+        let notifications = matsu!(self.read::<Vec<Notification>>("/api/v1/notifications", limit))?;
+        Ok(notifications.into_iter().filter(|notification| notification.kind == "mention")
+                                    .filter_map(|notification| notification.status)
+                                    .map(|status| status.into_status(HOST))
+                                    .collect())
+    }
+
+    ///Reads engagement statistics of status with `id`.
+    pub async fn stats(&self, id: &str) -> Result<crate::data::PostStats, GabError> {
+        let url = format!("{}/{}", POST_URL, id);
+        let req = Request::get(url).map_err(|_| GabError::StatsSendError)?
+                                   .bearer_auth(self.token.as_str())
+                                   .empty()
+                                   .global()
+                                   .send();
+
+        let mut resp = match matsu!(req) {
+            Ok(Ok(resp)) => resp,
+            _ => return Err(GabError::StatsSendError),
+        };
+
+        if !resp.is_success() {
+            return Err(GabError::StatsServerReject)
+        }
+
+        match matsu!(resp.json::<Status>()) {
+            Ok(status) => Ok(status.stats()),
+            Err(_) => Err(GabError::StatsInvalidResponse),
+        }
+    }
+
+    ///Performs action on status with `id`, e.g. `reblog`.
+    async fn interact(&self, id: &str, action: &str) -> Result<(), GabError> {
+        let url = format!("{}/{}/{}", POST_URL, id, action);
+        let req = Request::post(url).map_err(|_| GabError::InteractionSendError)?
+                                    .bearer_auth(self.token.as_str())
+                                    .empty()
+                                    .global()
+                                    .send();
+
+        let resp = match matsu!(req) {
+            Ok(Ok(resp)) => resp,
+            _ => return Err(GabError::InteractionSendError),
+        };
+
+        match resp.is_success() {
+            true => Ok(()),
+            false => Err(GabError::InteractionServerReject),
+        }
+    }
+
+    ///Reposts status with `id`.
+    pub async fn boost(&self, id: &str) -> Result<(), GabError> {
+        matsu!(self.interact(id, "reblog"))
+    }
+
+    ///Favourites status with `id`.
+    pub async fn like(&self, id: &str) -> Result<(), GabError> {
+        matsu!(self.interact(id, "favourite"))
+    }
 }
@@ -28,12 +28,33 @@ impl Config for Conf {
     }
 }
 
+///Yukikaze config, that allows plain HTTP.
+pub struct PlainConf;
+
+impl Config for PlainConf {
+    type Connector = yukikaze::connector::rustls::HttpsConnector;
+    type Timer = yukikaze::client::config::DefaultTimer;
+
+    fn timeout() -> Duration {
+        get_timeout()
+    }
+}
+
 mod gen {
     use super::Conf;
 
     yukikaze::declare_global_client!(Conf);
 }
 
+///Client for hosts that user configured with `http://` explicitly.
+///
+///It must not be used for anything else, as global client accepts only HTTPS.
+pub mod plain {
+    use super::PlainConf;
+
+    yukikaze::declare_global_client!(PlainConf);
+}
+
 pub use gen::GlobalRequest;
 
 ///Sets current timeout value;
@@ -0,0 +1,189 @@
+//!Boosts and likes of statuses
+
+use super::{API, ApiError, post_result};
+use super::{twitter, gab, Mastodon, Misskey};
+use super::{TwitterError, GabError, MastodonError, BlueskyError, MisskeyError};
+use super::http::{Uri, matsu};
+use crate::data::PostId;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///Action to perform on status.
+pub enum Interaction {
+    ///Boost, repost or retweet.
+    Boost,
+    ///Like or favourite.
+    Like,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Fediverse {
+    Mastodon,
+    Pleroma,
+    Misskey,
+}
+
+///Results of interaction on each platform, containing ID of status on it.
+///
+///Platform is `None` when it is not enabled or cannot open status.
+pub struct Interactions {
+    ///Twitter's result
+    pub twitter: Option<Result<PostId, ApiError>>,
+    ///Gab's result
+    pub gab: Option<Result<PostId, ApiError>>,
+    ///Mastodon's result
+    pub mastodon: Option<Result<PostId, ApiError>>,
+    ///Bluesky's result
+    pub bluesky: Option<Result<PostId, ApiError>>,
+    ///Pleroma's result
+    pub pleroma: Option<Result<PostId, ApiError>>,
+    ///Misskey's result
+    pub misskey: Option<Result<PostId, ApiError>>,
+}
+
+impl API {
+    ///Selects single fediverse account to open status from `host`.
+    ///
+    ///Account on the same instance is preferred, otherwise first configured of Mastodon, Pleroma and Misskey is used.
+    fn fediverse_account(&self, host: &str) -> Option<Fediverse> {
+        let accounts = [
+            (Fediverse::Mastodon, self.mastodon.as_ref().map(Mastodon::host)),
+            (Fediverse::Pleroma, self.pleroma.as_ref().map(Mastodon::host)),
+            (Fediverse::Misskey, self.misskey.as_ref().map(Misskey::host)),
+        ];
+
+        accounts.iter().find(|(_, account)| account.map(|account| account.trim_start_matches("www.").eq_ignore_ascii_case(host)).unwrap_or(false))
+                       .or_else(|| accounts.iter().find(|(_, account)| account.is_some()))
+                       .map(|(platform, _)| *platform)
+    }
+
+    ///Performs `interaction` on status at `url`.
+    ///
+    ///URLs of Twitter, Bluesky and Gab are handled only by corresponding platform, while Minds' ones are unsupported.
+    ///Any other URL is considered to be from fediverse, and single account resolves it through own instance,
+    ///so that the same person doesn't boost status multiple times.
+    pub async fn interact(&self, url: &str, interaction: Interaction) -> Result<Interactions, ApiError> {
+        let uri = url.parse::<Uri>().map_err(|_| ApiError::InvalidStatusUrl(url.to_owned()))?;
+        let host = match uri.host() {
+            Some(host) => host.trim_start_matches("www.").trim_start_matches("mobile."),
+            None => return Err(ApiError::InvalidStatusUrl(url.to_owned())),
+        };
+
+        let is_twitter = host == "twitter.com" || host == "x.com";
+        let is_bluesky = host == "bsky.app";
+        let is_gab = host == "gab.com";
+        if host == "minds.com" {
+            return Err(ApiError::UnsupportedStatusUrl(url.to_owned()));
+        }
+        let fediverse = match !is_twitter && !is_bluesky && !is_gab {
+            true => self.fediverse_account(host),
+            false => None,
+        };
+
+        let twitter = post_result(self.twitter.as_ref().filter(|_| is_twitter).map(|twitter| async move {
+            let id = twitter::status_id(url).ok_or(TwitterError::InvalidStatusUrl)?;
+            match interaction {
+                Interaction::Boost => matsu!(twitter.boost(id))?,
+                Interaction::Like => matsu!(twitter.like(id))?,
+            }
+            Ok::<PostId, TwitterError>(id.to_owned().into())
+        }));
+
+        let gab = post_result(self.gab.as_ref().filter(|_| is_gab).map(|gab| async move {
+            let id = gab::status_id(url).ok_or(GabError::InvalidStatusUrl)?;
+            match interaction {
+                Interaction::Boost => matsu!(gab.boost(id))?,
+                Interaction::Like => matsu!(gab.like(id))?,
+            }
+            Ok::<PostId, GabError>(id.to_owned().into())
+        }));
+
+        let mastodon = post_result(self.mastodon.as_ref().filter(|_| fediverse == Some(Fediverse::Mastodon)).map(|mastodon| async move {
+            let id = matsu!(mastodon.resolve(url))?;
+            match interaction {
+                Interaction::Boost => matsu!(mastodon.boost(&id))?,
+                Interaction::Like => matsu!(mastodon.like(&id))?,
+            }
+            Ok::<PostId, MastodonError>(id.into())
+        }));
+
+        let bluesky = post_result(self.bluesky.as_ref().filter(|_| is_bluesky).map(|bluesky| async move {
+            let subject = matsu!(bluesky.resolve(url))?;
+            match interaction {
+                Interaction::Boost => matsu!(bluesky.boost(&subject))?,
+                Interaction::Like => matsu!(bluesky.like(&subject))?,
+            }
+            Ok::<PostId, BlueskyError>(subject.uri.into())
+        }));
+
+        let pleroma = post_result(self.pleroma.as_ref().filter(|_| fediverse == Some(Fediverse::Pleroma)).map(|pleroma| async move {
+            let id = matsu!(pleroma.resolve(url)).map_err(ApiError::Pleroma)?;
+            match interaction {
+                Interaction::Boost => matsu!(pleroma.boost(&id)).map_err(ApiError::Pleroma)?,
+                Interaction::Like => matsu!(pleroma.like(&id)).map_err(ApiError::Pleroma)?,
+            }
+            Ok::<PostId, ApiError>(id.into())
+        }));
+
+        let misskey = post_result(self.misskey.as_ref().filter(|_| fediverse == Some(Fediverse::Misskey)).map(|misskey| async move {
+            let id = matsu!(misskey.resolve(url))?;
+            match interaction {
+                Interaction::Boost => matsu!(misskey.boost(&id))?,
+                Interaction::Like => matsu!(misskey.like(&id))?,
+            }
+            Ok::<PostId, MisskeyError>(id.into())
+        }));
+
+        let (twitter, gab, mastodon, bluesky, pleroma, misskey) = futures_util::join!(twitter, gab, mastodon, bluesky, pleroma, misskey);
+
+        Ok(Interactions {
+            twitter,
+            gab,
+            mastodon,
+            bluesky,
+            pleroma,
+            misskey,
+        })
+    }
+
+    ///Boosts status at `url` on each platform that can open it.
+    pub async fn boost(&self, url: &str) -> Result<Interactions, ApiError> {
+        matsu!(self.interact(url, Interaction::Boost))
+    }
+
+    ///Likes status at `url` on each platform that can open it.
+    pub async fn like(&self, url: &str) -> Result<Interactions, ApiError> {
+        matsu!(self.interact(url, Interaction::Like))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config;
+
+    fn api(mastodon: Option<&str>, pleroma: Option<&str>, misskey: Option<&str>) -> API {
+        let mut api = API::new(config::Settings::default());
+        if let Some(host) = mastodon {
+            api.configure_mastodon(config::Mastodon { host: host.to_owned(), access_token: "token".to_owned() }).expect("To configure Mastodon");
+        }
+        if let Some(host) = pleroma {
+            api.configure_pleroma(config::Pleroma { host: host.to_owned(), access_token: "token".to_owned(), ..Default::default() }).expect("To configure Pleroma");
+        }
+        if let Some(host) = misskey {
+            api.configure_misskey(config::Misskey { host: host.to_owned(), access_token: "token".to_owned() }).expect("To configure Misskey");
+        }
+        api
+    }
+
+    #[test]
+    fn should_select_fediverse_account() {
+        let all = api(Some("mastodon.social"), Some("pleroma.example"), Some("misskey.io"));
+        assert_eq!(all.fediverse_account("misskey.io"), Some(Fediverse::Misskey));
+        assert_eq!(all.fediverse_account("Pleroma.Example"), Some(Fediverse::Pleroma));
+        assert_eq!(all.fediverse_account("other.example"), Some(Fediverse::Mastodon));
+
+        let single = api(None, None, Some("misskey.io"));
+        assert_eq!(single.fediverse_account("mastodon.social"), Some(Fediverse::Misskey));
+        assert_eq!(api(None, None, None).fediverse_account("mastodon.social"), None);
+    }
+}
@@ -0,0 +1,161 @@
+//! LinkedIn API data types
+
+use serde_derive::{Serialize, Deserialize};
+
+use crate::data::Visibility;
+
+///Relation of uploaded media to its owner.
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ServiceRelationship {
+    relationship_type: &'static str,
+    identifier: &'static str,
+}
+
+///Request to upload image.
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct UploadRequest<'a> {
+    recipes: [&'static str; 1],
+    owner: &'a str,
+    service_relationships: [ServiceRelationship; 1],
+}
+
+///Payload to register upload of image.
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RegisterUpload<'a> {
+    register_upload_request: UploadRequest<'a>,
+}
+
+impl<'a> RegisterUpload<'a> {
+    ///Creates request to upload image, which is going to be used in `owner`'s post.
+    pub fn image(owner: &'a str) -> Self {
+        Self {
+            register_upload_request: UploadRequest {
+                recipes: ["urn:li:digitalmediaRecipe:feedshare-image"],
+                owner,
+                service_relationships: [ServiceRelationship {
+                    relationship_type: "OWNER",
+                    identifier: "urn:li:userGeneratedContent",
+                }],
+            }
+        }
+    }
+}
+
+///Where to upload image.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct UploadHttpRequest {
+    ///URL to upload image's data
+    pub upload_url: String,
+}
+
+///Mechanism of upload.
+#[derive(Deserialize, Debug)]
+pub struct UploadMechanism {
+    ///HTTP upload
+    #[serde(rename = "com.linkedin.digitalmedia.uploading.MediaUploadHttpRequest")]
+    pub http: UploadHttpRequest,
+}
+
+///Registered upload.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RegisteredUpload {
+    ///How to upload image
+    pub upload_mechanism: UploadMechanism,
+    ///URN of asset, which is attached to post.
+    pub asset: String,
+}
+
+///Response to registration of upload.
+#[derive(Deserialize, Debug)]
+pub struct RegisterUploadResponse {
+    ///Registered upload
+    pub value: RegisteredUpload,
+}
+
+///Text of post.
+#[derive(Serialize, Debug)]
+pub struct Commentary<'a> {
+    text: &'a str,
+}
+
+///Attached media.
+#[derive(Serialize, Debug)]
+pub struct ShareMedia<'a> {
+    status: &'static str,
+    media: &'a str,
+}
+
+///Content of post.
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ShareContent<'a> {
+    share_commentary: Commentary<'a>,
+    share_media_category: &'static str,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    media: Vec<ShareMedia<'a>>,
+}
+
+///Specific content of post.
+#[derive(Serialize, Debug)]
+pub struct SpecificContent<'a> {
+    #[serde(rename = "com.linkedin.ugc.ShareContent")]
+    share: ShareContent<'a>,
+}
+
+///Who can see post.
+#[derive(Serialize, Debug)]
+pub struct MemberVisibility {
+    #[serde(rename = "com.linkedin.ugc.MemberNetworkVisibility")]
+    visibility: &'static str,
+}
+
+///Payload to create UGC post.
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct UgcPost<'a> {
+    author: &'a str,
+    lifecycle_state: &'static str,
+    specific_content: SpecificContent<'a>,
+    visibility: MemberVisibility,
+}
+
+impl<'a> UgcPost<'a> {
+    ///Creates new instance, attaching uploaded `assets`.
+    pub fn new(author: &'a str, text: &'a str, assets: &'a [String], visibility: Visibility) -> Self {
+        Self {
+            author,
+            lifecycle_state: "PUBLISHED",
+            specific_content: SpecificContent {
+                share: ShareContent {
+                    share_commentary: Commentary {
+                        text,
+                    },
+                    share_media_category: match assets.len() {
+                        0 => "NONE",
+                        _ => "IMAGE",
+                    },
+                    media: assets.iter().map(|asset| ShareMedia { status: "READY", media: asset }).collect(),
+                }
+            },
+            //Only public posts are visible outside of connections
+            visibility: MemberVisibility {
+                visibility: match visibility {
+                    Visibility::Public => "PUBLIC",
+                    _ => "CONNECTIONS",
+                }
+            },
+        }
+    }
+}
+
+///Created post.
+#[derive(Deserialize, Debug)]
+pub struct Created {
+    ///URN of post
+    pub id: String,
+}
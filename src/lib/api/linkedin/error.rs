@@ -0,0 +1,69 @@
+use std::error::Error;
+use std::fmt;
+
+#[repr(u8)]
+#[derive(Debug)]
+///LinkedIn errors
+pub enum LinkedInError {
+    ///Access token or author is empty.
+    InvalidToken,
+    ///Failed to send request to upload image.
+    ImageUploadSendError,
+    ///Server rejected image upload.
+    ImageUploadServerReject,
+    ///Server responded with invalid data.
+    ///
+    ///Should contain `asset`
+    ImageUploadInvalidResponse,
+    ///Failed to send request to perform text post.
+    PostUploadSendError,
+    ///Server rejected posting.
+    PostUploadServerReject,
+    ///Server responded with invalid data
+    ///
+    ///Should contain `id`
+    PostUploadInvalidResponse,
+    ///Platform cannot attach poll to post.
+    PollUnsupported,
+    ///Text exceeds platform's limit.
+    MessageTooLong,
+}
+
+impl LinkedInError {
+    ///Returns short identifier of error's kind.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            &LinkedInError::InvalidToken => "invalid_config",
+            &LinkedInError::ImageUploadSendError => "image_upload_send",
+            &LinkedInError::ImageUploadServerReject => "image_upload_rejected",
+            &LinkedInError::ImageUploadInvalidResponse => "image_upload_invalid_response",
+            &LinkedInError::PostUploadSendError => "post_send",
+            &LinkedInError::PostUploadServerReject => "post_rejected",
+            &LinkedInError::PostUploadInvalidResponse => "post_invalid_response",
+            &LinkedInError::PollUnsupported => "unsupported",
+            &LinkedInError::MessageTooLong => "message_too_long",
+        }
+    }
+}
+
+impl fmt::Display for LinkedInError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl Error for LinkedInError {
+    fn description(&self) -> &str {
+        match self {
+            &LinkedInError::InvalidToken => "Token or author is not valid(empty)",
+            &LinkedInError::ImageUploadSendError => "Failed to send request to upload image",
+            &LinkedInError::ImageUploadServerReject => "Server rejected upload of image",
+            &LinkedInError::ImageUploadInvalidResponse => "Server sent invalid response. Doesn't contain field asset",
+            &LinkedInError::PostUploadSendError => "Failed to send request to perform text post",
+            &LinkedInError::PostUploadServerReject => "Server rejected posting",
+            &LinkedInError::PostUploadInvalidResponse => "Server sent invalid response. Doesn't contain field id",
+            &LinkedInError::PollUnsupported => "Polls are not supported",
+            &LinkedInError::MessageTooLong => "Post exceeds 3000 characters",
+        }
+    }
+}
@@ -0,0 +1,125 @@
+//!LinkedIn API
+
+use super::http::{header, GlobalRequest, Mime, Request, matsu};
+use crate::data::PostFlags;
+
+const REGISTER_UPLOAD_URL: &str = "https://api.linkedin.com/v2/assets?action=registerUpload";
+const POST_URL: &str = "https://api.linkedin.com/v2/ugcPosts";
+const RESTLI_VERSION: (&str, &str) = ("X-Restli-Protocol-Version", "2.0.0");
+
+pub mod data;
+mod error;
+
+pub use error::LinkedInError;
+
+///Maximum number of characters in post.
+pub const MAX_LEN: usize = 3000;
+
+///LinkedIn API
+pub struct LinkedIn {
+    config: crate::config::LinkedIn,
+}
+
+impl LinkedIn {
+    ///Verifies configuration and creates new instances.
+    pub fn new(config: crate::config::LinkedIn) -> Result<Self, LinkedInError> {
+        if config.access_token.len() == 0 || config.author.len() == 0 {
+            return Err(LinkedInError::InvalidToken);
+        }
+
+        Ok(Self {
+            config
+        })
+    }
+
+    ///Registers and performs image upload.
+    ///
+    ///Result contains URN of asset.
+    pub async fn upload_image(&self, mime: &Mime, data: &[u8]) -> Result<String, LinkedInError> {
+        let req = Request::post(REGISTER_UPLOAD_URL).expect("To create request")
+                                                    .bearer_auth(&self.config.access_token)
+                                                    .set_header(RESTLI_VERSION.0, RESTLI_VERSION.1)
+                                                    .json(&data::RegisterUpload::image(&self.config.author))
+                                                    .expect("To serialize json")
+                                                    .global()
+                                                    .send();
+
+        let mut resp = match matsu!(req) {
+            Ok(Ok(resp)) => resp,
+            _ => return Err(LinkedInError::ImageUploadSendError),
+        };
+
+        if !resp.is_success() {
+            return Err(LinkedInError::ImageUploadServerReject)
+        }
+
+        let upload = match matsu!(resp.json::<data::RegisterUploadResponse>()) {
+            Ok(upload) => upload.value,
+            Err(_) => return Err(LinkedInError::ImageUploadInvalidResponse),
+        };
+
+        let req = Request::put(&upload.upload_mechanism.http.upload_url).map_err(|_| LinkedInError::ImageUploadInvalidResponse)?
+                                                                        .bearer_auth(&self.config.access_token)
+                                                                        .set_header(header::CONTENT_TYPE, mime.as_ref())
+                                                                        .body(Some(data.to_vec()))
+                                                                        .global()
+                                                                        .send();
+
+        // For image we wait twice of time
+        // just to be sure
+        let resp = match matsu!(req) {
+            Ok(resp) => resp,
+            Err(err) => match matsu!(matsu!(err)) {
+                Ok(resp) => resp,
+                Err(_) => return Err(LinkedInError::ImageUploadSendError)
+            }
+        }.map_err(|_| LinkedInError::ImageUploadSendError)?;
+
+        match resp.is_success() {
+            true => Ok(upload.asset),
+            false => Err(LinkedInError::ImageUploadServerReject),
+        }
+    }
+
+    ///Prepares post upload request.
+    pub async fn post(&self, message: &str, assets: &[String], flags: &PostFlags) -> Result<crate::data::PublishedPost, LinkedInError> {
+        if message.chars().count() > MAX_LEN {
+            return Err(LinkedInError::MessageTooLong);
+        }
+
+        let req = Request::post(POST_URL).expect("To create request")
+                                         .bearer_auth(&self.config.access_token)
+                                         .set_header(RESTLI_VERSION.0, RESTLI_VERSION.1)
+                                         .json(&data::UgcPost::new(&self.config.author, message, assets, flags.visibility))
+                                         .expect("To serialzie post data")
+                                         .global()
+                                         .send();
+
+        let mut resp = match matsu!(req) {
+            Ok(resp) => resp,
+            Err(err) => match matsu!(matsu!(err)) {
+                Ok(resp) => resp,
+                Err(_) => return Err(LinkedInError::PostUploadSendError)
+            }
+        }.map_err(|_| LinkedInError::PostUploadSendError)?;
+
+        if !resp.is_success() {
+            return Err(LinkedInError::PostUploadServerReject)
+        }
+
+        //URN is returned in header, body is not guaranteed
+        let id = match resp.headers().get("x-restli-id").and_then(|id| id.to_str().ok()) {
+            Some(id) => id.to_owned(),
+            None => match matsu!(resp.json::<data::Created>()) {
+                Ok(created) => created.id,
+                Err(_) => return Err(LinkedInError::PostUploadInvalidResponse),
+            }
+        };
+
+        Ok(crate::data::PublishedPost {
+            url: format!("https://www.linkedin.com/feed/update/{}/", id),
+            id: id.into(),
+            created_at: None,
+        })
+    }
+}
@@ -2,7 +2,7 @@
 
 use serde_derive::{Serialize, Deserialize};
 
-use crate::data::PostFlags;
+use crate::data::{PostFlags, Poll};
 
 ///Generic payload for response that contains entity's information.
 ///
@@ -13,6 +13,131 @@ pub struct EntityId {
     pub id: String
 }
 
+///Results of search.
+#[derive(Deserialize, Debug)]
+pub struct SearchResults {
+    ///Found statuses
+    #[serde(default)]
+    pub statuses: Vec<EntityId>,
+}
+
+///Payload of account entity.
+#[derive(Deserialize, Debug)]
+pub struct Account {
+    ///Identifier
+    pub id: String,
+    ///Handle, including instance for remote accounts
+    pub acct: String,
+}
+
+///Payload of status entity.
+#[derive(Deserialize, Debug)]
+pub struct Status {
+    ///Identifier
+    pub id: String,
+    ///Link to HTML representation.
+    #[serde(default)]
+    pub url: Option<String>,
+    ///Time of creation in ISO 8601 format
+    #[serde(default)]
+    pub created_at: Option<String>,
+    ///Text in HTML
+    #[serde(default)]
+    pub content: String,
+    ///Author
+    #[serde(default)]
+    pub account: Option<Account>,
+    ///Boosted status
+    #[serde(default)]
+    pub reblog: Option<Box<Status>>,
+    ///Number of favourites
+    #[serde(default)]
+    pub favourites_count: u64,
+    ///Number of boosts
+    #[serde(default)]
+    pub reblogs_count: u64,
+    ///Number of replies
+    #[serde(default)]
+    pub replies_count: Option<u64>,
+}
+
+///Payload of notification entity.
+#[derive(Deserialize, Debug)]
+pub struct Notification {
+    ///Type of notification, e.g. `mention`
+    #[serde(rename = "type")]
+    pub kind: String,
+    ///Status that notification is about
+    #[serde(default)]
+    pub status: Option<Status>,
+}
+
+impl Status {
+    ///Returns engagement statistics.
+    pub fn stats(&self) -> crate::data::PostStats {
+        crate::data::PostStats {
+            likes: self.favourites_count,
+            reposts: self.reblogs_count,
+            replies: self.replies_count,
+        }
+    }
+
+    ///Converts into published post, using `host` to construct URL if status has none.
+    pub fn into_published(self, host: &str) -> crate::data::PublishedPost {
+        let url = match self.url {
+            Some(url) => url,
+            None => format!("https://{}/web/statuses/{}", host, self.id),
+        };
+
+        crate::data::PublishedPost {
+            id: self.id.into(),
+            url,
+            created_at: self.created_at,
+        }
+    }
+
+    ///Converts into common status, using `host` to construct URL if status has none.
+    ///
+    ///Boost is converted into status that was boosted.
+    pub fn into_status(self, host: &str) -> crate::data::Status {
+        if let Some(reblog) = self.reblog {
+            return reblog.into_status(host);
+        }
+
+        let text = crate::data::html::to_text(&self.content);
+        let author = self.account.as_ref().map(|account| account.acct.clone()).unwrap_or_default();
+        let timestamp = self.created_at.as_ref().and_then(|created_at| crate::data::time::parse(created_at));
+        let post = self.into_published(host);
+
+        crate::data::Status {
+            id: post.id,
+            url: post.url,
+            author,
+            text,
+            timestamp,
+        }
+    }
+}
+
+///Poll's payload
+#[derive(Serialize, Debug)]
+pub struct NewPoll<'a> {
+    options: &'a [String],
+    expires_in: u64,
+    multiple: bool,
+}
+
+impl<'a> NewPoll<'a> {
+    ///Creates new instance
+    pub fn new(poll: &'a Poll) -> Self {
+        Self {
+            options: &poll.options,
+            expires_in: poll.duration,
+            multiple: poll.multiple,
+        }
+    }
+}
+
 ///Posts new message on timeline
 #[derive(Serialize, Debug)]
 pub struct NewStatus<'a> {
@@ -20,15 +145,27 @@ pub struct NewStatus<'a> {
     ///List of `EntityId`'s id to attach
     pub media_ids: &'a [String],
     sensitive: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    spoiler_text: Option<&'a str>,
+    visibility: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    poll: Option<NewPoll<'a>>,
+    ///Format of status, supported by Pleroma and Akkoma.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    content_type: Option<&'a str>,
 }
 
 impl<'a> NewStatus<'a> {
     ///Creates new instance
-    pub fn new(status: &'a str, media_ids: &'a [String], flags: &PostFlags) -> Self {
+    pub fn new(status: &'a str, media_ids: &'a [String], flags: &'a PostFlags, poll: Option<&'a Poll>, content_type: Option<&'a str>) -> Self {
         Self {
             status,
             media_ids,
             sensitive: flags.nsfw,
+            spoiler_text: flags.cw.as_ref().map(|cw| cw.as_str()),
+            visibility: flags.visibility.as_str(),
+            poll: poll.map(NewPoll::new),
+            content_type,
         }
     }
 }
@@ -29,7 +29,58 @@ pub enum MastodonError {
     ///
     ///Should contain `id`
     PostUploadInvalidResponse,
+    ///Platform cannot attach poll together with images.
+    PollWithImagesUnsupported,
+    ///Failed to send request to read timeline.
+    ReadSendError,
+    ///Server rejected reading of timeline.
+    ReadServerReject,
+    ///Server responded with invalid data to reading of timeline.
+    ReadInvalidResponse,
+    ///Failed to send request to read statistics of post.
+    StatsSendError,
+    ///Server rejected reading of post's statistics.
+    StatsServerReject,
+    ///Server responded with invalid statistics of post.
+    StatsInvalidResponse,
+    ///Failed to send request to resolve status.
+    ResolveSendError,
+    ///Server rejected resolving of status.
+    ResolveServerReject,
+    ///Status cannot be found.
+    StatusNotFound,
+    ///Failed to send request to boost or like status.
+    InteractionSendError,
+    ///Server rejected boost or like of status.
+    InteractionServerReject,
+}
 
+impl MastodonError {
+    ///Returns short identifier of error's kind.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            &MastodonError::InvalidHostUri => "invalid_config",
+            &MastodonError::InvalidToken => "invalid_config",
+            &MastodonError::ImageUploadSendError => "image_upload_send",
+            &MastodonError::ImageUploadServerReject => "image_upload_rejected",
+            &MastodonError::ImageUploadInvalidResponse => "image_upload_invalid_response",
+            &MastodonError::PostUploadSendError => "post_send",
+            &MastodonError::PostUploadServerReject => "post_rejected",
+            &MastodonError::PostUploadInvalidResponse => "post_invalid_response",
+            &MastodonError::PollWithImagesUnsupported => "unsupported",
+            &MastodonError::ReadSendError => "read_send",
+            &MastodonError::ReadServerReject => "read_rejected",
+            &MastodonError::ReadInvalidResponse => "read_invalid_response",
+            &MastodonError::StatsSendError => "stats_send",
+            &MastodonError::StatsServerReject => "stats_rejected",
+            &MastodonError::StatsInvalidResponse => "stats_invalid_response",
+            &MastodonError::ResolveSendError => "resolve_send",
+            &MastodonError::ResolveServerReject => "resolve_rejected",
+            &MastodonError::StatusNotFound => "status_not_found",
+            &MastodonError::InteractionSendError => "interaction_send",
+            &MastodonError::InteractionServerReject => "interaction_rejected",
+        }
+    }
 }
 
 impl fmt::Display for MastodonError {
@@ -49,6 +100,18 @@ impl Error for MastodonError {
             &MastodonError::PostUploadSendError => "Failed to send request to perform text post",
             &MastodonError::PostUploadServerReject => "Server rejected posting",
             &MastodonError::PostUploadInvalidResponse => "Server sent invalid response. Doesn't contain field id",
+            &MastodonError::PollWithImagesUnsupported => "Poll cannot be attached together with images",
+            &MastodonError::ReadSendError => "Failed to send request to read timeline",
+            &MastodonError::ReadServerReject => "Server rejected reading of timeline",
+            &MastodonError::ReadInvalidResponse => "Server sent invalid timeline",
+            &MastodonError::StatsSendError => "Failed to send request to read statistics of post",
+            &MastodonError::StatsServerReject => "Server rejected reading of post's statistics",
+            &MastodonError::StatsInvalidResponse => "Server sent invalid statistics of post",
+            &MastodonError::ResolveSendError => "Failed to send request to resolve status",
+            &MastodonError::ResolveServerReject => "Server rejected resolving of status",
+            &MastodonError::StatusNotFound => "Status cannot be found",
+            &MastodonError::InteractionSendError => "Failed to send request to boost or like status",
+            &MastodonError::InteractionServerReject => "Server rejected boost or like of status",
         }
     }
 }
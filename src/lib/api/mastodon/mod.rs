@@ -1,16 +1,40 @@
 //!Mastodon API
 
 use super::http::{multipart, Uri, GlobalRequest, Mime, Request, matsu};
-use crate::data::PostFlags;
+use crate::data::{PostFlags, Poll};
 
 pub mod data;
 mod error;
 
 pub use error::MastodonError;
 
+///Default maximum number of characters in status.
+///
+///Instance can be configured to use different limit.
+pub const MAX_LEN: usize = 500;
+///Number of characters each link takes in status, regardless of its actual length.
+pub const URL_LEN: usize = 23;
+///Default maximum number of characters in status on Pleroma and Akkoma.
+pub const PLEROMA_MAX_LEN: usize = 5000;
+
+///Checks whether hashtag is recognized by Mastodon.
+///
+///Hashtag consists of letters, digits and `_` with at least one letter,
+///while `·`, `・` and zero-width non-joiner can be used between them.
+pub fn is_valid_hash_tag(tag: &str) -> bool {
+    let is_separator = |ch: char| ch == '\u{00B7}' || ch == '\u{30FB}' || ch == '\u{200C}';
+
+    tag.chars().all(|ch| ch.is_alphanumeric() || ch == '_' || is_separator(ch))
+        && !tag.starts_with(is_separator) && !tag.ends_with(is_separator)
+        && tag.chars().any(char::is_alphabetic)
+}
+
 ///Mastodon API
+///
+///Also used for Pleroma and Akkoma, which extend it with format of status.
 pub struct Mastodon {
     config: crate::config::Mastodon,
+    content_type: Option<String>,
 }
 
 impl Mastodon {
@@ -28,10 +52,24 @@ impl Mastodon {
         }
 
         Ok(Self {
-            config
+            config,
+            content_type: None,
         })
     }
 
+    ///Returns host of instance.
+    pub fn host(&self) -> &str {
+        &self.config.host
+    }
+
+    ///Verifies configuration and creates new instance for Pleroma or Akkoma.
+    pub fn pleroma(config: crate::config::Pleroma) -> Result<Self, MastodonError> {
+        let crate::config::Pleroma { host, access_token, content_type } = config;
+        let mut result = Self::new(crate::config::Mastodon { host, access_token })?;
+        result.content_type = content_type;
+        Ok(result)
+    }
+
     ///Prepares image upload request.
     ///
     ///Result contains `id` from `EntityId`
@@ -65,11 +103,11 @@ impl Mastodon {
     }
 
     ///Prepares post upload request.
-    pub async fn post(&self, message: &str, media_attachments: &[String], flags: &PostFlags) -> Result<crate::data::PostId, MastodonError> {
+    pub async fn post(&self, message: &str, media_attachments: &[String], flags: &PostFlags, poll: Option<&Poll>) -> Result<crate::data::PublishedPost, MastodonError> {
         let url = format!("https://{}/api/v1/statuses", &self.config.host);
         let req = Request::post(url).expect("To create request")
                                     .bearer_auth(self.config.access_token.as_str())
-                                    .json(&data::NewStatus::new(&message, &media_attachments, &flags))
+                                    .json(&data::NewStatus::new(&message, &media_attachments, &flags, poll, self.content_type.as_deref()))
                                     .expect("To serialzie post data")
                                     .global()
                                     .send();
@@ -87,9 +125,137 @@ impl Mastodon {
             return Err(MastodonError::PostUploadServerReject)
         }
 
-        match matsu!(resp.json::<data::EntityId>()) {
-            Ok(data) => Ok(data.id.into()),
+        match matsu!(resp.json::<data::Status>()) {
+            Ok(data) => Ok(data.into_published(&self.config.host)),
             Err(_) => Err(MastodonError::PostUploadInvalidResponse),
         }
     }
+
+    ///Performs request to read statuses from API's `path`.
+    async fn read<T: serde::de::DeserializeOwned>(&self, path: &str, limit: usize) -> Result<T, MastodonError> {
+        let url = format!("https://{}{}", &self.config.host, path);
+        let req = Request::get(url).map_err(|_| MastodonError::ReadSendError)?
+                                   .bearer_auth(self.config.access_token.as_str())
+                                   .query(&[("limit", limit)])
+                                   .empty()
+                                   .global()
+                                   .send();
+
+        let mut resp = match matsu!(req) {
+            Ok(Ok(resp)) => resp,
+            _ => return Err(MastodonError::ReadSendError),
+        };
+
+        if !resp.is_success() {
+            return Err(MastodonError::ReadServerReject)
+        }
+
+        matsu!(resp.json::<T>()).map_err(|_| MastodonError::ReadInvalidResponse)
+    }
+
+    ///Reads home timeline, newest first.
+    pub async fn home_timeline(&self, limit: usize) -> Result<Vec<crate::data::Status>, MastodonError> {
+        let statuses = matsu!(self.read::<Vec<data::Status>>("/api/v1/timelines/home", limit))?;
+        Ok(statuses.into_iter().map(|status| status.into_status(&self.config.host)).collect())
+    }
+
+    ///Reads own statuses, newest first.
+    pub async fn own_posts(&self, limit: usize) -> Result<Vec<crate::data::Status>, MastodonError> {
+        let account = matsu!(self.read::<data::Account>("/api/v1/accounts/verify_credentials", limit))?;
+        let path = format!("/api/v1/accounts/{}/statuses", account.id);
+        let statuses = matsu!(self.read::<Vec<data::Status>>(&path, limit))?;
+        Ok(statuses.into_iter().map(|status| status.into_status(&self.config.host)).collect())
+    }
+
+    ///Reads statuses that mention user, newest first.
+    ///
+    ///Notifications are filtered locally, as not every server can filter them by type.
+    pub async fn mentions(&self, limit: usize) -> Result<Vec<crate::data::Status>, MastodonError> {
+        let notifications = matsu!(self.read::<Vec<data::Notification>>("/api/v1/notifications", limit))?;
+        Ok(notifications.into_iter().filter(|notification| notification.kind == "mention")
+                                    .filter_map(|notification| notification.status)
+                                    .map(|status| status.into_status(&self.config.host))
+                                    .collect())
+    }
+
+    ///Reads engagement statistics of status with `id`.
+    pub async fn stats(&self, id: &str) -> Result<crate::data::PostStats, MastodonError> {
+        let url = format!("https://{}/api/v1/statuses/{}", &self.config.host, id);
+        let req = Request::get(url).map_err(|_| MastodonError::StatsSendError)?
+                                   .bearer_auth(self.config.access_token.as_str())
+                                   .empty()
+                                   .global()
+                                   .send();
+
+        let mut resp = match matsu!(req) {
+            Ok(Ok(resp)) => resp,
+            _ => return Err(MastodonError::StatsSendError),
+        };
+
+        if !resp.is_success() {
+            return Err(MastodonError::StatsServerReject)
+        }
+
+        match matsu!(resp.json::<data::Status>()) {
+            Ok(status) => Ok(status.stats()),
+            Err(_) => Err(MastodonError::StatsInvalidResponse),
+        }
+    }
+
+    ///Resolves status's `url` into ID on own instance.
+    ///
+    ///Remote status is fetched by instance, if it doesn't know it yet.
+    pub async fn resolve(&self, url: &str) -> Result<String, MastodonError> {
+        let search_url = format!("https://{}/api/v2/search", &self.config.host);
+        let req = Request::get(search_url).map_err(|_| MastodonError::ResolveSendError)?
+                                          .bearer_auth(self.config.access_token.as_str())
+                                          .query(&[("q", url), ("type", "statuses"), ("resolve", "true"), ("limit", "1")])
+                                          .empty()
+                                          .global()
+                                          .send();
+
+        let mut resp = match matsu!(req) {
+            Ok(Ok(resp)) => resp,
+            _ => return Err(MastodonError::ResolveSendError),
+        };
+
+        if !resp.is_success() {
+            return Err(MastodonError::ResolveServerReject)
+        }
+
+        match matsu!(resp.json::<data::SearchResults>()) {
+            Ok(results) => results.statuses.into_iter().next().map(|status| status.id).ok_or(MastodonError::StatusNotFound),
+            Err(_) => Err(MastodonError::StatusNotFound),
+        }
+    }
+
+    ///Performs action on status with `id`, e.g. `reblog`.
+    async fn interact(&self, id: &str, action: &str) -> Result<(), MastodonError> {
+        let url = format!("https://{}/api/v1/statuses/{}/{}", &self.config.host, id, action);
+        let req = Request::post(url).map_err(|_| MastodonError::InteractionSendError)?
+                                    .bearer_auth(self.config.access_token.as_str())
+                                    .empty()
+                                    .global()
+                                    .send();
+
+        let resp = match matsu!(req) {
+            Ok(Ok(resp)) => resp,
+            _ => return Err(MastodonError::InteractionSendError),
+        };
+
+        match resp.is_success() {
+            true => Ok(()),
+            false => Err(MastodonError::InteractionServerReject),
+        }
+    }
+
+    ///Boosts status with `id`.
+    pub async fn boost(&self, id: &str) -> Result<(), MastodonError> {
+        matsu!(self.interact(id, "reblog"))
+    }
+
+    ///Favourites status with `id`.
+    pub async fn like(&self, id: &str) -> Result<(), MastodonError> {
+        matsu!(self.interact(id, "favourite"))
+    }
 }
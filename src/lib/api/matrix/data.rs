@@ -0,0 +1,80 @@
+//! Matrix API data types
+
+use serde_derive::{Serialize, Deserialize};
+
+///Response to media upload.
+#[derive(Deserialize, Debug)]
+pub struct Uploaded {
+    ///MXC URI of media
+    pub content_uri: String,
+}
+
+///Uploaded image, ready to be sent.
+#[derive(Debug)]
+pub struct Media {
+    ///File name
+    pub name: String,
+    ///MXC URI of media
+    pub uri: String,
+    ///Mime
+    pub mime: String,
+    ///Size in bytes
+    pub size: usize,
+}
+
+///Response to sending of event.
+#[derive(Deserialize, Debug)]
+pub struct Event {
+    ///Identifier
+    pub event_id: String,
+}
+
+///Information about image.
+#[derive(Serialize, Debug)]
+pub struct ImageInfo<'a> {
+    mimetype: &'a str,
+    size: usize,
+}
+
+///Content of `m.room.message` event.
+#[derive(Serialize, Debug)]
+#[serde(tag = "msgtype")]
+pub enum Message<'a> {
+    ///Text
+    #[serde(rename = "m.text")]
+    Text {
+        ///Text
+        body: &'a str,
+    },
+    ///Image
+    #[serde(rename = "m.image")]
+    Image {
+        ///File name
+        body: &'a str,
+        ///MXC URI of image
+        url: &'a str,
+        ///Information about image
+        info: ImageInfo<'a>,
+    },
+}
+
+impl<'a> Message<'a> {
+    ///Creates text message
+    pub fn text(body: &'a str) -> Self {
+        Message::Text {
+            body
+        }
+    }
+
+    ///Creates message with uploaded image
+    pub fn image(media: &'a Media) -> Self {
+        Message::Image {
+            body: &media.name,
+            url: &media.uri,
+            info: ImageInfo {
+                mimetype: &media.mime,
+                size: media.size,
+            }
+        }
+    }
+}
@@ -0,0 +1,69 @@
+use std::error::Error;
+use std::fmt;
+
+#[repr(u8)]
+#[derive(Debug)]
+///Matrix errors
+pub enum MatrixError {
+    ///Provided HOST URI is not valid URI.
+    InvalidHostUri,
+    ///Access token or room is empty.
+    InvalidToken,
+    ///Failed to send request to upload image.
+    ImageUploadSendError,
+    ///Server rejected image upload.
+    ImageUploadServerReject,
+    ///Server responded with invalid data.
+    ///
+    ///Should contain `content_uri`
+    ImageUploadInvalidResponse,
+    ///Failed to send request to perform text post.
+    PostUploadSendError,
+    ///Server rejected posting.
+    PostUploadServerReject,
+    ///Server responded with invalid data
+    ///
+    ///Should contain `event_id`
+    PostUploadInvalidResponse,
+    ///Platform cannot attach poll to post.
+    PollUnsupported,
+}
+
+impl MatrixError {
+    ///Returns short identifier of error's kind.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            &MatrixError::InvalidHostUri => "invalid_config",
+            &MatrixError::InvalidToken => "invalid_config",
+            &MatrixError::ImageUploadSendError => "image_upload_send",
+            &MatrixError::ImageUploadServerReject => "image_upload_rejected",
+            &MatrixError::ImageUploadInvalidResponse => "image_upload_invalid_response",
+            &MatrixError::PostUploadSendError => "post_send",
+            &MatrixError::PostUploadServerReject => "post_rejected",
+            &MatrixError::PostUploadInvalidResponse => "post_invalid_response",
+            &MatrixError::PollUnsupported => "unsupported",
+        }
+    }
+}
+
+impl fmt::Display for MatrixError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl Error for MatrixError {
+    fn description(&self) -> &str {
+        match self {
+            &MatrixError::InvalidHostUri => "Provided Host URI is not valid URI",
+            &MatrixError::InvalidToken => "Token or room is not valid(empty)",
+            &MatrixError::ImageUploadSendError => "Failed to send request to upload image",
+            &MatrixError::ImageUploadServerReject => "Server rejected upload of image",
+            &MatrixError::ImageUploadInvalidResponse => "Server sent invalid response. Doesn't contain field content_uri",
+            &MatrixError::PostUploadSendError => "Failed to send request to perform text post",
+            &MatrixError::PostUploadServerReject => "Server rejected posting",
+            &MatrixError::PostUploadInvalidResponse => "Server sent invalid response. Doesn't contain field event_id",
+            &MatrixError::PollUnsupported => "Polls are not supported",
+        }
+    }
+}
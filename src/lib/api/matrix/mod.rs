@@ -0,0 +1,133 @@
+//!Matrix client-server API
+
+use super::http::{header, Uri, GlobalRequest, Mime, Request, matsu};
+use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+pub mod data;
+mod error;
+
+pub use error::MatrixError;
+
+///Counter to make transaction IDs unique within the same millisecond.
+static TRANSACTION: AtomicUsize = AtomicUsize::new(0);
+
+///Generates ID of transaction, which server uses to avoid sending the same event twice.
+fn transaction_id() -> String {
+    format!("fie{}.{}", crate::data::time::timestamp_millis(), TRANSACTION.fetch_add(1, Ordering::Relaxed))
+}
+
+///Matrix API
+pub struct Matrix {
+    config: crate::config::Matrix,
+}
+
+impl Matrix {
+    ///Verifies configuration and creates new instances.
+    pub fn new(config: crate::config::Matrix) -> Result<Self, MatrixError> {
+        match config.host.parse::<Uri>() {
+            Ok(_) => (),
+            Err(_) => {
+                return Err(MatrixError::InvalidHostUri);
+            }
+        }
+
+        if config.access_token.len() == 0 || config.room.len() == 0 {
+            return Err(MatrixError::InvalidToken);
+        }
+
+        Ok(Self {
+            config
+        })
+    }
+
+    ///Prepares image upload request.
+    ///
+    ///Result contains `Media` to send.
+    pub async fn upload_image(&self, name: &str, mime: &Mime, data: &[u8]) -> Result<data::Media, MatrixError> {
+        let url = format!("https://{}/_matrix/media/v3/upload", &self.config.host);
+        let req = Request::post(url).expect("To create request")
+                                    .bearer_auth(self.config.access_token.as_str())
+                                    .query(&[("filename", name)])
+                                    .set_header(header::CONTENT_TYPE, mime.as_ref())
+                                    .body(Some(data.to_vec()))
+                                    .global()
+                                    .send();
+
+        // For image we wait twice of time
+        // just to be sure
+        let mut resp = match matsu!(req) {
+            Ok(resp) => resp,
+            Err(err) => match matsu!(matsu!(err)) {
+                Ok(resp) => resp,
+                Err(_) => return Err(MatrixError::ImageUploadSendError)
+            }
+        }.map_err(|_| MatrixError::ImageUploadSendError)?;
+
+        if !resp.is_success() {
+            return Err(MatrixError::ImageUploadServerReject)
+        }
+
+        match matsu!(resp.json::<data::Uploaded>()) {
+            Ok(uploaded) => Ok(data::Media {
+                name: name.to_owned(),
+                uri: uploaded.content_uri,
+                mime: mime.as_ref().to_owned(),
+                size: data.len(),
+            }),
+            Err(_) => Err(MatrixError::ImageUploadInvalidResponse),
+        }
+    }
+
+    ///Sends single message into room, returning ID of event.
+    async fn send(&self, message: &data::Message<'_>) -> Result<String, MatrixError> {
+        let room = utf8_percent_encode(&self.config.room, NON_ALPHANUMERIC);
+        let url = format!("https://{}/_matrix/client/v3/rooms/{}/send/m.room.message/{}", &self.config.host, room, transaction_id());
+        let req = Request::put(url).expect("To create request")
+                                   .bearer_auth(self.config.access_token.as_str())
+                                   .json(message)
+                                   .expect("To serialzie post data")
+                                   .global()
+                                   .send();
+
+        let mut resp = match matsu!(req) {
+            Ok(resp) => resp,
+            Err(err) => match matsu!(matsu!(err)) {
+                Ok(resp) => resp,
+                Err(_) => return Err(MatrixError::PostUploadSendError)
+            }
+        }.map_err(|_| MatrixError::PostUploadSendError)?;
+
+        if !resp.is_success() {
+            return Err(MatrixError::PostUploadServerReject)
+        }
+
+        match matsu!(resp.json::<data::Event>()) {
+            Ok(event) => Ok(event.event_id),
+            Err(_) => Err(MatrixError::PostUploadInvalidResponse),
+        }
+    }
+
+    ///Prepares post upload request.
+    ///
+    ///Images are sent as separate messages after text, which is the one returned.
+    ///Failure to send image doesn't fail post, as its text is already published and cannot be sent again without duplicate.
+    pub async fn post(&self, message: &str, media: &[data::Media]) -> Result<crate::data::PublishedPost, MatrixError> {
+        let event_id = matsu!(self.send(&data::Message::text(message)))?;
+
+        for media in media {
+            if matsu!(self.send(&data::Message::image(media))).is_err() {
+                break;
+            }
+        }
+
+        let url = format!("https://matrix.to/#/{}/{}", utf8_percent_encode(&self.config.room, NON_ALPHANUMERIC), utf8_percent_encode(&event_id, NON_ALPHANUMERIC));
+
+        Ok(crate::data::PublishedPost {
+            id: event_id.into(),
+            url,
+            created_at: None,
+        })
+    }
+}
@@ -2,7 +2,8 @@
 
 use serde_derive::{Serialize, Deserialize};
 
-use crate::data::PostFlags;
+use crate::data::{PostFlags, Visibility};
+use crate::api::opengraph::OpenGraph;
 
 ///Auth payload
 #[derive(Serialize, Debug)]
@@ -42,30 +43,39 @@ pub struct Post<'a> {
     wire_threshold: Option<String>,
     message: &'a str,
     is_rich: u8,
-    title: Option<String>,
-    description: Option<String>,
-    thumbnail: Option<String>,
-    url: Option<String>,
+    title: Option<&'a str>,
+    description: Option<&'a str>,
+    thumbnail: Option<&'a str>,
+    url: Option<&'a str>,
     attachment_guid: &'a Option<String>,
+    tags: &'a [String],
     ///Whether content is safe for work or not
     pub mature: u8,
+    //0 - private, 1 - logged in users only, 2 - public
     access_id: u8,
 }
 
 impl<'a> Post<'a> {
     ///Creates new post
-    pub fn new(message: &'a str, attachment_guid: &'a Option<String>, flags: &PostFlags) -> Self {
+    ///
+    ///Post becomes rich when link's preview is provided.
+    pub fn new(message: &'a str, attachment_guid: &'a Option<String>, tags: &'a [String], preview: Option<&'a OpenGraph>, flags: &PostFlags) -> Self {
         Post {
             wire_threshold: None,
             message,
-            is_rich: 0,
-            title: None,
-            description: None,
-            thumbnail: None,
-            url: None,
+            is_rich: preview.is_some() as u8,
+            title: preview.and_then(|preview| preview.title.as_ref().map(|title| title.as_str())),
+            description: preview.and_then(|preview| preview.description.as_ref().map(|description| description.as_str())),
+            thumbnail: preview.and_then(|preview| preview.image.as_ref().map(|image| image.as_str())),
+            url: preview.map(|preview| preview.url.as_str()),
             attachment_guid,
+            tags,
             mature: flags.nsfw as u8,
-            access_id: 2,
+            access_id: match flags.visibility {
+                Visibility::Public => 2,
+                Visibility::Unlisted => 1,
+                Visibility::Private | Visibility::Direct => 0,
+            },
         }
     }
 }
@@ -76,3 +86,35 @@ pub struct UploadResponse {
     ///Newly created entity ID
     pub guid: String,
 }
+
+///Deserializes counter, which Minds sends either as number or as string.
+fn count<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<u64, D::Error> {
+    use serde::Deserialize;
+
+    match yukikaze::serde_json::Value::deserialize(deserializer)? {
+        yukikaze::serde_json::Value::Number(number) => Ok(number.as_u64().unwrap_or(0)),
+        yukikaze::serde_json::Value::String(text) => Ok(text.parse().unwrap_or(0)),
+        _ => Ok(0),
+    }
+}
+
+///Activity with its counters
+#[derive(Deserialize, Debug)]
+pub struct Activity {
+    ///Number of thumbs up
+    #[serde(rename = "thumbs:up:count", default, deserialize_with = "count")]
+    pub thumbs_up: u64,
+    ///Number of reminds
+    #[serde(default, deserialize_with = "count")]
+    pub reminds: u64,
+    ///Number of comments
+    #[serde(rename = "comments:count", default, deserialize_with = "count")]
+    pub comments: u64,
+}
+
+///Response with single activity
+#[derive(Deserialize, Debug)]
+pub struct SingleActivity {
+    ///Activity
+    pub activity: Activity,
+}
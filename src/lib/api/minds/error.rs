@@ -23,7 +23,33 @@ pub enum MindsError {
     ///
     ///Should contain `id`
     PostUploadInvalidResponse,
+    ///Platform cannot attach poll to post.
+    PollUnsupported,
+    ///Failed to send request to read statistics of post.
+    StatsSendError,
+    ///Server rejected reading of post's statistics.
+    StatsServerReject,
+    ///Server responded with invalid statistics of post.
+    StatsInvalidResponse,
+}
 
+impl MindsError {
+    ///Returns short identifier of error's kind.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            &MindsError::LoginFailed => "login_failed",
+            &MindsError::ImageUploadSendError => "image_upload_send",
+            &MindsError::ImageUploadServerReject => "image_upload_rejected",
+            &MindsError::ImageUploadInvalidResponse => "image_upload_invalid_response",
+            &MindsError::PostUploadSendError => "post_send",
+            &MindsError::PostUploadServerReject => "post_rejected",
+            &MindsError::PostUploadInvalidResponse => "post_invalid_response",
+            &MindsError::PollUnsupported => "unsupported",
+            &MindsError::StatsSendError => "stats_send",
+            &MindsError::StatsServerReject => "stats_rejected",
+            &MindsError::StatsInvalidResponse => "stats_invalid_response",
+        }
+    }
 }
 
 impl fmt::Display for MindsError {
@@ -42,6 +68,10 @@ impl Error for MindsError {
             &MindsError::PostUploadSendError => "Failed to send request to perform text post",
             &MindsError::PostUploadServerReject => "Server rejected posting",
             &MindsError::PostUploadInvalidResponse => "Server sent invalid response. Doesn't contain field id",
+            &MindsError::PollUnsupported => "Polls are not supported",
+            &MindsError::StatsSendError => "Failed to send request to read statistics of post",
+            &MindsError::StatsServerReject => "Server rejected reading of post's statistics",
+            &MindsError::StatsInvalidResponse => "Server sent invalid statistics of post",
         }
     }
 }
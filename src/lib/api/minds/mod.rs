@@ -3,8 +3,11 @@
 const OAUTH2_URL: &'static str = "https://www.minds.com/api/v2/oauth/token";
 const IMAGES_URL: &'static str = "https://www.minds.com/api/v1/media";
 const POST_URL: &'static str = "https://www.minds.com/api/v1/newsfeed";
+const NEWSFEED_URL: &'static str = "https://www.minds.com/newsfeed";
+const SINGLE_URL: &'static str = "https://www.minds.com/api/v1/newsfeed/single";
 
 use crate::data::PostFlags;
+use super::opengraph::OpenGraph;
 use super::http::{multipart, GlobalRequest, Mime, Request, matsu};
 
 pub mod data;
@@ -15,14 +18,25 @@ pub use error::MindsError;
 
 ///Minds API
 pub struct Minds {
+    config: crate::config::Minds,
     token: String,
 }
 
 impl Minds {
-    ///Creates new instances by attempting to login and get access token.
-    pub async fn new(config: crate::config::Minds) -> Result<Self, MindsError> {
+    ///Creates new instance without authorization.
+    ///
+    ///Use `login` to get access token before posting.
+    pub fn new(config: crate::config::Minds) -> Self {
+        Self {
+            config,
+            token: String::new(),
+        }
+    }
+
+    ///Attempts to login and get access token.
+    pub async fn login(&mut self) -> Result<(), MindsError> {
         let req = Request::post(OAUTH2_URL).expect("To create request")
-                                           .json(&Auth::new(&config.username, &config.password))
+                                           .json(&Auth::new(&self.config.username, &self.config.password))
                                            .expect("To serialize json")
                                            .global()
                                            .send();
@@ -44,7 +58,8 @@ impl Minds {
             Err(_) => return Err(MindsError::LoginFailed),
         };
 
-        Ok(Self { token: oauth2.access_token })
+        self.token = oauth2.access_token;
+        Ok(())
     }
 
     ///Prepares image upload request.
@@ -78,10 +93,14 @@ impl Minds {
     }
 
     ///Prepares post upload request.
-    pub async fn post(&self, message: &str, media_attachments: Option<String>, flags: &PostFlags) -> Result<crate::data::PostId, MindsError> {
+    ///
+    ///Hashtags are passed separately, without `#`.
+    ///
+    ///Link's preview turns post into rich one.
+    pub async fn post(&self, message: &str, media_attachments: Option<String>, tags: &[String], preview: Option<&OpenGraph>, flags: &PostFlags) -> Result<crate::data::PublishedPost, MindsError> {
         let req = Request::post(POST_URL).expect("To create request")
                                          .bearer_auth(&self.token)
-                                         .json(&Post::new(&message, &media_attachments, &flags))
+                                         .json(&Post::new(&message, &media_attachments, tags, preview, &flags))
                                          .expect("To serialzie post data")
                                          .global()
                                          .send();
@@ -100,8 +119,40 @@ impl Minds {
         }
 
         match matsu!(resp.json::<UploadResponse>()) {
-            Ok(data) => Ok(data.guid.into()),
+            Ok(data) => Ok(crate::data::PublishedPost {
+                url: format!("{}/{}", NEWSFEED_URL, data.guid),
+                id: data.guid.into(),
+                created_at: None,
+            }),
             Err(_) => Err(MindsError::PostUploadInvalidResponse),
         }
     }
+
+    ///Reads engagement statistics of activity with `guid`.
+    pub async fn stats(&self, guid: &str) -> Result<crate::data::PostStats, MindsError> {
+        let url = format!("{}/{}", SINGLE_URL, guid);
+        let req = Request::get(url).map_err(|_| MindsError::StatsSendError)?
+                                   .bearer_auth(&self.token)
+                                   .empty()
+                                   .global()
+                                   .send();
+
+        let mut resp = match matsu!(req) {
+            Ok(Ok(resp)) => resp,
+            _ => return Err(MindsError::StatsSendError),
+        };
+
+        if !resp.is_success() {
+            return Err(MindsError::StatsServerReject)
+        }
+
+        match matsu!(resp.json::<SingleActivity>()) {
+            Ok(single) => Ok(crate::data::PostStats {
+                likes: single.activity.thumbs_up,
+                reposts: single.activity.reminds,
+                replies: Some(single.activity.comments),
+            }),
+            Err(_) => Err(MindsError::StatsInvalidResponse),
+        }
+    }
 }
@@ -0,0 +1,269 @@
+//! Misskey API data types
+
+use serde_derive::{Serialize, Deserialize};
+
+use crate::data::{PostFlags, Poll, Visibility};
+
+use std::collections::HashMap;
+
+///Uploaded file in drive.
+#[derive(Deserialize, Debug)]
+pub struct DriveFile {
+    ///Identifier
+    pub id: String,
+}
+
+///Payload of user entity.
+#[derive(Deserialize, Debug)]
+pub struct User {
+    ///Identifier
+    pub id: String,
+    ///Username
+    pub username: String,
+    ///Instance of remote user
+    #[serde(default)]
+    pub host: Option<String>,
+}
+
+///Payload of note entity.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct Note {
+    ///Identifier
+    pub id: String,
+    ///Time of creation in ISO 8601 format
+    #[serde(default)]
+    pub created_at: Option<String>,
+    ///Text, absent for renote without quote
+    #[serde(default)]
+    pub text: Option<String>,
+    ///Author
+    #[serde(default)]
+    pub user: Option<User>,
+    ///Renoted note
+    #[serde(default)]
+    pub renote: Option<Box<Note>>,
+    ///Number of renotes
+    #[serde(default)]
+    pub renote_count: u64,
+    ///Number of replies
+    #[serde(default)]
+    pub replies_count: u64,
+    ///Number of each reaction
+    #[serde(default)]
+    pub reactions: HashMap<String, u64>,
+}
+
+impl Note {
+    ///Returns engagement statistics, counting all reactions as likes.
+    pub fn stats(&self) -> crate::data::PostStats {
+        crate::data::PostStats {
+            likes: self.reactions.values().sum(),
+            reposts: self.renote_count,
+            replies: Some(self.replies_count),
+        }
+    }
+
+    ///Converts into common status, using `host` to construct URL.
+    ///
+    ///Renote without text is converted into note that was renoted.
+    pub fn into_status(self, host: &str) -> crate::data::Status {
+        match self.renote {
+            Some(renote) if self.text.is_none() => return renote.into_status(host),
+            _ => (),
+        }
+
+        let author = match self.user {
+            Some(User { username, host: Some(host), .. }) => format!("{}@{}", username, host),
+            Some(User { username, .. }) => username,
+            None => String::new(),
+        };
+
+        crate::data::Status {
+            url: format!("https://{}/notes/{}", host, self.id),
+            id: self.id.into(),
+            author,
+            text: self.text.unwrap_or_default(),
+            timestamp: self.created_at.as_ref().and_then(|created_at| crate::data::time::parse(created_at)),
+        }
+    }
+}
+
+///Payload to read notes.
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ReadNotes<'a> {
+    i: &'a str,
+    limit: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    user_id: Option<&'a str>,
+}
+
+impl<'a> ReadNotes<'a> {
+    ///Creates new instance, reading notes of `user_id` if specified.
+    pub fn new(i: &'a str, limit: usize, user_id: Option<&'a str>) -> Self {
+        Self {
+            i,
+            limit,
+            user_id,
+        }
+    }
+}
+
+///Payload to show note.
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ShowNote<'a> {
+    i: &'a str,
+    note_id: &'a str,
+}
+
+impl<'a> ShowNote<'a> {
+    ///Creates new instance
+    pub fn new(i: &'a str, note_id: &'a str) -> Self {
+        Self {
+            i,
+            note_id,
+        }
+    }
+}
+
+///Payload to fetch remote object by its URI.
+#[derive(Serialize, Debug)]
+pub struct ApShow<'a> {
+    i: &'a str,
+    uri: &'a str,
+}
+
+impl<'a> ApShow<'a> {
+    ///Creates new instance
+    pub fn new(i: &'a str, uri: &'a str) -> Self {
+        Self {
+            i,
+            uri,
+        }
+    }
+}
+
+///Remote object, fetched by instance.
+#[derive(Deserialize, Debug)]
+pub struct ApObject {
+    ///Type of object, `Note` or `User`
+    #[serde(rename = "type")]
+    pub kind: String,
+    ///Object's payload
+    pub object: EntityId,
+}
+
+///Generic payload with entity's ID.
+#[derive(Deserialize, Debug)]
+pub struct EntityId {
+    ///Identifier
+    pub id: String,
+}
+
+///Payload to renote.
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct Renote<'a> {
+    i: &'a str,
+    renote_id: &'a str,
+}
+
+impl<'a> Renote<'a> {
+    ///Creates new instance
+    pub fn new(i: &'a str, renote_id: &'a str) -> Self {
+        Self {
+            i,
+            renote_id,
+        }
+    }
+}
+
+///Payload to react on note.
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct Reaction<'a> {
+    i: &'a str,
+    note_id: &'a str,
+    reaction: &'static str,
+}
+
+impl<'a> Reaction<'a> {
+    ///Creates new instance with like, i.e. heart reaction.
+    pub fn like(i: &'a str, note_id: &'a str) -> Self {
+        Self {
+            i,
+            note_id,
+            reaction: "❤",
+        }
+    }
+}
+
+///Response to creation of note.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CreatedNote {
+    ///Created note
+    pub created_note: Note,
+}
+
+///Poll's payload
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct NewPoll<'a> {
+    choices: &'a [String],
+    multiple: bool,
+    ///Duration in milliseconds
+    expired_after: u64,
+}
+
+impl<'a> NewPoll<'a> {
+    ///Creates new instance
+    pub fn new(poll: &'a Poll) -> Self {
+        Self {
+            choices: &poll.options,
+            multiple: poll.multiple,
+            expired_after: poll.duration * 1000,
+        }
+    }
+}
+
+///Returns visibility, as it is named by Misskey.
+fn visibility(visibility: Visibility) -> &'static str {
+    match visibility {
+        Visibility::Public => "public",
+        Visibility::Unlisted => "home",
+        Visibility::Private => "followers",
+        Visibility::Direct => "specified",
+    }
+}
+
+///Creates new note
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct NewNote<'a> {
+    i: &'a str,
+    text: &'a str,
+    #[serde(skip_serializing_if = "<[String]>::is_empty")]
+    file_ids: &'a [String],
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cw: Option<&'a str>,
+    visibility: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    poll: Option<NewPoll<'a>>,
+}
+
+impl<'a> NewNote<'a> {
+    ///Creates new instance
+    pub fn new(token: &'a str, text: &'a str, file_ids: &'a [String], flags: &'a PostFlags, poll: Option<&'a Poll>) -> Self {
+        Self {
+            i: token,
+            text,
+            file_ids,
+            cw: flags.cw.as_ref().map(|cw| cw.as_str()),
+            visibility: visibility(flags.visibility),
+            poll: poll.map(NewPoll::new),
+        }
+    }
+}
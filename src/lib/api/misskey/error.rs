@@ -0,0 +1,113 @@
+use std::error::Error;
+use std::fmt;
+
+#[repr(u8)]
+#[derive(Debug)]
+///Misskey errors
+pub enum MisskeyError {
+    ///Provided HOST URI is not valid URI.
+    InvalidHostUri,
+    ///Access token is invalid.
+    ///
+    ///Possible reasons:
+    ///
+    ///- Empty token
+    InvalidToken,
+    ///Failed to send request to upload image.
+    ImageUploadSendError,
+    ///Server rejected image upload.
+    ImageUploadServerReject,
+    ///Server responded with invalid data.
+    ///
+    ///Should contain `id`
+    ImageUploadInvalidResponse,
+    ///Failed to send request to perform text post.
+    PostUploadSendError,
+    ///Server rejected posting.
+    PostUploadServerReject,
+    ///Server responded with invalid data
+    ///
+    ///Should contain `createdNote`
+    PostUploadInvalidResponse,
+    ///Failed to send request to read timeline.
+    ReadSendError,
+    ///Server rejected reading of timeline.
+    ReadServerReject,
+    ///Server responded with invalid data to reading of timeline.
+    ReadInvalidResponse,
+    ///Failed to send request to read statistics of post.
+    StatsSendError,
+    ///Server rejected reading of post's statistics.
+    StatsServerReject,
+    ///Server responded with invalid statistics of post.
+    StatsInvalidResponse,
+    ///Failed to send request to resolve status.
+    ResolveSendError,
+    ///Server rejected resolving of status.
+    ResolveServerReject,
+    ///Status cannot be found.
+    StatusNotFound,
+    ///Failed to send request to boost or like status.
+    InteractionSendError,
+    ///Server rejected boost or like of status.
+    InteractionServerReject,
+}
+
+impl MisskeyError {
+    ///Returns short identifier of error's kind.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            &MisskeyError::InvalidHostUri => "invalid_config",
+            &MisskeyError::InvalidToken => "invalid_config",
+            &MisskeyError::ImageUploadSendError => "image_upload_send",
+            &MisskeyError::ImageUploadServerReject => "image_upload_rejected",
+            &MisskeyError::ImageUploadInvalidResponse => "image_upload_invalid_response",
+            &MisskeyError::PostUploadSendError => "post_send",
+            &MisskeyError::PostUploadServerReject => "post_rejected",
+            &MisskeyError::PostUploadInvalidResponse => "post_invalid_response",
+            &MisskeyError::ReadSendError => "read_send",
+            &MisskeyError::ReadServerReject => "read_rejected",
+            &MisskeyError::ReadInvalidResponse => "read_invalid_response",
+            &MisskeyError::StatsSendError => "stats_send",
+            &MisskeyError::StatsServerReject => "stats_rejected",
+            &MisskeyError::StatsInvalidResponse => "stats_invalid_response",
+            &MisskeyError::ResolveSendError => "resolve_send",
+            &MisskeyError::ResolveServerReject => "resolve_rejected",
+            &MisskeyError::StatusNotFound => "status_not_found",
+            &MisskeyError::InteractionSendError => "interaction_send",
+            &MisskeyError::InteractionServerReject => "interaction_rejected",
+        }
+    }
+}
+
+impl fmt::Display for MisskeyError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl Error for MisskeyError {
+    fn description(&self) -> &str {
+        match self {
+            &MisskeyError::InvalidHostUri => "Provided Host URI is not valid URI",
+            &MisskeyError::InvalidToken => "Token is not valid(empty)",
+            &MisskeyError::ImageUploadSendError => "Failed to send request to upload image",
+            &MisskeyError::ImageUploadServerReject => "Server rejected upload of image",
+            &MisskeyError::ImageUploadInvalidResponse => "Server sent invalid response. Doesn't contain field id",
+            &MisskeyError::PostUploadSendError => "Failed to send request to perform text post",
+            &MisskeyError::PostUploadServerReject => "Server rejected posting",
+            &MisskeyError::PostUploadInvalidResponse => "Server sent invalid response. Doesn't contain field createdNote",
+            &MisskeyError::ReadSendError => "Failed to send request to read timeline",
+            &MisskeyError::ReadServerReject => "Server rejected reading of timeline",
+            &MisskeyError::ReadInvalidResponse => "Server sent invalid timeline",
+            &MisskeyError::StatsSendError => "Failed to send request to read statistics of post",
+            &MisskeyError::StatsServerReject => "Server rejected reading of post's statistics",
+            &MisskeyError::StatsInvalidResponse => "Server sent invalid statistics of post",
+            &MisskeyError::ResolveSendError => "Failed to send request to resolve status",
+            &MisskeyError::ResolveServerReject => "Server rejected resolving of status",
+            &MisskeyError::StatusNotFound => "Status cannot be found",
+            &MisskeyError::InteractionSendError => "Failed to send request to boost or like status",
+            &MisskeyError::InteractionServerReject => "Server rejected boost or like of status",
+        }
+    }
+}
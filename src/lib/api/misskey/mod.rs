@@ -0,0 +1,247 @@
+//!Misskey API
+
+use super::http::{multipart, Uri, GlobalRequest, Mime, Request, matsu};
+use crate::data::{PostFlags, Poll};
+
+pub mod data;
+mod error;
+
+pub use error::MisskeyError;
+
+///Default maximum number of characters in note.
+///
+///Instance can be configured to use different limit.
+pub const MAX_LEN: usize = 3000;
+
+///Checks whether hashtag is recognized by Misskey.
+///
+///Hashtag cannot contain whitespace, brackets and some punctuation, nor consist only of digits.
+pub fn is_valid_hash_tag(tag: &str) -> bool {
+    const FORBIDDEN: &str = ".,!?'\"#:/[]【】()「」（）<>";
+
+    tag.len() > 0 && !tag.contains(|ch: char| ch.is_whitespace() || FORBIDDEN.contains(ch)) && !tag.bytes().all(|byte| byte.is_ascii_digit())
+}
+
+///Misskey API
+pub struct Misskey {
+    config: crate::config::Misskey,
+}
+
+impl Misskey {
+    ///Verifies configuration and creates new instances.
+    pub fn new(config: crate::config::Misskey) -> Result<Self, MisskeyError> {
+        match config.host.parse::<Uri>() {
+            Ok(_) => (),
+            Err(_) => {
+                return Err(MisskeyError::InvalidHostUri);
+            }
+        }
+
+        if config.access_token.len() == 0 {
+            return Err(MisskeyError::InvalidToken);
+        }
+
+        Ok(Self {
+            config
+        })
+    }
+
+    ///Returns host of instance.
+    pub fn host(&self) -> &str {
+        &self.config.host
+    }
+
+    ///Prepares image upload request.
+    ///
+    ///Image is uploaded to drive and marked as sensitive when post is NSFW, as notes have no such flag.
+    ///
+    ///Result contains `id` from `DriveFile`
+    pub async fn upload_image(&self, name: &str, mime: &Mime, data: &[u8], flags: &PostFlags) -> Result<String, MisskeyError> {
+        let url = format!("https://{}/api/drive/files/create", &self.config.host);
+        let mut form = multipart::Form::new();
+
+        form.add_field("i".to_string(), self.config.access_token.as_bytes());
+        form.add_field("isSensitive".to_string(), flags.nsfw.to_string().as_bytes());
+        form.add_file_field("file".to_string(), name.to_string(), mime, data);
+
+        let req = Request::post(url).expect("To create request").multipart(form).global().send();
+
+        // For image we wait twice of time
+        // just to be sure
+        let mut resp = match matsu!(req) {
+            Ok(resp) => resp,
+            Err(err) => match matsu!(matsu!(err)) {
+                Ok(resp) => resp,
+                Err(_) => return Err(MisskeyError::ImageUploadSendError)
+            }
+        }.map_err(|_| MisskeyError::ImageUploadSendError)?;
+
+        if !resp.is_success() {
+            return Err(MisskeyError::ImageUploadServerReject)
+        }
+
+        match matsu!(resp.json::<data::DriveFile>()) {
+            Ok(data) => Ok(data.id),
+            Err(_) => Err(MisskeyError::ImageUploadInvalidResponse),
+        }
+    }
+
+    ///Prepares post upload request.
+    pub async fn post(&self, message: &str, file_ids: &[String], flags: &PostFlags, poll: Option<&Poll>) -> Result<crate::data::PublishedPost, MisskeyError> {
+        let url = format!("https://{}/api/notes/create", &self.config.host);
+        let req = Request::post(url).expect("To create request")
+                                    .json(&data::NewNote::new(&self.config.access_token, &message, &file_ids, &flags, poll))
+                                    .expect("To serialzie post data")
+                                    .global()
+                                    .send();
+
+        let mut resp = match matsu!(req) {
+            Ok(resp) => resp,
+            Err(err) => match matsu!(matsu!(err)) {
+                Ok(resp) => resp,
+                Err(_) => return Err(MisskeyError::PostUploadSendError)
+            }
+        }.map_err(|_| MisskeyError::PostUploadSendError)?;
+
+        if !resp.is_success() {
+            return Err(MisskeyError::PostUploadServerReject)
+        }
+
+        match matsu!(resp.json::<data::CreatedNote>()) {
+            Ok(data) => Ok(crate::data::PublishedPost {
+                url: format!("https://{}/notes/{}", self.config.host, data.created_note.id),
+                id: data.created_note.id.into(),
+                created_at: data.created_note.created_at,
+            }),
+            Err(_) => Err(MisskeyError::PostUploadInvalidResponse),
+        }
+    }
+
+    ///Performs request to read data from API's `path`.
+    async fn read<T: serde::de::DeserializeOwned>(&self, path: &str, payload: &data::ReadNotes<'_>) -> Result<T, MisskeyError> {
+        let url = format!("https://{}{}", &self.config.host, path);
+        let req = Request::post(url).map_err(|_| MisskeyError::ReadSendError)?
+                                    .json(payload)
+                                    .expect("To serialize json")
+                                    .global()
+                                    .send();
+
+        let mut resp = match matsu!(req) {
+            Ok(Ok(resp)) => resp,
+            _ => return Err(MisskeyError::ReadSendError),
+        };
+
+        if !resp.is_success() {
+            return Err(MisskeyError::ReadServerReject)
+        }
+
+        matsu!(resp.json::<T>()).map_err(|_| MisskeyError::ReadInvalidResponse)
+    }
+
+    ///Reads home timeline, newest first.
+    pub async fn home_timeline(&self, limit: usize) -> Result<Vec<crate::data::Status>, MisskeyError> {
+        let notes = matsu!(self.read::<Vec<data::Note>>("/api/notes/timeline", &data::ReadNotes::new(&self.config.access_token, limit, None)))?;
+        Ok(notes.into_iter().map(|note| note.into_status(&self.config.host)).collect())
+    }
+
+    ///Reads own notes, newest first.
+    pub async fn own_posts(&self, limit: usize) -> Result<Vec<crate::data::Status>, MisskeyError> {
+        let user = matsu!(self.read::<data::User>("/api/i", &data::ReadNotes::new(&self.config.access_token, limit, None)))?;
+        let notes = matsu!(self.read::<Vec<data::Note>>("/api/users/notes", &data::ReadNotes::new(&self.config.access_token, limit, Some(&user.id))))?;
+        Ok(notes.into_iter().map(|note| note.into_status(&self.config.host)).collect())
+    }
+
+    ///Reads notes that mention user, newest first.
+    pub async fn mentions(&self, limit: usize) -> Result<Vec<crate::data::Status>, MisskeyError> {
+        let notes = matsu!(self.read::<Vec<data::Note>>("/api/notes/mentions", &data::ReadNotes::new(&self.config.access_token, limit, None)))?;
+        Ok(notes.into_iter().map(|note| note.into_status(&self.config.host)).collect())
+    }
+
+    ///Reads engagement statistics of note with `id`.
+    pub async fn stats(&self, id: &str) -> Result<crate::data::PostStats, MisskeyError> {
+        let url = format!("https://{}/api/notes/show", &self.config.host);
+        let req = Request::post(url).map_err(|_| MisskeyError::StatsSendError)?
+                                    .json(&data::ShowNote::new(&self.config.access_token, id))
+                                    .expect("To serialize json")
+                                    .global()
+                                    .send();
+
+        let mut resp = match matsu!(req) {
+            Ok(Ok(resp)) => resp,
+            _ => return Err(MisskeyError::StatsSendError),
+        };
+
+        if !resp.is_success() {
+            return Err(MisskeyError::StatsServerReject)
+        }
+
+        match matsu!(resp.json::<data::Note>()) {
+            Ok(note) => Ok(note.stats()),
+            Err(_) => Err(MisskeyError::StatsInvalidResponse),
+        }
+    }
+
+    ///Resolves note's `url` into ID on own instance.
+    ///
+    ///Remote note is fetched by instance, if it doesn't know it yet.
+    pub async fn resolve(&self, url: &str) -> Result<String, MisskeyError> {
+        let own_prefix = format!("https://{}/notes/", self.config.host);
+        if url.starts_with(&own_prefix) {
+            let id = url[own_prefix.len()..].split(|ch| ch == '/' || ch == '?' || ch == '#').next().unwrap_or("");
+            if id.len() > 0 {
+                return Ok(id.to_owned());
+            }
+        }
+
+        let show_url = format!("https://{}/api/ap/show", self.config.host);
+        let req = Request::post(show_url).map_err(|_| MisskeyError::ResolveSendError)?
+                                         .json(&data::ApShow::new(&self.config.access_token, url))
+                                         .expect("To serialize json")
+                                         .global()
+                                         .send();
+
+        let mut resp = match matsu!(req) {
+            Ok(Ok(resp)) => resp,
+            _ => return Err(MisskeyError::ResolveSendError),
+        };
+
+        if !resp.is_success() {
+            return Err(MisskeyError::ResolveServerReject)
+        }
+
+        match matsu!(resp.json::<data::ApObject>()) {
+            Ok(object) if object.kind == "Note" => Ok(object.object.id),
+            _ => Err(MisskeyError::StatusNotFound),
+        }
+    }
+
+    ///Sends `payload` to API's `path` to perform action on note.
+    async fn interact<T: serde::Serialize>(&self, path: &str, payload: &T) -> Result<(), MisskeyError> {
+        let url = format!("https://{}{}", &self.config.host, path);
+        let req = Request::post(url).map_err(|_| MisskeyError::InteractionSendError)?
+                                    .json(payload)
+                                    .expect("To serialize json")
+                                    .global()
+                                    .send();
+
+        let resp = match matsu!(req) {
+            Ok(Ok(resp)) => resp,
+            _ => return Err(MisskeyError::InteractionSendError),
+        };
+
+        match resp.is_success() {
+            true => Ok(()),
+            false => Err(MisskeyError::InteractionServerReject),
+        }
+    }
+
+    ///Renotes note with `id`.
+    pub async fn boost(&self, id: &str) -> Result<(), MisskeyError> {
+        matsu!(self.interact("/api/notes/create", &data::Renote::new(&self.config.access_token, id)))
+    }
+
+    ///Reacts with heart on note with `id`.
+    pub async fn like(&self, id: &str) -> Result<(), MisskeyError> {
+        matsu!(self.interact("/api/notes/reactions/create", &data::Reaction::like(&self.config.access_token, id)))
+    }
+}
@@ -5,13 +5,39 @@ pub mod twitter;
 pub mod gab;
 pub mod mastodon;
 pub mod minds;
+pub mod bluesky;
+pub mod misskey;
+pub mod wordpress;
+pub mod matrix;
+pub mod telegram;
+pub mod discord;
+pub mod slack;
+pub mod linkedin;
+pub mod facebook;
+pub mod opengraph;
+pub mod preview;
+pub mod timeline;
+pub mod stats;
+pub mod interaction;
 
 use twitter::{Twitter, TwitterError};
 use gab::{Gab, GabError};
 use mastodon::{Mastodon, MastodonError};
 use minds::{Minds, MindsError};
+use bluesky::{Bluesky, BlueskyError};
+use misskey::{Misskey, MisskeyError};
+use wordpress::{WordPress, WordPressError};
+use matrix::{Matrix, MatrixError};
+use telegram::{Telegram, TelegramError};
+use discord::{Discord, DiscordError};
+use slack::{Slack, SlackError};
+use linkedin::{LinkedIn, LinkedInError};
+use facebook::{Facebook, FacebookError};
+use opengraph::OpenGraph;
 use http::{matsu};
-use crate::data::{join_hash_tags, PostId, Post};
+use crate::data::url::find_urls;
+use crate::data::tags::is_word_hash_tag;
+use crate::data::{normalize_hash_tags, place_hash_tags, replace_mentions, Image, PublishedPost, Post, PostOverride, TagPlacement};
 
 use super::config;
 
@@ -24,6 +50,14 @@ use std::io;
 pub enum ApiError {
     ///Unable to load Image for attachment
     CannotLoadImage(String, io::Error),
+    ///Hashtag is not accepted by platform.
+    InvalidHashTag(String),
+    ///Mention of person that is not in address book.
+    UnknownPerson(String),
+    ///URL of status cannot be parsed.
+    InvalidStatusUrl(String),
+    ///Status is from platform that cannot interact with it.
+    UnsupportedStatusUrl(String),
     ///Twitter error
     Twitter(TwitterError),
     ///Gab error
@@ -32,16 +66,79 @@ pub enum ApiError {
     Mastodon(MastodonError),
     ///Minds error
     Minds(MindsError),
+    ///Bluesky error
+    Bluesky(BlueskyError),
+    ///Pleroma error
+    Pleroma(MastodonError),
+    ///Misskey error
+    Misskey(MisskeyError),
+    ///WordPress error
+    WordPress(WordPressError),
+    ///Matrix error
+    Matrix(MatrixError),
+    ///Telegram error
+    Telegram(TelegramError),
+    ///Discord error
+    Discord(DiscordError),
+    ///Slack error
+    Slack(SlackError),
+    ///LinkedIn error
+    LinkedIn(LinkedInError),
+    ///Facebook error
+    Facebook(FacebookError),
 }
 
 impl fmt::Display for ApiError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
             &ApiError::CannotLoadImage(ref name, ref error) => write!(f, "Error opening image '{}'. Error: {}", name, error),
+            &ApiError::InvalidHashTag(ref tag) => write!(f, "Invalid hashtag '{}'. Platform does not recognize it", tag),
+            &ApiError::UnknownPerson(ref key) => write!(f, "Mention of unknown person '{}'. Add it to [people] section of config", key),
+            &ApiError::InvalidStatusUrl(ref url) => write!(f, "Invalid URL of status '{}'", url),
+            &ApiError::UnsupportedStatusUrl(ref url) => write!(f, "Status '{}' is from unsupported platform", url),
             &ApiError::Twitter(ref error) => write!(f, "Twitter API Error: {}", error),
             &ApiError::Gab(ref error) => write!(f, "Gab API Error: {}", error),
             &ApiError::Mastodon(ref error) => write!(f, "Mastodon API Error: {}", error),
             &ApiError::Minds(ref error) => write!(f, "MindsError API Error: {}", error),
+            &ApiError::Bluesky(ref error) => write!(f, "Bluesky API Error: {}", error),
+            &ApiError::Pleroma(ref error) => write!(f, "Pleroma API Error: {}", error),
+            &ApiError::Misskey(ref error) => write!(f, "Misskey API Error: {}", error),
+            &ApiError::WordPress(ref error) => write!(f, "WordPress API Error: {}", error),
+            &ApiError::Matrix(ref error) => write!(f, "Matrix API Error: {}", error),
+            &ApiError::Telegram(ref error) => write!(f, "Telegram API Error: {}", error),
+            &ApiError::Discord(ref error) => write!(f, "Discord API Error: {}", error),
+            &ApiError::Slack(ref error) => write!(f, "Slack API Error: {}", error),
+            &ApiError::LinkedIn(ref error) => write!(f, "LinkedIn API Error: {}", error),
+            &ApiError::Facebook(ref error) => write!(f, "Facebook API Error: {}", error),
+        }
+    }
+}
+
+impl ApiError {
+    ///Returns short identifier of error's kind.
+    ///
+    ///Platform errors use the same identifiers across platforms.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            &ApiError::CannotLoadImage(_, _) => "image_load",
+            &ApiError::InvalidHashTag(_) => "invalid_hashtag",
+            &ApiError::UnknownPerson(_) => "unknown_person",
+            &ApiError::InvalidStatusUrl(_) => "invalid_url",
+            &ApiError::UnsupportedStatusUrl(_) => "unsupported",
+            &ApiError::Twitter(ref error) => error.kind(),
+            &ApiError::Gab(ref error) => error.kind(),
+            &ApiError::Mastodon(ref error) => error.kind(),
+            &ApiError::Minds(ref error) => error.kind(),
+            &ApiError::Bluesky(ref error) => error.kind(),
+            &ApiError::Pleroma(ref error) => error.kind(),
+            &ApiError::Misskey(ref error) => error.kind(),
+            &ApiError::WordPress(ref error) => error.kind(),
+            &ApiError::Matrix(ref error) => error.kind(),
+            &ApiError::Telegram(ref error) => error.kind(),
+            &ApiError::Discord(ref error) => error.kind(),
+            &ApiError::Slack(ref error) => error.kind(),
+            &ApiError::LinkedIn(ref error) => error.kind(),
+            &ApiError::Facebook(ref error) => error.kind(),
         }
     }
 }
@@ -72,7 +169,61 @@ impl From<MindsError> for ApiError {
     }
 }
 
-type PostResultInner = (Option<Result<PostId, ApiError>>, Option<Result<PostId, ApiError>>, Option<Result<PostId, ApiError>>, Option<Result<PostId, ApiError>>);
+impl From<BlueskyError> for ApiError {
+    fn from(error: BlueskyError) -> Self {
+        ApiError::Bluesky(error)
+    }
+}
+
+impl From<MisskeyError> for ApiError {
+    fn from(error: MisskeyError) -> Self {
+        ApiError::Misskey(error)
+    }
+}
+
+impl From<WordPressError> for ApiError {
+    fn from(error: WordPressError) -> Self {
+        ApiError::WordPress(error)
+    }
+}
+
+impl From<MatrixError> for ApiError {
+    fn from(error: MatrixError) -> Self {
+        ApiError::Matrix(error)
+    }
+}
+
+impl From<TelegramError> for ApiError {
+    fn from(error: TelegramError) -> Self {
+        ApiError::Telegram(error)
+    }
+}
+
+impl From<DiscordError> for ApiError {
+    fn from(error: DiscordError) -> Self {
+        ApiError::Discord(error)
+    }
+}
+
+impl From<SlackError> for ApiError {
+    fn from(error: SlackError) -> Self {
+        ApiError::Slack(error)
+    }
+}
+
+impl From<LinkedInError> for ApiError {
+    fn from(error: LinkedInError) -> Self {
+        ApiError::LinkedIn(error)
+    }
+}
+
+impl From<FacebookError> for ApiError {
+    fn from(error: FacebookError) -> Self {
+        ApiError::Facebook(error)
+    }
+}
+
+type PostResultInner = (Option<Result<PublishedPost, ApiError>>, Option<Result<PublishedPost, ApiError>>, Option<Result<PublishedPost, ApiError>>, Option<Result<PublishedPost, ApiError>>, Option<Result<PublishedPost, ApiError>>, Option<Result<PublishedPost, ApiError>>, Option<Result<PublishedPost, ApiError>>, Option<Result<PublishedPost, ApiError>>, Option<Result<PublishedPost, ApiError>>, Option<Result<PublishedPost, ApiError>>, Option<Result<PublishedPost, ApiError>>, Option<Result<PublishedPost, ApiError>>, Option<Result<PublishedPost, ApiError>>, Option<Result<PublishedPost, ApiError>>);
 
 async fn post_result<T, E: Into<ApiError>, F: core::future::Future<Output=Result<T, E>>>(post: Option<F>) -> Option<Result<T, ApiError>> {
     match post {
@@ -81,6 +232,67 @@ async fn post_result<T, E: Into<ApiError>, F: core::future::Future<Output=Result
     }
 }
 
+///Passes result of platform to `on_result` once it is available.
+async fn notify<T, F: core::future::Future<Output=Option<Result<T, ApiError>>>, C: Fn(&'static str, &Result<T, ApiError>)>(platform: &'static str, result: F, on_result: &C) -> Option<Result<T, ApiError>> {
+    let result = matsu!(result);
+    if let Some(ref result) = result {
+        on_result(platform, result);
+    }
+    result
+}
+
+///Posts content prepared for platform, unless platform does not accept some of its hashtags.
+async fn post_content<'a, A: Copy, T, E: Into<ApiError>, F: core::future::Future<Output=Result<T, E>>, P: FnOnce(A, &'a Content) -> F>(prepared: &'a Option<(A, Content)>, post: P) -> Option<Result<T, ApiError>> {
+    match prepared {
+        Some((_, content)) if content.invalid_tags.len() > 0 => Some(Err(ApiError::InvalidHashTag(content.invalid_tags[0].clone()))),
+        Some((api, content)) => Some(matsu!(post(*api, content)).map_err(|err| err.into())),
+        None => None,
+    }
+}
+
+///Post's content, prepared for particular platform.
+struct Content {
+    message: String,
+    tags: Vec<String>,
+    images: Vec<Image>,
+    ///Hashtags that platform does not accept, and which are left out of message and tags.
+    invalid_tags: Vec<String>,
+}
+
+impl Content {
+    ///Creates content for platform, using `handle` to get person's account on it and `is_valid_tag` to check its hashtags.
+    fn new(post: &Post, overrides: &PostOverride, placement: TagPlacement, is_valid_tag: fn(&str) -> bool, people: &config::People, handle: fn(&config::Person) -> Option<&str>) -> Result<Self, ApiError> {
+        let mut content = Self::text(post, overrides, placement, is_valid_tag, people, handle)?;
+
+        for image in post.images_for(overrides) {
+            match Image::open(image) {
+                Ok(image) => content.images.push(image),
+                Err(error) => return Err(ApiError::CannotLoadImage(image.clone(), error)),
+            }
+        }
+
+        Ok(content)
+    }
+
+    ///Creates content for platform without loading images, for platforms that can only link them.
+    fn text(post: &Post, overrides: &PostOverride, placement: TagPlacement, is_valid_tag: fn(&str) -> bool, people: &config::People, handle: fn(&config::Person) -> Option<&str>) -> Result<Self, ApiError> {
+        let (tags, invalid_tags): (Vec<String>, Vec<String>) = normalize_hash_tags(post.tags_for(overrides)).into_iter().partition(|tag| is_valid_tag(tag));
+        //Person without account on platform is just named
+        let message = replace_mentions(post.message_for(overrides), |key| people.get(key).map(|person| match handle(person) {
+            Some(handle) => format!("@{}", handle.trim_start_matches('@')),
+            None => person.name.clone().unwrap_or_else(|| key.to_owned()),
+        })).map_err(ApiError::UnknownPerson)?;
+        let message = place_hash_tags(&message, &tags, placement);
+
+        Ok(Self {
+            message,
+            tags,
+            images: vec![],
+            invalid_tags,
+        })
+    }
+}
+
 ///Result of Post.
 pub struct PostResult {
     inner: PostResultInner,
@@ -88,28 +300,78 @@ pub struct PostResult {
 
 impl PostResult {
     ///Retrieves Twitter's result
-    pub fn twitter(&mut self) -> Option<Result<PostId, ApiError>> {
+    pub fn twitter(&mut self) -> Option<Result<PublishedPost, ApiError>> {
         self.inner.0.take()
     }
 
     ///Retrieves Gab's result
-    pub fn gab(&mut self) -> Option<Result<PostId, ApiError>> {
+    pub fn gab(&mut self) -> Option<Result<PublishedPost, ApiError>> {
         self.inner.1.take()
     }
 
     ///Retrieves Mastodon's result
-    pub fn mastodon(&mut self) -> Option<Result<PostId, ApiError>> {
+    pub fn mastodon(&mut self) -> Option<Result<PublishedPost, ApiError>> {
         self.inner.2.take()
     }
 
     ///Retrieves Minds's result
-    pub fn minds(&mut self) -> Option<Result<PostId, ApiError>> {
+    pub fn minds(&mut self) -> Option<Result<PublishedPost, ApiError>> {
         self.inner.3.take()
     }
 
+    ///Retrieves Bluesky's result
+    pub fn bluesky(&mut self) -> Option<Result<PublishedPost, ApiError>> {
+        self.inner.4.take()
+    }
+
+    ///Retrieves Pleroma's result
+    pub fn pleroma(&mut self) -> Option<Result<PublishedPost, ApiError>> {
+        self.inner.5.take()
+    }
+
+    ///Retrieves Misskey's result
+    pub fn misskey(&mut self) -> Option<Result<PublishedPost, ApiError>> {
+        self.inner.6.take()
+    }
+
+    ///Retrieves WordPress's result
+    pub fn wordpress(&mut self) -> Option<Result<PublishedPost, ApiError>> {
+        self.inner.7.take()
+    }
+
+    ///Retrieves Matrix's result
+    pub fn matrix(&mut self) -> Option<Result<PublishedPost, ApiError>> {
+        self.inner.8.take()
+    }
+
+    ///Retrieves Telegram's result
+    pub fn telegram(&mut self) -> Option<Result<PublishedPost, ApiError>> {
+        self.inner.9.take()
+    }
+
+    ///Retrieves Discord's result
+    pub fn discord(&mut self) -> Option<Result<PublishedPost, ApiError>> {
+        self.inner.10.take()
+    }
+
+    ///Retrieves Slack's result
+    pub fn slack(&mut self) -> Option<Result<PublishedPost, ApiError>> {
+        self.inner.11.take()
+    }
+
+    ///Retrieves LinkedIn's result
+    pub fn linkedin(&mut self) -> Option<Result<PublishedPost, ApiError>> {
+        self.inner.12.take()
+    }
+
+    ///Retrieves Facebook's result
+    pub fn facebook(&mut self) -> Option<Result<PublishedPost, ApiError>> {
+        self.inner.13.take()
+    }
+
     ///Retrieves underlying errors.
     ///
-    ///Order: Twitter, Gab, Mastodon, Minds
+    ///Order: Twitter, Gab, Mastodon, Minds, Bluesky, Pleroma, Misskey, WordPress, Matrix, Telegram, Discord, Slack, LinkedIn, Facebook
     pub fn into_parts(self) -> PostResultInner {
         self.inner
     }
@@ -117,10 +379,22 @@ impl PostResult {
 
 ///API access
 pub struct API {
+    tags: config::Tags,
+    people: config::People,
     twitter: Option<Twitter>,
     gab: Option<Gab>,
     mastodon: Option<Mastodon>,
     minds: Option<Minds>,
+    bluesky: Option<Bluesky>,
+    pleroma: Option<Mastodon>,
+    misskey: Option<Misskey>,
+    wordpress: Option<WordPress>,
+    matrix: Option<Matrix>,
+    telegram: Option<Telegram>,
+    discord: Option<Discord>,
+    slack: Option<Slack>,
+    linkedin: Option<LinkedIn>,
+    facebook: Option<Facebook>,
 }
 
 impl API {
@@ -128,13 +402,30 @@ impl API {
     pub fn new(settings: config::Settings) -> Self {
         http::set_timeout(&settings);
         Self {
+            tags: settings.tags,
+            people: config::People::new(),
             twitter: None,
             mastodon: None,
             gab: None,
             minds: None,
+            bluesky: None,
+            pleroma: None,
+            misskey: None,
+            wordpress: None,
+            matrix: None,
+            telegram: None,
+            discord: None,
+            slack: None,
+            linkedin: None,
+            facebook: None,
         }
     }
 
+    ///Sets address book, used to expand mentions in form `{@key}`.
+    pub fn set_people(&mut self, people: config::People) {
+        self.people = people;
+    }
+
     ///Performs initial configuration of Twitter API.
     pub fn configure_twitter(&mut self, config: config::Twitter) -> Result<(), ApiError> {
         if self.twitter.is_some() {
@@ -201,10 +492,21 @@ impl API {
             return Ok(());
         }
 
-        self.minds = Some(matsu!(Minds::new(config))?);
+        let mut minds = Minds::new(config);
+        matsu!(minds.login())?;
+        self.minds = Some(minds);
         Ok(())
     }
 
+    ///Performs initial configuration of Minds API, skipping authorization.
+    ///
+    ///Posting is going to fail, therefore it is only useful for `preview`.
+    pub fn configure_minds_offline(&mut self, config: config::Minds) {
+        if self.minds.is_none() {
+            self.minds = Some(Minds::new(config));
+        }
+    }
+
     ///Enables Minds back, if it was enabled
     pub fn enable_minds(&mut self, minds: Option<Minds>) {
         self.minds = minds;
@@ -215,87 +517,533 @@ impl API {
         self.minds.take()
     }
 
+    ///Performs initial configuration of Bluesky API.
+    pub async fn configure_bluesky(&mut self, config: config::Bluesky) -> Result<(), ApiError> {
+        if self.bluesky.is_some() {
+            return Ok(());
+        }
+
+        let mut bluesky = Bluesky::new(config)?;
+        matsu!(bluesky.login())?;
+        self.bluesky = Some(bluesky);
+        Ok(())
+    }
+
+    ///Performs initial configuration of Bluesky API, skipping creation of session.
+    ///
+    ///Posting is going to fail, therefore it is only useful for `preview`.
+    pub fn configure_bluesky_offline(&mut self, config: config::Bluesky) -> Result<(), ApiError> {
+        if self.bluesky.is_none() {
+            self.bluesky = Some(Bluesky::new(config)?);
+        }
+
+        Ok(())
+    }
+
+    ///Enables Bluesky back, if it was enabled
+    pub fn enable_bluesky(&mut self, bluesky: Option<Bluesky>) {
+        self.bluesky = bluesky;
+    }
+
+    ///Disables Bluesky.
+    pub fn disable_bluesky(&mut self) -> Option<Bluesky> {
+        self.bluesky.take()
+    }
+
+    ///Performs initial configuration of Pleroma API.
+    pub fn configure_pleroma(&mut self, config: config::Pleroma) -> Result<(), ApiError> {
+        if self.pleroma.is_some() {
+            return Ok(());
+        }
+
+        self.pleroma = Some(Mastodon::pleroma(config).map_err(ApiError::Pleroma)?);
+        Ok(())
+    }
+
+    ///Enables Pleroma back, if it was enabled
+    pub fn enable_pleroma(&mut self, pleroma: Option<Mastodon>) {
+        self.pleroma = pleroma;
+    }
+
+    ///Disables Pleroma.
+    pub fn disable_pleroma(&mut self) -> Option<Mastodon> {
+        self.pleroma.take()
+    }
+
+    ///Performs initial configuration of Misskey API.
+    pub fn configure_misskey(&mut self, config: config::Misskey) -> Result<(), ApiError> {
+        if self.misskey.is_some() {
+            return Ok(());
+        }
+
+        self.misskey = Some(Misskey::new(config)?);
+        Ok(())
+    }
+
+    ///Enables Misskey back, if it was enabled
+    pub fn enable_misskey(&mut self, misskey: Option<Misskey>) {
+        self.misskey = misskey;
+    }
+
+    ///Disables Misskey.
+    pub fn disable_misskey(&mut self) -> Option<Misskey> {
+        self.misskey.take()
+    }
+
+    ///Performs initial configuration of WordPress API.
+    pub fn configure_wordpress(&mut self, config: config::WordPress) -> Result<(), ApiError> {
+        if self.wordpress.is_some() {
+            return Ok(());
+        }
+
+        self.wordpress = Some(WordPress::new(config)?);
+        Ok(())
+    }
+
+    ///Enables WordPress back, if it was enabled
+    pub fn enable_wordpress(&mut self, wordpress: Option<WordPress>) {
+        self.wordpress = wordpress;
+    }
+
+    ///Disables WordPress.
+    pub fn disable_wordpress(&mut self) -> Option<WordPress> {
+        self.wordpress.take()
+    }
+
+    ///Performs initial configuration of Matrix API.
+    pub fn configure_matrix(&mut self, config: config::Matrix) -> Result<(), ApiError> {
+        if self.matrix.is_some() {
+            return Ok(());
+        }
+
+        self.matrix = Some(Matrix::new(config)?);
+        Ok(())
+    }
+
+    ///Enables Matrix back, if it was enabled
+    pub fn enable_matrix(&mut self, matrix: Option<Matrix>) {
+        self.matrix = matrix;
+    }
+
+    ///Disables Matrix.
+    pub fn disable_matrix(&mut self) -> Option<Matrix> {
+        self.matrix.take()
+    }
+
+    ///Performs initial configuration of Telegram API.
+    pub fn configure_telegram(&mut self, config: config::Telegram) -> Result<(), ApiError> {
+        if self.telegram.is_some() {
+            return Ok(());
+        }
+
+        self.telegram = Some(Telegram::new(config)?);
+        Ok(())
+    }
+
+    ///Enables Telegram back, if it was enabled
+    pub fn enable_telegram(&mut self, telegram: Option<Telegram>) {
+        self.telegram = telegram;
+    }
+
+    ///Disables Telegram.
+    pub fn disable_telegram(&mut self) -> Option<Telegram> {
+        self.telegram.take()
+    }
+
+    ///Performs initial configuration of Discord API.
+    pub fn configure_discord(&mut self, config: config::Discord) -> Result<(), ApiError> {
+        if self.discord.is_some() {
+            return Ok(());
+        }
+
+        self.discord = Some(Discord::new(config)?);
+        Ok(())
+    }
+
+    ///Enables Discord back, if it was enabled
+    pub fn enable_discord(&mut self, discord: Option<Discord>) {
+        self.discord = discord;
+    }
+
+    ///Disables Discord.
+    pub fn disable_discord(&mut self) -> Option<Discord> {
+        self.discord.take()
+    }
+
+    ///Performs initial configuration of Slack API.
+    pub fn configure_slack(&mut self, config: config::Slack) -> Result<(), ApiError> {
+        if self.slack.is_some() {
+            return Ok(());
+        }
+
+        self.slack = Some(Slack::new(config)?);
+        Ok(())
+    }
+
+    ///Enables Slack back, if it was enabled
+    pub fn enable_slack(&mut self, slack: Option<Slack>) {
+        self.slack = slack;
+    }
+
+    ///Disables Slack.
+    pub fn disable_slack(&mut self) -> Option<Slack> {
+        self.slack.take()
+    }
+
+    ///Performs initial configuration of LinkedIn API.
+    pub fn configure_linkedin(&mut self, config: config::LinkedIn) -> Result<(), ApiError> {
+        if self.linkedin.is_some() {
+            return Ok(());
+        }
+
+        self.linkedin = Some(LinkedIn::new(config)?);
+        Ok(())
+    }
+
+    ///Enables LinkedIn back, if it was enabled
+    pub fn enable_linkedin(&mut self, linkedin: Option<LinkedIn>) {
+        self.linkedin = linkedin;
+    }
+
+    ///Disables LinkedIn.
+    pub fn disable_linkedin(&mut self) -> Option<LinkedIn> {
+        self.linkedin.take()
+    }
+
+    ///Performs initial configuration of Facebook API.
+    pub fn configure_facebook(&mut self, config: config::Facebook) -> Result<(), ApiError> {
+        if self.facebook.is_some() {
+            return Ok(());
+        }
+
+        self.facebook = Some(Facebook::new(config)?);
+        Ok(())
+    }
+
+    ///Enables Facebook back, if it was enabled
+    pub fn enable_facebook(&mut self, facebook: Option<Facebook>) {
+        self.facebook = facebook;
+    }
+
+    ///Disables Facebook.
+    pub fn disable_facebook(&mut self) -> Option<Facebook> {
+        self.facebook.take()
+    }
+
+    ///Returns names of enabled platforms.
+    pub fn platforms(&self) -> Vec<&'static str> {
+        let platforms = [
+            ("twitter", self.twitter.is_some()),
+            ("gab", self.gab.is_some()),
+            ("mastodon", self.mastodon.is_some()),
+            ("minds", self.minds.is_some()),
+            ("bluesky", self.bluesky.is_some()),
+            ("pleroma", self.pleroma.is_some()),
+            ("misskey", self.misskey.is_some()),
+            ("wordpress", self.wordpress.is_some()),
+            ("matrix", self.matrix.is_some()),
+            ("telegram", self.telegram.is_some()),
+            ("discord", self.discord.is_some()),
+            ("slack", self.slack.is_some()),
+            ("linkedin", self.linkedin.is_some()),
+            ("facebook", self.facebook.is_some()),
+        ];
+
+        platforms.iter().filter(|(_, is_enabled)| *is_enabled).map(|(name, _)| *name).collect()
+    }
+
     ///Sends Post to enabled APIs (blocking)
     pub async fn send(&self, post: Post) -> Result<PostResult, ApiError> {
-        let Post { message, tags, flags, mut images } = post;
+        matsu!(self.send_with(post, |_, _| ()))
+    }
 
-        let message = if tags.len() > 0 {
-            match message.as_str() {
-                "" => join_hash_tags(&tags),
-                message => format!("{}\n{}", message, join_hash_tags(&tags)),
-            }
-        } else {
-            message
+    ///Sends Post to enabled APIs, calling `on_result` with name of platform as soon as it finishes.
+    ///
+    ///Allows to record progress before all platforms finish.
+    pub async fn send_with<C: Fn(&'static str, &Result<PublishedPost, ApiError>)>(&self, post: Post, on_result: C) -> Result<PostResult, ApiError> {
+        let twitter = match self.twitter {
+            Some(ref twitter) => Some((twitter, Content::new(&post, &post.twitter, self.tags.twitter, is_word_hash_tag, &self.people, |person| person.twitter.as_deref())?)),
+            None => None,
+        };
+        let gab = match self.gab {
+            Some(ref gab) => Some((gab, Content::new(&post, &post.gab, self.tags.gab, is_word_hash_tag, &self.people, |person| person.gab.as_deref())?)),
+            None => None,
+        };
+        let mastodon = match self.mastodon {
+            Some(ref mastodon) => Some((mastodon, Content::new(&post, &post.mastodon, self.tags.mastodon, mastodon::is_valid_hash_tag, &self.people, |person| person.mastodon.as_deref())?)),
+            None => None,
+        };
+        let minds = match self.minds {
+            Some(ref minds) => Some((minds, Content::new(&post, &post.minds, self.tags.minds, is_word_hash_tag, &self.people, |person| person.minds.as_deref())?)),
+            None => None,
+        };
+        let bluesky = match self.bluesky {
+            Some(ref bluesky) => Some((bluesky, Content::new(&post, &post.bluesky, self.tags.bluesky, bluesky::is_valid_hash_tag, &self.people, |person| person.bluesky.as_deref())?)),
+            None => None,
+        };
+        let pleroma = match self.pleroma {
+            Some(ref pleroma) => Some((pleroma, Content::new(&post, &post.pleroma, self.tags.pleroma, mastodon::is_valid_hash_tag, &self.people, |person| person.pleroma.as_deref())?)),
+            None => None,
+        };
+        let misskey = match self.misskey {
+            Some(ref misskey) => Some((misskey, Content::new(&post, &post.misskey, self.tags.misskey, misskey::is_valid_hash_tag, &self.people, |person| person.misskey.as_deref())?)),
+            None => None,
+        };
+        //Blog has no accounts to mention, while tags are its own taxonomy
+        let wordpress = match self.wordpress {
+            Some(ref wordpress) => Some((wordpress, Content::new(&post, &post.wordpress, self.tags.wordpress, |_| true, &self.people, |_| None)?)),
+            None => None,
+        };
+        //Chats do not recognize hashtags, so any is kept in text
+        let matrix = match self.matrix {
+            Some(ref matrix) => Some((matrix, Content::new(&post, &post.matrix, self.tags.matrix, |_| true, &self.people, |person| person.matrix.as_deref())?)),
+            None => None,
+        };
+        let telegram = match self.telegram {
+            Some(ref telegram) => Some((telegram, Content::new(&post, &post.telegram, self.tags.telegram, is_word_hash_tag, &self.people, |person| person.telegram.as_deref())?)),
+            None => None,
+        };
+        //Webhooks post under own name, so people are just named
+        let discord = match self.discord {
+            Some(ref discord) => Some((discord, Content::new(&post, &post.discord, self.tags.discord, |_| true, &self.people, |_| None)?)),
+            None => None,
+        };
+        let slack = match self.slack {
+            Some(ref slack) => Some((slack, Content::text(&post, &post.slack, self.tags.slack, |_| true, &self.people, |_| None)?)),
+            None => None,
+        };
+        //Mentions require member's URN, so people are just named
+        let linkedin = match self.linkedin {
+            Some(ref linkedin) => Some((linkedin, Content::new(&post, &post.linkedin, self.tags.linkedin, is_word_hash_tag, &self.people, |_| None)?)),
+            None => None,
+        };
+        let facebook = match self.facebook {
+            Some(ref facebook) => Some((facebook, Content::new(&post, &post.facebook, self.tags.facebook, is_word_hash_tag, &self.people, |_| None)?)),
+            None => None,
         };
+        let slack_images = slack::image_urls(post.images_for(&post.slack));
+        let slack_images = &slack_images;
 
-        let message = message.as_str();
-        let flags = &flags;
-
-        let inner = match images.len() {
-            0 => {
-                let twitter = post_result(self.twitter.as_ref().map(|twitter| twitter.post(&message, &[], &flags)));
-                let gab = post_result(self.gab.as_ref().map(|gab| gab.post(&message, &[], &flags)));
-                let mastodon = post_result(self.mastodon.as_ref().map(|mastodon| mastodon.post(&message, &[], &flags)));
-                let minds = post_result(self.minds.as_ref().map(|minds| minds.post(&message, None, &flags)));
-                futures_util::join!(twitter, gab, mastodon, minds)
-            },
-            _ => {
-                let images = {
-                    let mut result = vec![];
-                    for image in images.drain(..) {
-                        match crate::data::Image::open(&image) {
-                            Ok(image) => result.push(image),
-                            Err(error) => {
-                                return Err(ApiError::CannotLoadImage(image, error));
-                            },
-                        };
-                    }
-                    result
-                };
-
-                let images = &images[..];
-
-                let twitter = post_result(self.twitter.as_ref().map(|twitter| async move {
-                    let mut uploads = vec![];
-                    for image in images.iter() {
-                        let upload = matsu!(twitter.upload_image(&image.name, &image.mime, &image.mmap[..]))?;
-                        uploads.push(upload);
-                    }
-
-                    matsu!(twitter.post(&message, &uploads, &flags))
-                }));
-
-                let gab = post_result(self.gab.as_ref().map(|gab| async move {
-                    let mut uploads = vec![];
-                    for image in images.iter() {
-                        let upload = matsu!(gab.upload_image(&image.name, &image.mime, &image.mmap[..]))?;
-                        uploads.push(upload);
-                    }
-
-                    matsu!(gab.post(&message, &uploads, &flags))
-                }));
-
-                let mastodon = post_result(self.mastodon.as_ref().map(|mastodon| async move {
-                    let mut uploads = vec![];
-                    for image in images.iter() {
-                        let upload = matsu!(mastodon.upload_image(&image.name, &image.mime, &image.mmap[..]))?;
-                        uploads.push(upload);
-                    }
-
-                    matsu!(mastodon.post(&message, &uploads, &flags))
-                }));
-
-                let minds = post_result(self.minds.as_ref().map(|minds| async move {
-                    let image = unsafe { images.get_unchecked(0) };
-                    let upload = matsu!(minds.upload_image(&image.name, &image.mime, &image.mmap[..]))?;
-
-                    matsu!(minds.post(&message, Some(upload), &flags))
-                }));
-
-                let res = futures_util::join!(twitter, gab, mastodon, minds);
-                res
+        let flags = &post.flags;
+        let poll = post.poll.as_ref();
+        let title = post.title.as_deref();
+        let quote = post.quote.as_deref();
+
+        let twitter = post_content(&twitter, |twitter, content| async move {
+            if let Some(poll) = poll {
+                twitter.check_poll(poll)?;
             }
-        };
+
+            let mut uploads = vec![];
+            for image in content.images.iter() {
+                let upload = matsu!(twitter.upload_image(&image.name, &image.mime, &image.mmap[..]))?;
+                uploads.push(upload);
+            }
+
+            matsu!(twitter.post(&content.message, &uploads, quote, poll, &flags))
+        });
+
+        let gab = post_content(&gab, |gab, content| async move {
+            if poll.is_some() && content.images.len() > 0 {
+                return Err(GabError::PollWithImagesUnsupported);
+            }
+
+            let mut uploads = vec![];
+            for image in content.images.iter() {
+                let upload = matsu!(gab.upload_image(&image.name, &image.mime, &image.mmap[..]))?;
+                uploads.push(upload);
+            }
+
+            matsu!(gab.post(&content.message, &uploads, &flags, poll))
+        });
+
+        let mastodon = post_content(&mastodon, |mastodon, content| async move {
+            if poll.is_some() && content.images.len() > 0 {
+                return Err(MastodonError::PollWithImagesUnsupported);
+            }
+
+            let mut uploads = vec![];
+            for image in content.images.iter() {
+                let upload = matsu!(mastodon.upload_image(&image.name, &image.mime, &image.mmap[..]))?;
+                uploads.push(upload);
+            }
+
+            matsu!(mastodon.post(&content.message, &uploads, &flags, poll))
+        });
+
+        //Minds allows only single attachment
+        let minds = post_content(&minds, |minds, content| async move {
+            if poll.is_some() {
+                return Err(MindsError::PollUnsupported);
+            }
+
+            let upload = match content.images.first() {
+                Some(image) => Some(matsu!(minds.upload_image(&image.name, &image.mime, &image.mmap[..]))?),
+                None => None,
+            };
+
+            //Rich post cannot have attachment
+            let preview = match (upload.is_some() || flags.no_preview, find_urls(&content.message).first()) {
+                (false, Some(url)) => matsu!(OpenGraph::fetch(url)),
+                _ => None,
+            };
+
+            matsu!(minds.post(&content.message, upload, &content.tags, preview.as_ref(), &flags))
+        });
+
+        let bluesky = post_content(&bluesky, |bluesky, content| async move {
+            if poll.is_some() {
+                return Err(BlueskyError::PollUnsupported);
+            } else if content.images.len() > bluesky::MAX_IMAGES {
+                return Err(BlueskyError::TooManyImages);
+            }
+
+            let mut uploads = vec![];
+            for image in content.images.iter() {
+                let upload = matsu!(bluesky.upload_image(&image.mime, &image.mmap[..]))?;
+                uploads.push(upload);
+            }
+
+            //Link's card cannot be used together with images
+            let preview = match (uploads.len() > 0 || flags.no_preview, find_urls(&content.message).first()) {
+                (false, Some(url)) => matsu!(OpenGraph::fetch(url)),
+                _ => None,
+            };
+
+            matsu!(bluesky.post(&content.message, &uploads, preview.as_ref(), &flags))
+        });
+
+        //Pleroma shares client with Mastodon, so its errors are wrapped explicitly
+        let pleroma = post_content(&pleroma, |pleroma, content| async move {
+            if poll.is_some() && content.images.len() > 0 {
+                return Err(ApiError::Pleroma(MastodonError::PollWithImagesUnsupported));
+            }
+
+            let mut uploads = vec![];
+            for image in content.images.iter() {
+                let upload = matsu!(pleroma.upload_image(&image.name, &image.mime, &image.mmap[..])).map_err(ApiError::Pleroma)?;
+                uploads.push(upload);
+            }
+
+            matsu!(pleroma.post(&content.message, &uploads, &flags, poll)).map_err(ApiError::Pleroma)
+        });
+
+        let misskey = post_content(&misskey, |misskey, content| async move {
+            let mut uploads = vec![];
+            for image in content.images.iter() {
+                let upload = matsu!(misskey.upload_image(&image.name, &image.mime, &image.mmap[..], &flags))?;
+                uploads.push(upload);
+            }
+
+            matsu!(misskey.post(&content.message, &uploads, &flags, poll))
+        });
+
+        let wordpress = post_content(&wordpress, |wordpress, content| async move {
+            if poll.is_some() {
+                return Err(WordPressError::PollUnsupported);
+            }
+
+            let mut uploads = vec![];
+            for image in content.images.iter() {
+                let upload = matsu!(wordpress.upload_image(&image.name, &image.mime, &image.mmap[..]))?;
+                uploads.push(upload);
+            }
+
+            let mut tags = vec![];
+            for tag in content.tags.iter() {
+                tags.push(matsu!(wordpress.tag_id(tag))?);
+            }
+
+            matsu!(wordpress.post(title, &content.message, &uploads, &tags))
+        });
+
+        let matrix = post_content(&matrix, |matrix, content| async move {
+            if poll.is_some() {
+                return Err(MatrixError::PollUnsupported);
+            }
+
+            let mut uploads = vec![];
+            for image in content.images.iter() {
+                let upload = matsu!(matrix.upload_image(&image.name, &image.mime, &image.mmap[..]))?;
+                uploads.push(upload);
+            }
+
+            matsu!(matrix.post(&content.message, &uploads))
+        });
+
+        //Images are uploaded together with message
+        let telegram = post_content(&telegram, |telegram, content| async move {
+            if poll.is_some() {
+                return Err(TelegramError::PollUnsupported);
+            }
+
+            match content.images.len() {
+                0 => matsu!(telegram.post(&content.message, &flags)),
+                _ => matsu!(telegram.post_images(&content.message, &content.images, &flags)),
+            }
+        });
+
+        let discord = post_content(&discord, |discord, content| async move {
+            if poll.is_some() {
+                return Err(DiscordError::PollUnsupported);
+            }
+
+            matsu!(discord.post(&content.message, &content.images, &flags))
+        });
+
+        let slack = post_content(&slack, |slack, content| async move {
+            if poll.is_some() {
+                return Err(SlackError::PollUnsupported);
+            }
+
+            let images = slack_images.as_ref().ok_or(SlackError::LocalImageUnsupported)?;
+            matsu!(slack.post(&content.message, images))
+        });
+
+        let linkedin = post_content(&linkedin, |linkedin, content| async move {
+            if poll.is_some() {
+                return Err(LinkedInError::PollUnsupported);
+            }
+
+            let mut uploads = vec![];
+            for image in content.images.iter() {
+                let upload = matsu!(linkedin.upload_image(&image.mime, &image.mmap[..]))?;
+                uploads.push(upload);
+            }
+
+            matsu!(linkedin.post(&content.message, &uploads, &flags))
+        });
+
+        let facebook = post_content(&facebook, |facebook, content| async move {
+            if poll.is_some() {
+                return Err(FacebookError::PollUnsupported);
+            }
+
+            let mut uploads = vec![];
+            for image in content.images.iter() {
+                let upload = matsu!(facebook.upload_image(&image.name, &image.mime, &image.mmap[..]))?;
+                uploads.push(upload);
+            }
+
+            matsu!(facebook.post(&content.message, &uploads))
+        });
+
+        let twitter = notify("twitter", twitter, &on_result);
+        let gab = notify("gab", gab, &on_result);
+        let mastodon = notify("mastodon", mastodon, &on_result);
+        let minds = notify("minds", minds, &on_result);
+        let bluesky = notify("bluesky", bluesky, &on_result);
+        let pleroma = notify("pleroma", pleroma, &on_result);
+        let misskey = notify("misskey", misskey, &on_result);
+        let wordpress = notify("wordpress", wordpress, &on_result);
+        let matrix = notify("matrix", matrix, &on_result);
+        let telegram = notify("telegram", telegram, &on_result);
+        let discord = notify("discord", discord, &on_result);
+        let slack = notify("slack", slack, &on_result);
+        let linkedin = notify("linkedin", linkedin, &on_result);
+        let facebook = notify("facebook", facebook, &on_result);
+
+        let inner = futures_util::join!(twitter, gab, mastodon, minds, bluesky, pleroma, misskey, wordpress, matrix, telegram, discord, slack, linkedin, facebook);
 
         Ok(PostResult {
             inner,
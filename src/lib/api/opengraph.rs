@@ -0,0 +1,174 @@
+//!OpenGraph metadata of links
+
+use super::http::{GlobalRequest, Request, matsu};
+
+///Link's preview, described by OpenGraph meta tags.
+#[derive(Debug, Clone, Default)]
+pub struct OpenGraph {
+    ///Canonical URL
+    pub url: String,
+    ///Title
+    pub title: Option<String>,
+    ///Short description
+    pub description: Option<String>,
+    ///URL of image
+    pub image: Option<String>,
+}
+
+fn decode_entities(text: &str) -> String {
+    text.replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&#x27;", "'")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&amp;", "&")
+}
+
+///Extracts attributes of single tag.
+///
+///Names are lowercased.
+fn parse_attributes(tag: &str) -> Vec<(String, String)> {
+    let mut result = Vec::new();
+    let mut rest = tag;
+
+    loop {
+        rest = rest.trim_start_matches(|ch: char| ch.is_whitespace() || ch == '/');
+        let name_end = match rest.find(|ch: char| ch.is_whitespace() || ch == '=' || ch == '/') {
+            Some(0) | None => break,
+            Some(name_end) => name_end,
+        };
+        let name = rest[..name_end].to_ascii_lowercase();
+        rest = rest[name_end..].trim_start();
+
+        if !rest.starts_with('=') {
+            continue;
+        }
+        rest = rest[1..].trim_start();
+
+        let value = match rest.chars().next() {
+            Some(quote) if quote == '"' || quote == '\'' => match rest[1..].find(quote) {
+                Some(end) => {
+                    let value = &rest[1..end + 1];
+                    rest = &rest[end + 2..];
+                    value
+                },
+                None => break,
+            },
+            _ => {
+                let end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+                let value = &rest[..end];
+                rest = &rest[end..];
+                value
+            }
+        };
+
+        result.push((name, decode_entities(value)));
+    }
+
+    result
+}
+
+impl OpenGraph {
+    ///Extracts OpenGraph meta tags from HTML page.
+    ///
+    ///Returns `None` if page has neither title nor description.
+    pub fn parse(url: &str, html: &str) -> Option<Self> {
+        let mut result = Self {
+            url: url.to_owned(),
+            ..Default::default()
+        };
+
+        //ASCII lowercase preserves offsets, so it is safe to use them with original
+        let lowercase = html.to_ascii_lowercase();
+        let mut offset = 0;
+
+        while let Some(start) = lowercase[offset..].find("<meta") {
+            let start = offset + start + "<meta".len();
+            let end = match lowercase[start..].find('>') {
+                Some(end) => start + end,
+                None => break,
+            };
+            offset = end;
+
+            let attributes = parse_attributes(&html[start..end]);
+            let property = attributes.iter().find(|(name, _)| name == "property" || name == "name").map(|(_, value)| value.as_str());
+            let content = attributes.iter().find(|(name, _)| name == "content").map(|(_, value)| value.clone());
+
+            match (property, content) {
+                (Some("og:url"), Some(content)) => result.url = content,
+                (Some("og:title"), Some(content)) => result.title = Some(content),
+                (Some("og:description"), Some(content)) => result.description = Some(content),
+                (Some("og:image"), Some(content)) => result.image = Some(content),
+                _ => (),
+            }
+        }
+
+        match result.title.is_some() || result.description.is_some() {
+            true => Some(result),
+            false => None,
+        }
+    }
+
+    ///Retrieves page and extracts its metadata.
+    ///
+    ///Any failure, including timeout, results in `None`.
+    pub async fn fetch(url: &str) -> Option<Self> {
+        let req = match Request::get(url) {
+            Ok(req) => req.empty().global().send_redirect(),
+            Err(_) => return None,
+        };
+
+        let mut resp = match matsu!(req) {
+            Ok(Ok(resp)) => resp,
+            _ => return None,
+        };
+
+        if !resp.is_success() {
+            return None;
+        }
+
+        match matsu!(resp.text()) {
+            Ok(html) => Self::parse(url, &html),
+            Err(_) => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_parse_attributes() {
+        let attributes = parse_attributes(r#" Property="og:title" content='Tom &amp; &quot;Jerry&quot;' data-x=raw disabled /"#);
+        assert_eq!(attributes, [
+            ("property".to_owned(), "og:title".to_owned()),
+            ("content".to_owned(), "Tom & \"Jerry\"".to_owned()),
+            ("data-x".to_owned(), "raw".to_owned()),
+        ]);
+    }
+
+    #[test]
+    fn should_parse_open_graph() {
+        let html = r#"<html><HEAD>
+            <META property="og:title" content="Title">
+            <meta name="og:description" content="Description &lt;3" />
+            <meta content="https://example.com/image.png" property="og:image">
+            <meta property="og:url" content="https://example.com/canonical">
+            <meta charset="utf-8">
+        </HEAD></html>"#;
+
+        let graph = OpenGraph::parse("https://example.com/page", html).expect("To parse OpenGraph");
+        assert_eq!(graph.url, "https://example.com/canonical");
+        assert_eq!(graph.title.as_deref(), Some("Title"));
+        assert_eq!(graph.description.as_deref(), Some("Description <3"));
+        assert_eq!(graph.image.as_deref(), Some("https://example.com/image.png"));
+    }
+
+    #[test]
+    fn should_not_parse_page_without_title_and_description() {
+        let html = r#"<meta property="og:image" content="https://example.com/image.png"><title>Page</title>"#;
+        assert!(OpenGraph::parse("https://example.com", html).is_none());
+        assert!(OpenGraph::parse("https://example.com", "<meta property=\"og:title\"").is_none());
+    }
+}
@@ -0,0 +1,360 @@
+//!Dry-run of posting
+
+use super::{API, ApiError, Content};
+use super::twitter::{self, TwitterError};
+use super::gab::{self, GabError};
+use super::mastodon::{self, MastodonError};
+use super::minds::MindsError;
+use super::bluesky::{self, BlueskyError};
+use super::misskey;
+use super::wordpress::WordPressError;
+use super::matrix::MatrixError;
+use super::telegram::{self, TelegramError};
+use super::discord::{self, DiscordError};
+use super::slack::{self, SlackError};
+use super::linkedin::{self, LinkedInError};
+use super::facebook::FacebookError;
+use crate::data::tags::is_word_hash_tag;
+use crate::data::url::text_len;
+use crate::data::{Post, PostFlags};
+
+///Post, as it would be sent to single platform.
+pub struct PlatformPreview {
+    ///Final text of post
+    pub message: String,
+    ///Length of text, as it is counted by platform
+    pub len: usize,
+    ///Maximum length of text, if platform has limit
+    pub max_len: Option<usize>,
+    ///Hashtags, as they are sent in separate field (Minds and WordPress only)
+    pub tags: Vec<String>,
+    ///Images to attach
+    pub images: Vec<String>,
+    ///Flags
+    pub flags: PostFlags,
+    ///Error that would prevent posting, if any
+    pub error: Option<ApiError>,
+}
+
+impl PlatformPreview {
+    fn new(content: Content, flags: &PostFlags, len: usize, max_len: Option<usize>) -> Self {
+        Self {
+            error: content.invalid_tags.first().map(|tag| ApiError::InvalidHashTag(tag.clone())),
+            len,
+            max_len,
+            images: content.images.iter().map(|image| image.name.clone()).collect(),
+            message: content.message,
+            tags: content.tags,
+            flags: flags.clone(),
+        }
+    }
+}
+
+///Result of preview.
+///
+///Platforms that are not enabled are `None`
+pub struct Preview {
+    ///Twitter's preview
+    pub twitter: Option<PlatformPreview>,
+    ///Gab's preview
+    pub gab: Option<PlatformPreview>,
+    ///Mastodon's preview
+    pub mastodon: Option<PlatformPreview>,
+    ///Minds's preview
+    pub minds: Option<PlatformPreview>,
+    ///Bluesky's preview
+    pub bluesky: Option<PlatformPreview>,
+    ///Pleroma's preview
+    pub pleroma: Option<PlatformPreview>,
+    ///Misskey's preview
+    pub misskey: Option<PlatformPreview>,
+    ///WordPress's preview
+    pub wordpress: Option<PlatformPreview>,
+    ///Matrix's preview
+    pub matrix: Option<PlatformPreview>,
+    ///Telegram's preview
+    pub telegram: Option<PlatformPreview>,
+    ///Discord's preview
+    pub discord: Option<PlatformPreview>,
+    ///Slack's preview
+    pub slack: Option<PlatformPreview>,
+    ///LinkedIn's preview
+    pub linkedin: Option<PlatformPreview>,
+    ///Facebook's preview
+    pub facebook: Option<PlatformPreview>,
+}
+
+impl API {
+    ///Performs all local processing of Post for enabled APIs, without sending it.
+    ///
+    ///Fails on the same errors as `send` does before any request is made.
+    pub fn preview(&self, post: &Post) -> Result<Preview, ApiError> {
+        let flags = &post.flags;
+
+        let twitter = match self.twitter {
+            Some(ref twitter) => {
+                let content = Content::new(post, &post.twitter, self.tags.twitter, is_word_hash_tag, &self.people, |person| person.twitter.as_deref())?;
+                let len = text_len(&content.message, twitter::URL_LEN);
+                let mut preview = PlatformPreview::new(content, flags, len, Some(twitter::MAX_LEN));
+                preview.tags.clear();
+
+                if let Some(Err(error)) = post.poll.as_ref().map(|poll| twitter.check_poll(poll)) {
+                    preview.error = Some(error.into());
+                } else if len > twitter::MAX_LEN {
+                    preview.error = Some(TwitterError::MessageTooLong.into());
+                } else if let Some(Err(error)) = post.quote.as_ref().map(|quote| twitter.quote_id(quote)) {
+                    preview.error = Some(error.into());
+                }
+
+                Some(preview)
+            },
+            None => None,
+        };
+
+        let gab = match self.gab {
+            Some(_) => {
+                let content = Content::new(post, &post.gab, self.tags.gab, is_word_hash_tag, &self.people, |person| person.gab.as_deref())?;
+                let len = content.message.chars().count();
+                let mut preview = PlatformPreview::new(content, flags, len, Some(gab::MAX_LEN));
+                preview.tags.clear();
+
+                if post.poll.is_some() && preview.images.len() > 0 {
+                    preview.error = Some(GabError::PollWithImagesUnsupported.into());
+                }
+
+                Some(preview)
+            },
+            None => None,
+        };
+
+        let mastodon = match self.mastodon {
+            Some(_) => {
+                let content = Content::new(post, &post.mastodon, self.tags.mastodon, mastodon::is_valid_hash_tag, &self.people, |person| person.mastodon.as_deref())?;
+                let len = text_len(&content.message, mastodon::URL_LEN);
+                let mut preview = PlatformPreview::new(content, flags, len, Some(mastodon::MAX_LEN));
+                preview.tags.clear();
+
+                if post.poll.is_some() && preview.images.len() > 0 {
+                    preview.error = Some(MastodonError::PollWithImagesUnsupported.into());
+                }
+
+                Some(preview)
+            },
+            None => None,
+        };
+
+        let minds = match self.minds {
+            Some(_) => {
+                let mut content = Content::new(post, &post.minds, self.tags.minds, is_word_hash_tag, &self.people, |person| person.minds.as_deref())?;
+                //Minds allows only single attachment
+                content.images.truncate(1);
+                let len = content.message.chars().count();
+                let mut preview = PlatformPreview::new(content, flags, len, None);
+
+                if post.poll.is_some() {
+                    preview.error = Some(MindsError::PollUnsupported.into());
+                }
+
+                Some(preview)
+            },
+            None => None,
+        };
+
+        let bluesky = match self.bluesky {
+            Some(_) => {
+                let content = Content::new(post, &post.bluesky, self.tags.bluesky, bluesky::is_valid_hash_tag, &self.people, |person| person.bluesky.as_deref())?;
+                let images = content.images.len();
+                let len = content.message.chars().count();
+                let mut preview = PlatformPreview::new(content, flags, len, Some(bluesky::MAX_LEN));
+                preview.tags.clear();
+
+                if post.poll.is_some() {
+                    preview.error = Some(BlueskyError::PollUnsupported.into());
+                } else if images > bluesky::MAX_IMAGES {
+                    preview.error = Some(BlueskyError::TooManyImages.into());
+                } else if len > bluesky::MAX_LEN {
+                    preview.error = Some(BlueskyError::MessageTooLong.into());
+                }
+
+                Some(preview)
+            },
+            None => None,
+        };
+
+        //Unlike Mastodon, Pleroma counts links by their actual length
+        let pleroma = match self.pleroma {
+            Some(_) => {
+                let content = Content::new(post, &post.pleroma, self.tags.pleroma, mastodon::is_valid_hash_tag, &self.people, |person| person.pleroma.as_deref())?;
+                let len = content.message.chars().count();
+                let mut preview = PlatformPreview::new(content, flags, len, Some(mastodon::PLEROMA_MAX_LEN));
+                preview.tags.clear();
+
+                if post.poll.is_some() && preview.images.len() > 0 {
+                    preview.error = Some(ApiError::Pleroma(MastodonError::PollWithImagesUnsupported));
+                }
+
+                Some(preview)
+            },
+            None => None,
+        };
+
+        let misskey = match self.misskey {
+            Some(_) => {
+                let content = Content::new(post, &post.misskey, self.tags.misskey, misskey::is_valid_hash_tag, &self.people, |person| person.misskey.as_deref())?;
+                let len = content.message.chars().count();
+                let mut preview = PlatformPreview::new(content, flags, len, Some(misskey::MAX_LEN));
+                preview.tags.clear();
+                Some(preview)
+            },
+            None => None,
+        };
+
+        let wordpress = match self.wordpress {
+            Some(_) => {
+                let content = Content::new(post, &post.wordpress, self.tags.wordpress, |_| true, &self.people, |_| None)?;
+                let len = content.message.chars().count();
+                let mut preview = PlatformPreview::new(content, flags, len, None);
+
+                if post.poll.is_some() {
+                    preview.error = Some(WordPressError::PollUnsupported.into());
+                }
+
+                Some(preview)
+            },
+            None => None,
+        };
+
+        let matrix = match self.matrix {
+            Some(_) => {
+                let content = Content::new(post, &post.matrix, self.tags.matrix, |_| true, &self.people, |person| person.matrix.as_deref())?;
+                let len = content.message.chars().count();
+                let mut preview = PlatformPreview::new(content, flags, len, None);
+                preview.tags.clear();
+
+                if post.poll.is_some() {
+                    preview.error = Some(MatrixError::PollUnsupported.into());
+                }
+
+                Some(preview)
+            },
+            None => None,
+        };
+
+        let telegram = match self.telegram {
+            Some(_) => {
+                let content = Content::new(post, &post.telegram, self.tags.telegram, is_word_hash_tag, &self.people, |person| person.telegram.as_deref())?;
+                let images = content.images.len();
+                //With images, message becomes caption
+                let max_len = match content.images.len() {
+                    0 => telegram::MAX_LEN,
+                    _ => telegram::CAPTION_MAX_LEN,
+                };
+                let len = content.message.chars().count();
+                let mut preview = PlatformPreview::new(content, flags, len, Some(max_len));
+                preview.tags.clear();
+
+                if post.poll.is_some() {
+                    preview.error = Some(TelegramError::PollUnsupported.into());
+                } else if images > telegram::MAX_IMAGES {
+                    preview.error = Some(TelegramError::TooManyImages.into());
+                } else if len > max_len {
+                    preview.error = Some(TelegramError::MessageTooLong.into());
+                }
+
+                Some(preview)
+            },
+            None => None,
+        };
+
+        let discord = match self.discord {
+            Some(_) => {
+                let mut content = Content::new(post, &post.discord, self.tags.discord, |_| true, &self.people, |_| None)?;
+                content.images.truncate(discord::MAX_IMAGES);
+                let len = content.message.chars().count();
+                let mut preview = PlatformPreview::new(content, flags, len, Some(discord::MAX_LEN));
+                preview.tags.clear();
+
+                if post.poll.is_some() {
+                    preview.error = Some(DiscordError::PollUnsupported.into());
+                } else if len > discord::MAX_LEN {
+                    preview.error = Some(DiscordError::MessageTooLong.into());
+                }
+
+                Some(preview)
+            },
+            None => None,
+        };
+
+        //Only images given as URLs can be sent
+        let slack = match self.slack {
+            Some(_) => {
+                let content = Content::text(post, &post.slack, self.tags.slack, |_| true, &self.people, |_| None)?;
+                let len = slack::text_len(&content.message);
+                let mut preview = PlatformPreview::new(content, flags, len, Some(slack::MAX_LEN));
+                preview.tags.clear();
+
+                if post.poll.is_some() {
+                    preview.error = Some(SlackError::PollUnsupported.into());
+                } else if slack::image_urls(post.images_for(&post.slack)).is_none() {
+                    preview.error = Some(SlackError::LocalImageUnsupported.into());
+                } else if len > slack::MAX_LEN {
+                    preview.error = Some(SlackError::MessageTooLong.into());
+                }
+
+                Some(preview)
+            },
+            None => None,
+        };
+
+        let linkedin = match self.linkedin {
+            Some(_) => {
+                let content = Content::new(post, &post.linkedin, self.tags.linkedin, is_word_hash_tag, &self.people, |_| None)?;
+                let len = content.message.chars().count();
+                let mut preview = PlatformPreview::new(content, flags, len, Some(linkedin::MAX_LEN));
+                preview.tags.clear();
+
+                if post.poll.is_some() {
+                    preview.error = Some(LinkedInError::PollUnsupported.into());
+                } else if len > linkedin::MAX_LEN {
+                    preview.error = Some(LinkedInError::MessageTooLong.into());
+                }
+
+                Some(preview)
+            },
+            None => None,
+        };
+
+        let facebook = match self.facebook {
+            Some(_) => {
+                let content = Content::new(post, &post.facebook, self.tags.facebook, is_word_hash_tag, &self.people, |_| None)?;
+                let len = content.message.chars().count();
+                let mut preview = PlatformPreview::new(content, flags, len, None);
+                preview.tags.clear();
+
+                if post.poll.is_some() {
+                    preview.error = Some(FacebookError::PollUnsupported.into());
+                }
+
+                Some(preview)
+            },
+            None => None,
+        };
+
+        Ok(Preview {
+            twitter,
+            gab,
+            mastodon,
+            minds,
+            bluesky,
+            pleroma,
+            misskey,
+            wordpress,
+            matrix,
+            telegram,
+            discord,
+            slack,
+            linkedin,
+            facebook,
+        })
+    }
+}
@@ -0,0 +1,70 @@
+//! Slack API data types
+
+use serde_derive::Serialize;
+
+///Text of block.
+#[derive(Serialize, Debug)]
+pub struct Text<'a> {
+    #[serde(rename = "type")]
+    kind: &'static str,
+    text: &'a str,
+}
+
+///Layout block of message.
+#[derive(Serialize, Debug)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum Block<'a> {
+    ///Section with text
+    Section {
+        ///Text
+        text: Text<'a>,
+    },
+    ///Image
+    Image {
+        ///Public URL of image
+        image_url: &'a str,
+        ///Description of image
+        alt_text: &'a str,
+    },
+}
+
+impl<'a> Block<'a> {
+    ///Creates section with formatted text.
+    pub fn section(text: &'a str) -> Self {
+        Block::Section {
+            text: Text {
+                kind: "mrkdwn",
+                text,
+            }
+        }
+    }
+
+    ///Creates image out of its URL.
+    pub fn image(url: &'a str) -> Self {
+        Block::Image {
+            image_url: url,
+            alt_text: url,
+        }
+    }
+}
+
+///Message to send through incoming webhook.
+#[derive(Serialize, Debug)]
+pub struct NewMessage<'a> {
+    ///Text for notifications
+    text: &'a str,
+    blocks: Vec<Block<'a>>,
+}
+
+impl<'a> NewMessage<'a> {
+    ///Creates new instance with text and images.
+    pub fn new(text: &'a str, images: &[&'a str]) -> Self {
+        let mut blocks = vec![Block::section(text)];
+        blocks.extend(images.iter().map(|url| Block::image(url)));
+
+        Self {
+            text,
+            blocks,
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use std::error::Error;
+use std::fmt;
+
+#[repr(u8)]
+#[derive(Debug)]
+///Slack errors
+pub enum SlackError {
+    ///Webhook's URL is not valid HTTPS URI.
+    InvalidWebhook,
+    ///Failed to send request to perform post.
+    PostUploadSendError,
+    ///Server rejected posting.
+    PostUploadServerReject,
+    ///Platform cannot attach poll to post.
+    PollUnsupported,
+    ///Text exceeds platform's limit.
+    MessageTooLong,
+    ///Image is local file, which webhook cannot upload.
+    LocalImageUnsupported,
+}
+
+impl SlackError {
+    ///Returns short identifier of error's kind.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            &SlackError::InvalidWebhook => "invalid_config",
+            &SlackError::PostUploadSendError => "post_send",
+            &SlackError::PostUploadServerReject => "post_rejected",
+            &SlackError::PollUnsupported => "unsupported",
+            &SlackError::MessageTooLong => "message_too_long",
+            &SlackError::LocalImageUnsupported => "unsupported",
+        }
+    }
+}
+
+impl fmt::Display for SlackError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl Error for SlackError {
+    fn description(&self) -> &str {
+        match self {
+            &SlackError::InvalidWebhook => "Webhook is not valid HTTPS URI",
+            &SlackError::PostUploadSendError => "Failed to send request to perform post",
+            &SlackError::PostUploadServerReject => "Server rejected posting",
+            &SlackError::PollUnsupported => "Polls are not supported",
+            &SlackError::MessageTooLong => "Message exceeds 3000 characters",
+            &SlackError::LocalImageUnsupported => "Images must be given as URLs, as local files cannot be uploaded",
+        }
+    }
+}
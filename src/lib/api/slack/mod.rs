@@ -0,0 +1,106 @@
+//!Slack incoming webhook API
+
+use super::http::{Uri, GlobalRequest, Request, matsu};
+
+pub mod data;
+mod error;
+
+pub use error::SlackError;
+
+///Maximum number of characters in section of message.
+pub const MAX_LEN: usize = 3000;
+
+///Escapes characters that have special meaning in Slack's formatting.
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;")
+}
+
+///Returns length of message, as it is counted by Slack after escaping.
+pub fn text_len(message: &str) -> usize {
+    escape(message).chars().count()
+}
+
+///Returns images, if all of them are given as URLs, as local files cannot be sent.
+pub fn image_urls(images: &[String]) -> Option<Vec<&str>> {
+    images.iter().map(|image| match image.starts_with("https://") || image.starts_with("http://") {
+        true => Some(image.as_str()),
+        false => None,
+    }).collect()
+}
+
+///Slack API
+pub struct Slack {
+    config: crate::config::Slack,
+}
+
+impl Slack {
+    ///Verifies configuration and creates new instances.
+    pub fn new(config: crate::config::Slack) -> Result<Self, SlackError> {
+        match config.webhook.parse::<Uri>() {
+            Ok(ref uri) if uri.scheme_str() == Some("https") => (),
+            _ => {
+                return Err(SlackError::InvalidWebhook);
+            }
+        }
+
+        Ok(Self {
+            config
+        })
+    }
+
+    ///Prepares post request.
+    ///
+    ///Webhook cannot upload files, so images must be public URLs.
+    ///As Slack doesn't identify sent message, result has no URL.
+    pub async fn post(&self, message: &str, images: &[&str]) -> Result<crate::data::PublishedPost, SlackError> {
+        let message = escape(message);
+        if message.chars().count() > MAX_LEN {
+            return Err(SlackError::MessageTooLong);
+        }
+
+        let req = Request::post(&self.config.webhook).expect("To create request")
+                                                     .json(&data::NewMessage::new(&message, images))
+                                                     .expect("To serialzie post data")
+                                                     .global()
+                                                     .send();
+
+        let resp = match matsu!(req) {
+            Ok(resp) => resp,
+            Err(err) => match matsu!(matsu!(err)) {
+                Ok(resp) => resp,
+                Err(_) => return Err(SlackError::PostUploadSendError)
+            }
+        }.map_err(|_| SlackError::PostUploadSendError)?;
+
+        if !resp.is_success() {
+            return Err(SlackError::PostUploadServerReject)
+        }
+
+        Ok(crate::data::PublishedPost {
+            id: "".into(),
+            url: String::new(),
+            created_at: Some(crate::data::time::now()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_select_image_urls() {
+        let images = vec!["https://example.com/a.png".to_owned(), "http://example.com/b.png".to_owned()];
+        assert_eq!(image_urls(&images), Some(vec!["https://example.com/a.png", "http://example.com/b.png"]));
+
+        let images = vec!["https://example.com/a.png".to_owned(), "image.png".to_owned()];
+        assert_eq!(image_urls(&images), None);
+        assert_eq!(image_urls(&[]), Some(vec![]));
+    }
+
+    #[test]
+    fn should_count_escaped_text() {
+        assert_eq!(text_len("a < b"), 8);
+        assert_eq!(text_len("Tom & Jerry"), 15);
+    }
+}
@@ -0,0 +1,52 @@
+//!Engagement statistics of posts
+
+use super::{API, ApiError, post_result};
+use super::http::matsu;
+use crate::data::{PostIds, PostStats};
+
+///Statistics read from each platform.
+///
+///Platform is `None` when it is not enabled or post has no ID on it.
+pub struct Stats {
+    ///Twitter's statistics
+    pub twitter: Option<Result<PostStats, ApiError>>,
+    ///Gab's statistics
+    pub gab: Option<Result<PostStats, ApiError>>,
+    ///Mastodon's statistics
+    pub mastodon: Option<Result<PostStats, ApiError>>,
+    ///Minds's statistics
+    pub minds: Option<Result<PostStats, ApiError>>,
+    ///Bluesky's statistics
+    pub bluesky: Option<Result<PostStats, ApiError>>,
+    ///Pleroma's statistics
+    pub pleroma: Option<Result<PostStats, ApiError>>,
+    ///Misskey's statistics
+    pub misskey: Option<Result<PostStats, ApiError>>,
+}
+
+impl API {
+    ///Reads likes, reposts and replies of post from each enabled platform it was published on.
+    pub async fn stats(&self, ids: &PostIds) -> Stats {
+        let twitter = post_result(self.twitter.as_ref().and_then(|twitter| ids.twitter.as_ref().map(|id| twitter.stats(id))));
+        let gab = post_result(self.gab.as_ref().and_then(|gab| ids.gab.as_ref().map(|id| gab.stats(id))));
+        let mastodon = post_result(self.mastodon.as_ref().and_then(|mastodon| ids.mastodon.as_ref().map(|id| mastodon.stats(id))));
+        let minds = post_result(self.minds.as_ref().and_then(|minds| ids.minds.as_ref().map(|id| minds.stats(id))));
+        let bluesky = post_result(self.bluesky.as_ref().and_then(|bluesky| ids.bluesky.as_ref().map(|id| bluesky.stats(id))));
+        let pleroma = post_result(self.pleroma.as_ref().and_then(|pleroma| ids.pleroma.as_ref().map(|id| async move {
+            matsu!(pleroma.stats(id)).map_err(ApiError::Pleroma)
+        })));
+        let misskey = post_result(self.misskey.as_ref().and_then(|misskey| ids.misskey.as_ref().map(|id| misskey.stats(id))));
+
+        let (twitter, gab, mastodon, minds, bluesky, pleroma, misskey) = futures_util::join!(twitter, gab, mastodon, minds, bluesky, pleroma, misskey);
+
+        Stats {
+            twitter,
+            gab,
+            mastodon,
+            minds,
+            bluesky,
+            pleroma,
+            misskey,
+        }
+    }
+}
@@ -0,0 +1,111 @@
+//! Telegram Bot API data types
+
+use serde_derive::{Serialize, Deserialize};
+
+///Response of Bot API.
+#[derive(Deserialize, Debug)]
+pub struct Response<T> {
+    ///Result of method
+    pub result: T,
+}
+
+///Chat, in which message is sent.
+#[derive(Deserialize, Debug)]
+pub struct Chat {
+    ///Identifier
+    pub id: i64,
+    ///Public username of channel, if any.
+    #[serde(default)]
+    pub username: Option<String>,
+}
+
+///Sent message.
+#[derive(Deserialize, Debug)]
+pub struct Message {
+    ///Identifier within chat
+    pub message_id: u64,
+    ///UNIX timestamp of sending
+    pub date: u64,
+    ///Chat
+    pub chat: Chat,
+}
+
+impl Message {
+    ///Converts into published post.
+    pub fn into_published(self) -> crate::data::PublishedPost {
+        //Private channels have IDs prefixed with -100, which is not part of link
+        let url = match self.chat.username {
+            Some(username) => format!("https://t.me/{}/{}", username, self.message_id),
+            None => format!("https://t.me/c/{}/{}", self.chat.id.to_string().trim_start_matches("-100"), self.message_id),
+        };
+
+        crate::data::PublishedPost {
+            id: self.message_id.into(),
+            url,
+            created_at: Some(crate::data::time::from_timestamp(self.date)),
+        }
+    }
+}
+
+///Message or messages of media group, sent with images.
+#[derive(Deserialize, Debug)]
+#[serde(untagged)]
+pub enum Sent {
+    ///Single photo
+    Single(Message),
+    ///Media group
+    Group(Vec<Message>),
+}
+
+impl Sent {
+    ///Returns first sent message.
+    pub fn first(self) -> Option<Message> {
+        match self {
+            Sent::Single(message) => Some(message),
+            Sent::Group(messages) => messages.into_iter().next(),
+        }
+    }
+}
+
+///Text message to send.
+#[derive(Serialize, Debug)]
+pub struct SendMessage<'a> {
+    chat_id: &'a str,
+    text: &'a str,
+    disable_web_page_preview: bool,
+}
+
+impl<'a> SendMessage<'a> {
+    ///Creates new instance
+    pub fn new(chat_id: &'a str, text: &'a str, no_preview: bool) -> Self {
+        Self {
+            chat_id,
+            text,
+            disable_web_page_preview: no_preview,
+        }
+    }
+}
+
+///Photo within media group.
+#[derive(Serialize, Debug)]
+pub struct InputMediaPhoto<'a> {
+    #[serde(rename = "type")]
+    kind: &'static str,
+    ///Reference to multipart's field in form `attach://<name>`
+    media: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    caption: Option<&'a str>,
+    has_spoiler: bool,
+}
+
+impl<'a> InputMediaPhoto<'a> {
+    ///Creates new instance, referring to multipart's `field`
+    pub fn new(field: &str, caption: Option<&'a str>, nsfw: bool) -> Self {
+        Self {
+            kind: "photo",
+            media: format!("attach://{}", field),
+            caption,
+            has_spoiler: nsfw,
+        }
+    }
+}
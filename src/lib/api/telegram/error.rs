@@ -0,0 +1,73 @@
+use std::error::Error;
+use std::fmt;
+
+#[repr(u8)]
+#[derive(Debug)]
+///Telegram errors
+pub enum TelegramError {
+    ///Bot's token or chat is empty.
+    InvalidToken,
+    ///Failed to send request to upload images.
+    ImageUploadSendError,
+    ///Server rejected images upload.
+    ImageUploadServerReject,
+    ///Server responded with invalid data.
+    ///
+    ///Should contain `result`
+    ImageUploadInvalidResponse,
+    ///Failed to send request to perform text post.
+    PostUploadSendError,
+    ///Server rejected posting.
+    PostUploadServerReject,
+    ///Server responded with invalid data
+    ///
+    ///Should contain `result`
+    PostUploadInvalidResponse,
+    ///Platform cannot attach poll to post.
+    PollUnsupported,
+    ///Text exceeds platform's limit.
+    MessageTooLong,
+    ///More images than media group can contain.
+    TooManyImages,
+}
+
+impl TelegramError {
+    ///Returns short identifier of error's kind.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            &TelegramError::InvalidToken => "invalid_config",
+            &TelegramError::ImageUploadSendError => "image_upload_send",
+            &TelegramError::ImageUploadServerReject => "image_upload_rejected",
+            &TelegramError::ImageUploadInvalidResponse => "image_upload_invalid_response",
+            &TelegramError::PostUploadSendError => "post_send",
+            &TelegramError::PostUploadServerReject => "post_rejected",
+            &TelegramError::PostUploadInvalidResponse => "post_invalid_response",
+            &TelegramError::PollUnsupported => "unsupported",
+            &TelegramError::MessageTooLong => "message_too_long",
+            &TelegramError::TooManyImages => "too_many_images",
+        }
+    }
+}
+
+impl fmt::Display for TelegramError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl Error for TelegramError {
+    fn description(&self) -> &str {
+        match self {
+            &TelegramError::InvalidToken => "Bot token or chat is not valid(empty)",
+            &TelegramError::ImageUploadSendError => "Failed to send request to upload images",
+            &TelegramError::ImageUploadServerReject => "Server rejected upload of images",
+            &TelegramError::ImageUploadInvalidResponse => "Server sent invalid response. Doesn't contain field result",
+            &TelegramError::PostUploadSendError => "Failed to send request to perform text post",
+            &TelegramError::PostUploadServerReject => "Server rejected posting",
+            &TelegramError::PostUploadInvalidResponse => "Server sent invalid response. Doesn't contain field result",
+            &TelegramError::PollUnsupported => "Polls are not supported",
+            &TelegramError::MessageTooLong => "Message exceeds 4096 characters, or 1024 characters with images",
+            &TelegramError::TooManyImages => "Post has more than 10 images",
+        }
+    }
+}
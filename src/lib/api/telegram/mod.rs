@@ -0,0 +1,136 @@
+//!Telegram Bot API
+
+use super::http::{multipart, GlobalRequest, Request, matsu};
+use crate::data::{Image, PostFlags};
+use yukikaze::serde_json;
+
+pub mod data;
+mod error;
+
+pub use error::TelegramError;
+
+///Maximum number of characters in message.
+pub const MAX_LEN: usize = 4096;
+///Maximum number of characters in caption of images.
+pub const CAPTION_MAX_LEN: usize = 1024;
+///Maximum number of images in media group.
+pub const MAX_IMAGES: usize = 10;
+
+///Telegram API
+pub struct Telegram {
+    config: crate::config::Telegram,
+}
+
+impl Telegram {
+    ///Verifies configuration and creates new instances.
+    pub fn new(config: crate::config::Telegram) -> Result<Self, TelegramError> {
+        if config.bot_token.len() == 0 || config.chat.len() == 0 {
+            return Err(TelegramError::InvalidToken);
+        }
+
+        Ok(Self {
+            config
+        })
+    }
+
+    fn url(&self, method: &str) -> String {
+        format!("https://api.telegram.org/bot{}/{}", self.config.bot_token, method)
+    }
+
+    ///Prepares text post request.
+    pub async fn post(&self, message: &str, flags: &PostFlags) -> Result<crate::data::PublishedPost, TelegramError> {
+        if message.chars().count() > MAX_LEN {
+            return Err(TelegramError::MessageTooLong);
+        }
+
+        let req = Request::post(self.url("sendMessage")).expect("To create request")
+                                                        .json(&data::SendMessage::new(&self.config.chat, message, flags.no_preview))
+                                                        .expect("To serialzie post data")
+                                                        .global()
+                                                        .send();
+
+        let mut resp = match matsu!(req) {
+            Ok(resp) => resp,
+            Err(err) => match matsu!(matsu!(err)) {
+                Ok(resp) => resp,
+                Err(_) => return Err(TelegramError::PostUploadSendError)
+            }
+        }.map_err(|_| TelegramError::PostUploadSendError)?;
+
+        if !resp.is_success() {
+            return Err(TelegramError::PostUploadServerReject)
+        }
+
+        match matsu!(resp.json::<data::Response<data::Message>>()) {
+            Ok(data) => Ok(data.result.into_published()),
+            Err(_) => Err(TelegramError::PostUploadInvalidResponse),
+        }
+    }
+
+    ///Prepares post request with images, using message as caption.
+    ///
+    ///Single image is sent as photo, while multiple are sent as media group.
+    ///Result is the first message of media group.
+    pub async fn post_images(&self, message: &str, images: &[Image], flags: &PostFlags) -> Result<crate::data::PublishedPost, TelegramError> {
+        if images.len() > MAX_IMAGES {
+            return Err(TelegramError::TooManyImages);
+        } else if message.chars().count() > CAPTION_MAX_LEN {
+            return Err(TelegramError::MessageTooLong);
+        }
+
+        let mut form = multipart::Form::new();
+        form.add_field("chat_id".to_string(), self.config.chat.as_bytes());
+
+        //Media group requires at least 2 items
+        let method = match images {
+            [image] => {
+                form.add_field("caption".to_string(), message.as_bytes());
+                form.add_field("has_spoiler".to_string(), flags.nsfw.to_string().as_bytes());
+                form.add_file_field("photo".to_string(), image.name.clone(), &image.mime, &image.mmap[..]);
+                "sendPhoto"
+            },
+            images => {
+                let mut media = vec![];
+
+                for (idx, image) in images.iter().enumerate() {
+                    let field = format!("image{}", idx);
+                    let caption = match idx {
+                        0 => Some(message),
+                        _ => None,
+                    };
+
+                    media.push(data::InputMediaPhoto::new(&field, caption, flags.nsfw));
+                    form.add_file_field(field, image.name.clone(), &image.mime, &image.mmap[..]);
+                }
+
+                let media = serde_json::to_string(&media).expect("To serialize media");
+                form.add_field("media".to_string(), media.as_bytes());
+                "sendMediaGroup"
+            },
+        };
+
+        let req = Request::post(self.url(method)).expect("To create request").multipart(form).global().send();
+
+        // For image we wait twice of time
+        // just to be sure
+        let mut resp = match matsu!(req) {
+            Ok(resp) => resp,
+            Err(err) => match matsu!(matsu!(err)) {
+                Ok(resp) => resp,
+                Err(_) => return Err(TelegramError::ImageUploadSendError)
+            }
+        }.map_err(|_| TelegramError::ImageUploadSendError)?;
+
+        if !resp.is_success() {
+            return Err(TelegramError::ImageUploadServerReject)
+        }
+
+        match matsu!(resp.json::<data::Response<data::Sent>>()) {
+            Ok(data) => match data.result.first() {
+                Some(message) => Ok(message.into_published()),
+                None => Err(TelegramError::ImageUploadInvalidResponse),
+            },
+            Err(_) => Err(TelegramError::ImageUploadInvalidResponse),
+        }
+    }
+}
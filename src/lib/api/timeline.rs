@@ -0,0 +1,100 @@
+//!Reading of timelines
+
+use super::{API, ApiError, post_result};
+use super::http::matsu;
+use crate::data::Status;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///Kind of timeline to read.
+pub enum Timeline {
+    ///Posts of followed accounts.
+    Home,
+    ///Own posts.
+    Own,
+    ///Posts that mention user.
+    Mentions,
+}
+
+///Statuses read from each platform.
+///
+///Platform is `None` when it is not enabled or cannot be read.
+pub struct Statuses {
+    ///Twitter's statuses
+    pub twitter: Option<Result<Vec<Status>, ApiError>>,
+    ///Gab's statuses
+    pub gab: Option<Result<Vec<Status>, ApiError>>,
+    ///Mastodon's statuses
+    pub mastodon: Option<Result<Vec<Status>, ApiError>>,
+    ///Bluesky's statuses
+    pub bluesky: Option<Result<Vec<Status>, ApiError>>,
+    ///Pleroma's statuses
+    pub pleroma: Option<Result<Vec<Status>, ApiError>>,
+    ///Misskey's statuses
+    pub misskey: Option<Result<Vec<Status>, ApiError>>,
+}
+
+impl API {
+    ///Reads up to `limit` newest statuses of `timeline` from every enabled platform that supports it.
+    ///
+    ///Minds, whose API has no documented way to read feeds, and publishing only platforms are not read.
+    pub async fn timeline(&self, timeline: Timeline, limit: usize) -> Statuses {
+        let twitter = post_result(self.twitter.as_ref().map(|twitter| async move {
+            match timeline {
+                Timeline::Home => matsu!(twitter.home_timeline(limit)),
+                Timeline::Own => matsu!(twitter.own_posts(limit)),
+                Timeline::Mentions => matsu!(twitter.mentions(limit)),
+            }
+        }));
+
+        let gab = post_result(self.gab.as_ref().map(|gab| async move {
+            match timeline {
+                Timeline::Home => matsu!(gab.home_timeline(limit)),
+                Timeline::Own => matsu!(gab.own_posts(limit)),
+                Timeline::Mentions => matsu!(gab.mentions(limit)),
+            }
+        }));
+
+        let mastodon = post_result(self.mastodon.as_ref().map(|mastodon| async move {
+            match timeline {
+                Timeline::Home => matsu!(mastodon.home_timeline(limit)),
+                Timeline::Own => matsu!(mastodon.own_posts(limit)),
+                Timeline::Mentions => matsu!(mastodon.mentions(limit)),
+            }
+        }));
+
+        let bluesky = post_result(self.bluesky.as_ref().map(|bluesky| async move {
+            match timeline {
+                Timeline::Home => matsu!(bluesky.home_timeline(limit)),
+                Timeline::Own => matsu!(bluesky.own_posts(limit)),
+                Timeline::Mentions => matsu!(bluesky.mentions(limit)),
+            }
+        }));
+
+        let pleroma = post_result(self.pleroma.as_ref().map(|pleroma| async move {
+            match timeline {
+                Timeline::Home => matsu!(pleroma.home_timeline(limit)),
+                Timeline::Own => matsu!(pleroma.own_posts(limit)),
+                Timeline::Mentions => matsu!(pleroma.mentions(limit)),
+            }.map_err(ApiError::Pleroma)
+        }));
+
+        let misskey = post_result(self.misskey.as_ref().map(|misskey| async move {
+            match timeline {
+                Timeline::Home => matsu!(misskey.home_timeline(limit)),
+                Timeline::Own => matsu!(misskey.own_posts(limit)),
+                Timeline::Mentions => matsu!(misskey.mentions(limit)),
+            }
+        }));
+
+        let (twitter, gab, mastodon, bluesky, pleroma, misskey) = futures_util::join!(twitter, gab, mastodon, bluesky, pleroma, misskey);
+
+        Statuses {
+            twitter,
+            gab,
+            mastodon,
+            bluesky,
+            pleroma,
+            misskey,
+        }
+    }
+}
@@ -220,8 +220,324 @@ impl<'a> Tweet<'a> {
 }
 
 #[derive(Deserialize, Debug)]
-///Response to successful tweet creation.
+///Author of tweet.
+pub struct TweetUser {
+    ///Name of user, as it is used in URLs
+    pub screen_name: String,
+}
+
+#[derive(Deserialize, Debug)]
+///Response to successful tweet creation, also used as tweet of timeline.
 pub struct TweetResponse {
     ///ID
     pub id: u64,
+    ///Time of creation, like `Wed Oct 10 20:19:24 +0000 2018`
+    #[serde(default)]
+    pub created_at: Option<String>,
+    ///Author
+    pub user: TweetUser,
+    ///Text, when requested in extended mode
+    #[serde(default)]
+    pub full_text: Option<String>,
+    ///Text, possibly truncated
+    #[serde(default)]
+    pub text: Option<String>,
+    ///Number of likes
+    #[serde(default)]
+    pub favorite_count: u64,
+    ///Number of retweets
+    #[serde(default)]
+    pub retweet_count: u64,
+}
+
+impl TweetResponse {
+    ///Returns engagement statistics.
+    ///
+    ///API v1.1 doesn't report number of replies.
+    pub fn stats(&self) -> crate::data::PostStats {
+        crate::data::PostStats {
+            likes: self.favorite_count,
+            reposts: self.retweet_count,
+            replies: None,
+        }
+    }
+
+    ///Converts into common status
+    pub fn into_status(self) -> crate::data::Status {
+        let text = self.full_text.or(self.text).unwrap_or_default();
+
+        crate::data::Status {
+            url: format!("https://twitter.com/{}/status/{}", self.user.screen_name, self.id),
+            id: self.id.into(),
+            author: self.user.screen_name,
+            //Text escapes HTML's special characters
+            text: crate::data::html::to_text(&text),
+            timestamp: self.created_at.as_ref().and_then(|created_at| crate::data::time::parse(created_at)),
+        }
+    }
+}
+
+#[derive(Deserialize, Debug)]
+///Uploaded media of API v2
+pub struct MediaV2 {
+    ///ID
+    pub id: String,
+}
+
+#[derive(Deserialize, Debug)]
+///Response of API v2 with uploaded media
+pub struct MediaResponseV2 {
+    ///Uploaded media
+    pub data: MediaV2,
+}
+
+#[derive(Serialize, Debug)]
+///Attachments of tweet in API v2
+pub struct TweetMedia {
+    ///IDs of uploaded media
+    pub media_ids: Vec<String>,
+}
+
+#[derive(Serialize, Debug)]
+///Poll of tweet in API v2
+pub struct TweetPoll<'a> {
+    ///Choices to vote for
+    pub options: &'a [String],
+    ///Number of minutes during which poll accepts votes
+    pub duration_minutes: u64,
+}
+
+#[derive(Serialize, Debug)]
+///Tweet's representation in API v2
+pub struct TweetV2<'a> {
+    ///Text of tweet
+    pub text: &'a str,
+    ///Attachments
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub media: Option<TweetMedia>,
+    ///ID of quoted tweet
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quote_tweet_id: Option<&'a str>,
+    ///Poll
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub poll: Option<TweetPoll<'a>>,
+    ///Who can reply, everyone when not set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_settings: Option<&'static str>,
+}
+
+impl<'a> TweetV2<'a> {
+    ///Creates new instance
+    pub fn new(text: &'a str) -> Self {
+        Self {
+            text,
+            media: None,
+            quote_tweet_id: None,
+            poll: None,
+            reply_settings: None,
+        }
+    }
+
+    ///Adds attachments
+    pub fn media_ids(mut self, ids: &[u64]) -> Self {
+        if !ids.is_empty() {
+            self.media = Some(TweetMedia {
+                media_ids: ids.iter().map(|id| id.to_string()).collect(),
+            });
+        }
+        self
+    }
+
+    ///Sets quoted tweet
+    pub fn quote(mut self, id: Option<&'a str>) -> Self {
+        self.quote_tweet_id = id;
+        self
+    }
+
+    ///Sets poll, rounding its duration up to minutes.
+    pub fn poll(mut self, poll: Option<&'a crate::data::Poll>) -> Self {
+        self.poll = poll.map(|poll| TweetPoll {
+            options: &poll.options,
+            duration_minutes: (poll.duration + 59) / 60,
+        });
+        self
+    }
+
+    ///Limits replies according to visibility of post.
+    pub fn visibility(mut self, visibility: crate::data::Visibility) -> Self {
+        use crate::data::Visibility;
+
+        self.reply_settings = match visibility {
+            Visibility::Public | Visibility::Unlisted => None,
+            Visibility::Private => Some("following"),
+            Visibility::Direct => Some("mentionedUsers"),
+        };
+        self
+    }
+}
+
+#[derive(Deserialize, Debug)]
+///Created tweet in API v2
+pub struct CreatedTweet {
+    ///ID
+    pub id: String,
+}
+
+#[derive(Deserialize, Debug)]
+///Response of API v2 to successful tweet creation.
+pub struct TweetResponseV2 {
+    ///Created tweet
+    pub data: CreatedTweet,
+}
+
+#[derive(Serialize, Debug)]
+///Tweet to retweet or like in API v2
+pub struct TargetTweet<'a> {
+    tweet_id: &'a str,
+}
+
+impl<'a> TargetTweet<'a> {
+    ///Creates new instance
+    pub fn new(tweet_id: &'a str) -> Self {
+        Self {
+            tweet_id,
+        }
+    }
+}
+
+#[derive(Deserialize, Debug)]
+///Public engagement metrics of tweet in API v2
+pub struct PublicMetrics {
+    ///Number of likes
+    #[serde(default)]
+    pub like_count: u64,
+    ///Number of retweets
+    #[serde(default)]
+    pub retweet_count: u64,
+    ///Number of quotes
+    #[serde(default)]
+    pub quote_count: u64,
+    ///Number of replies
+    #[serde(default)]
+    pub reply_count: u64,
+}
+
+#[derive(Deserialize, Debug)]
+///Tweet with its metrics in API v2
+pub struct MetricsTweet {
+    ///Metrics
+    pub public_metrics: PublicMetrics,
+}
+
+#[derive(Deserialize, Debug)]
+///Response of API v2 with tweet's metrics
+pub struct MetricsResponseV2 {
+    ///Tweet
+    pub data: MetricsTweet,
+}
+
+impl MetricsResponseV2 {
+    ///Returns engagement statistics, counting quotes as reposts.
+    pub fn stats(&self) -> crate::data::PostStats {
+        let metrics = &self.data.public_metrics;
+
+        crate::data::PostStats {
+            likes: metrics.like_count,
+            reposts: metrics.retweet_count + metrics.quote_count,
+            replies: Some(metrics.reply_count),
+        }
+    }
+}
+
+#[derive(Deserialize, Debug)]
+///User of API v2
+pub struct UserV2 {
+    ///ID
+    pub id: String,
+    ///Name of user, as it is used in URLs
+    pub username: String,
+}
+
+#[derive(Deserialize, Debug)]
+///Response of API v2 with user
+pub struct UserResponseV2 {
+    ///User
+    pub data: UserV2,
+}
+
+#[derive(Deserialize, Debug)]
+///Tweet of timeline in API v2
+pub struct TimelineTweet {
+    ///ID
+    pub id: String,
+    ///Text
+    #[serde(default)]
+    pub text: String,
+    ///Time of creation in ISO 8601 format
+    #[serde(default)]
+    pub created_at: Option<String>,
+    ///ID of author
+    #[serde(default)]
+    pub author_id: Option<String>,
+}
+
+#[derive(Deserialize, Debug, Default)]
+///Entities, referenced by timeline in API v2
+pub struct TimelineIncludes {
+    ///Authors
+    #[serde(default)]
+    pub users: Vec<UserV2>,
+}
+
+#[derive(Deserialize, Debug)]
+///Response of API v2 with timeline
+pub struct TimelineResponseV2 {
+    ///Tweets, absent when timeline is empty
+    #[serde(default)]
+    pub data: Vec<TimelineTweet>,
+    ///Authors of tweets
+    #[serde(default)]
+    pub includes: TimelineIncludes,
+}
+
+impl TimelineResponseV2 {
+    ///Converts into common statuses, looking up authors within included users.
+    pub fn into_statuses(self) -> Vec<crate::data::Status> {
+        let users = self.includes.users;
+
+        self.data.into_iter().map(|tweet| {
+            let author = tweet.author_id.as_ref().and_then(|id| users.iter().find(|user| user.id == *id)).map(|user| user.username.clone()).unwrap_or_default();
+            let url = match author.len() {
+                0 => format!("https://twitter.com/i/web/status/{}", tweet.id),
+                _ => format!("https://twitter.com/{}/status/{}", author, tweet.id),
+            };
+
+            crate::data::Status {
+                url,
+                id: tweet.id.into(),
+                author,
+                text: crate::data::html::to_text(&tweet.text),
+                timestamp: tweet.created_at.as_ref().and_then(|created_at| crate::data::time::parse(created_at)),
+            }
+        }).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::Poll;
+
+    #[test]
+    fn should_serialize_poll_v2() {
+        let poll = Poll {
+            options: vec!["Yes".to_owned(), "No".to_owned()],
+            duration: 3601,
+            multiple: false,
+        };
+        let tweet = TweetV2::new("Question?").poll(Some(&poll));
+
+        let json = yukikaze::serde_json::to_string(&tweet).expect("To serialize tweet");
+        assert_eq!(json, r#"{"text":"Question?","poll":{"options":["Yes","No"],"duration_minutes":61}}"#);
+    }
 }
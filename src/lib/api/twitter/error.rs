@@ -27,7 +27,65 @@ pub enum TwitterError {
     ///
     ///Should contain `id`
     PostUploadInvalidResponse,
+    ///Platform cannot attach poll to post.
+    PollUnsupported,
+    ///Text of tweet exceeds limit.
+    ///
+    ///Each link is counted as 23 characters.
+    MessageTooLong,
+    ///Configured API version is neither 1 nor 2.
+    InvalidApiVersion,
+    ///Quote tweets require API v2.
+    QuoteUnsupported,
+    ///Quoted tweet is neither ID nor URL of status.
+    InvalidQuote,
+    ///Failed to send request to read timeline.
+    ReadSendError,
+    ///Server rejected reading of timeline.
+    ReadServerReject,
+    ///Server responded with invalid data to reading of timeline.
+    ReadInvalidResponse,
+    ///Failed to send request to read statistics of post.
+    StatsSendError,
+    ///Server rejected reading of post's statistics.
+    StatsServerReject,
+    ///Server responded with invalid statistics of post.
+    StatsInvalidResponse,
+    ///URL is not link to status.
+    InvalidStatusUrl,
+    ///Failed to send request to boost or like status.
+    InteractionSendError,
+    ///Server rejected boost or like of status.
+    InteractionServerReject,
+}
 
+impl TwitterError {
+    ///Returns short identifier of error's kind.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            &TwitterError::InvalidAuthData => "invalid_config",
+            &TwitterError::ImageUploadSendError => "image_upload_send",
+            &TwitterError::ImageUploadServerReject => "image_upload_rejected",
+            &TwitterError::ImageUploadInvalidResponse => "image_upload_invalid_response",
+            &TwitterError::PostUploadSendError => "post_send",
+            &TwitterError::PostUploadServerReject => "post_rejected",
+            &TwitterError::PostUploadInvalidResponse => "post_invalid_response",
+            &TwitterError::PollUnsupported => "unsupported",
+            &TwitterError::MessageTooLong => "message_too_long",
+            &TwitterError::InvalidApiVersion => "invalid_config",
+            &TwitterError::QuoteUnsupported => "unsupported",
+            &TwitterError::InvalidQuote => "invalid_quote",
+            &TwitterError::ReadSendError => "read_send",
+            &TwitterError::ReadServerReject => "read_rejected",
+            &TwitterError::ReadInvalidResponse => "read_invalid_response",
+            &TwitterError::StatsSendError => "stats_send",
+            &TwitterError::StatsServerReject => "stats_rejected",
+            &TwitterError::StatsInvalidResponse => "stats_invalid_response",
+            &TwitterError::InvalidStatusUrl => "invalid_url",
+            &TwitterError::InteractionSendError => "interaction_send",
+            &TwitterError::InteractionServerReject => "interaction_rejected",
+        }
+    }
 }
 
 impl fmt::Display for TwitterError {
@@ -46,6 +104,20 @@ impl Error for TwitterError {
             &TwitterError::PostUploadSendError => "Failed to send request to perform text post",
             &TwitterError::PostUploadServerReject => "Server rejected posting",
             &TwitterError::PostUploadInvalidResponse => "Server sent invalid response. Doesn't contain field id",
+            &TwitterError::PollUnsupported => "Polls are not supported",
+            &TwitterError::MessageTooLong => "Tweet exceeds 280 characters",
+            &TwitterError::InvalidApiVersion => "API version must be 1 or 2",
+            &TwitterError::QuoteUnsupported => "Quote tweets require api_version = 2",
+            &TwitterError::InvalidQuote => "Quoted tweet must be ID or URL of status",
+            &TwitterError::ReadSendError => "Failed to send request to read timeline",
+            &TwitterError::ReadServerReject => "Server rejected reading of timeline",
+            &TwitterError::ReadInvalidResponse => "Server sent invalid timeline",
+            &TwitterError::StatsSendError => "Failed to send request to read statistics of post",
+            &TwitterError::StatsServerReject => "Server rejected reading of post's statistics",
+            &TwitterError::StatsInvalidResponse => "Server sent invalid statistics of post",
+            &TwitterError::InvalidStatusUrl => "URL is not link to status",
+            &TwitterError::InteractionSendError => "Failed to send request to boost or like status",
+            &TwitterError::InteractionServerReject => "Server rejected boost or like of status",
         }
     }
 }
@@ -6,40 +6,112 @@ mod error;
 use std::collections::HashMap;
 
 use crate::config;
-use super::http::{self, GlobalRequest, Mime, Request, matsu};
+use super::http::{self, multipart, GlobalRequest, Mime, Request, matsu};
 
-use crate::data::PostFlags;
+use crate::data::{PostFlags, Poll};
+use crate::data::url::text_len;
 pub use error::TwitterError;
 
 const IMAGES_URL: &'static str = "https://upload.twitter.com/1.1/media/upload.json";
 const POST_URL: &'static str = "https://api.twitter.com/1.1/statuses/update.json";
+const IMAGES_V2_URL: &'static str = "https://api.twitter.com/2/media/upload";
+const POST_V2_URL: &'static str = "https://api.twitter.com/2/tweets";
+const V1_URL: &'static str = "https://api.twitter.com/1.1";
+const V2_URL: &'static str = "https://api.twitter.com/2";
+
+///Maximum number of characters in tweet.
+pub const MAX_LEN: usize = 280;
+///Number of characters each link takes in tweet, regardless of its actual length.
+pub const URL_LEN: usize = 23;
+
+///Extracts ID of tweet out of status's URL (e.g. `https://twitter.com/user/status/123`) or returns it as it is.
+pub fn status_id(url: &str) -> Option<&str> {
+    let is_id = |id: &str| id.len() > 0 && id.bytes().all(|byte| byte.is_ascii_digit());
+
+    if is_id(url) {
+        return Some(url);
+    }
+
+    let path = url.split(|ch| ch == '?' || ch == '#').next().unwrap_or(url);
+    let mut parts = path.trim_end_matches('/').rsplit('/');
+    let id = parts.next()?;
+    match parts.next() {
+        Some("status") | Some("statuses") if is_id(id) => Some(id),
+        _ => None,
+    }
+}
+
+///Authorization of requests
+enum Auth {
+    ///OAuth 1.0a with user's access tokens
+    Oauth1(data::Oauth),
+    ///OAuth 2.0 user's access token, only usable with API v2
+    Oauth2(String),
+}
 
 ///Twitter API
 pub struct Twitter {
-    oauth: data::Oauth,
+    auth: Auth,
+    api_version: u8,
 }
 
 impl Twitter {
     ///Verifies and creates twitter API instance
+    ///
+    ///API v2 uses OAuth 2.0 token only if access tokens are not specified.
     pub fn new(config: config::Twitter) -> Result<Self, TwitterError> {
-        if config.consumer.key.len() == 0 || config.consumer.secret.len() == 0 || config.access.key.len() == 0 || config.access.secret.len() == 0 {
-            Err(TwitterError::InvalidAuthData)
-        } else {
-            let oauth = data::Oauth::new(config);
-            Ok(Self { oauth })
+        let api_version = config.api_version.unwrap_or(1);
+        let is_oauth1 = config.consumer.key.len() > 0 && config.consumer.secret.len() > 0 && config.access.key.len() > 0 && config.access.secret.len() > 0;
+
+        let auth = match api_version {
+            1 | 2 if is_oauth1 => Auth::Oauth1(data::Oauth::new(config)),
+            2 if config.oauth2.access_token.len() > 0 => Auth::Oauth2(config.oauth2.access_token),
+            1 | 2 => return Err(TwitterError::InvalidAuthData),
+            _ => return Err(TwitterError::InvalidApiVersion),
+        };
+
+        Ok(Self {
+            auth,
+            api_version,
+        })
+    }
+
+    ///Returns ID of tweet to quote, failing if it cannot be quoted.
+    pub fn quote_id<'a>(&self, quote: &'a str) -> Result<&'a str, TwitterError> {
+        if self.api_version < 2 {
+            return Err(TwitterError::QuoteUnsupported);
+        }
+
+        status_id(quote).ok_or(TwitterError::InvalidQuote)
+    }
+
+    ///Checks that poll can be attached, which requires API v2.
+    ///
+    ///Twitter's polls allow to choose only single option.
+    pub fn check_poll(&self, poll: &Poll) -> Result<(), TwitterError> {
+        match self.api_version < 2 || poll.multiple {
+            true => Err(TwitterError::PollUnsupported),
+            false => Ok(()),
         }
     }
 
     ///Prepares image upload request.
     ///
     ///Result contains `id` from `MediaResponse`
-    pub async fn upload_image(&self, _name: &str, _mime: &Mime, data: &[u8]) -> Result<u64, TwitterError> {
+    pub async fn upload_image(&self, name: &str, mime: &Mime, data: &[u8]) -> Result<u64, TwitterError> {
+        match self.auth {
+            Auth::Oauth1(ref oauth) => matsu!(Self::upload_image_v1(oauth, data)),
+            Auth::Oauth2(ref token) => matsu!(Self::upload_image_v2(token, name, mime, data)),
+        }
+    }
+
+    async fn upload_image_v1(oauth: &data::Oauth, data: &[u8]) -> Result<u64, TwitterError> {
         let media = data::Media::from_bytes(data);
 
         let auth_header = {
             let mut auth_params = HashMap::new();
             auth_params.insert("media_data", media.media_data.as_str());
-            self.oauth.gen_auth(&http::Method::POST, IMAGES_URL, auth_params)
+            oauth.gen_auth(&http::Method::POST, IMAGES_URL, auth_params)
         };
 
         let req = Request::post(IMAGES_URL).expect("To create request")
@@ -68,8 +140,61 @@ impl Twitter {
         }
     }
 
+    async fn upload_image_v2(token: &str, name: &str, mime: &Mime, data: &[u8]) -> Result<u64, TwitterError> {
+        let mut form = multipart::Form::new();
+        form.add_field("media_category".to_string(), b"tweet_image");
+        form.add_file_field("media".to_string(), name.to_string(), mime, data);
+
+        let req = Request::post(IMAGES_V2_URL).expect("To create request")
+                                              .bearer_auth(token)
+                                              .multipart(form)
+                                              .global()
+                                              .send();
+
+        // For image we wait twice of time
+        // just to be sure
+        let mut resp = match matsu!(req) {
+            Ok(resp) => resp,
+            Err(err) => match matsu!(matsu!(err)) {
+                Ok(resp) => resp,
+                Err(_) => return Err(TwitterError::ImageUploadSendError)
+            }
+        }.map_err(|_| TwitterError::ImageUploadSendError)?;
+
+        if !resp.is_success() {
+            return Err(TwitterError::ImageUploadServerReject)
+        }
+
+        match matsu!(resp.json::<data::MediaResponseV2>()) {
+            Ok(data) => data.data.id.parse().map_err(|_| TwitterError::ImageUploadInvalidResponse),
+            Err(_) => Err(TwitterError::ImageUploadInvalidResponse),
+        }
+    }
+
     ///Prepares post upload request.
-    pub async fn post(&self, message: &str, media_attachments: &[u64], flags: &PostFlags) -> Result<crate::data::PostId, TwitterError> {
+    ///
+    ///Tweet can quote another one, given as its ID or URL, and have poll only with API v2.
+    pub async fn post(&self, message: &str, media_attachments: &[u64], quote: Option<&str>, poll: Option<&Poll>, flags: &PostFlags) -> Result<crate::data::PublishedPost, TwitterError> {
+        if text_len(message, URL_LEN) > MAX_LEN {
+            return Err(TwitterError::MessageTooLong);
+        }
+
+        if let Some(poll) = poll {
+            self.check_poll(poll)?;
+        }
+
+        let quote = match quote {
+            Some(quote) => Some(self.quote_id(quote)?),
+            None => None,
+        };
+
+        match (self.api_version, &self.auth) {
+            (1, &Auth::Oauth1(ref oauth)) => matsu!(Self::post_v1(oauth, message, media_attachments, flags)),
+            (_, auth) => matsu!(Self::post_v2(auth, message, media_attachments, quote, poll, flags)),
+        }
+    }
+
+    async fn post_v1(oauth: &data::Oauth, message: &str, media_attachments: &[u64], flags: &PostFlags) -> Result<crate::data::PublishedPost, TwitterError> {
         let tweet = data::Tweet::new(message).nsfw(flags.nsfw).media_ids(media_attachments);
 
         let auth_header = {
@@ -82,7 +207,7 @@ impl Twitter {
             if let Some(ids) = tweet.media_ids.as_ref() {
                 auth_params.insert("media_ids", ids);
             }
-            self.oauth.gen_auth(&http::Method::POST, POST_URL, auth_params)
+            oauth.gen_auth(&http::Method::POST, POST_URL, auth_params)
         };
 
         let req = Request::post(POST_URL).expect("To create request")
@@ -106,8 +231,204 @@ impl Twitter {
         }
 
         match matsu!(resp.json::<data::TweetResponse>()) {
-            Ok(data) => Ok(data.id.into()),
+            Ok(data) => Ok(crate::data::PublishedPost {
+                url: format!("https://twitter.com/{}/status/{}", data.user.screen_name, data.id),
+                id: data.id.into(),
+                created_at: data.created_at,
+            }),
+            Err(_) => Err(TwitterError::PostUploadInvalidResponse),
+        }
+    }
+
+    async fn post_v2(auth: &Auth, message: &str, media_attachments: &[u64], quote: Option<&str>, poll: Option<&Poll>, flags: &PostFlags) -> Result<crate::data::PublishedPost, TwitterError> {
+        let tweet = data::TweetV2::new(message).media_ids(media_attachments).quote(quote).poll(poll).visibility(flags.visibility);
+
+        //JSON body is not part of signature
+        let auth_header = match auth {
+            Auth::Oauth1(oauth) => oauth.gen_auth(&http::Method::POST, POST_V2_URL, HashMap::new()),
+            Auth::Oauth2(token) => format!("Bearer {}", token),
+        };
+
+        let req = Request::post(POST_V2_URL).expect("To create request")
+                                            .set_header(http::header::AUTHORIZATION, auth_header)
+                                            .json(&tweet)
+                                            .expect("To create tweet data")
+                                            .global()
+                                            .send();
+
+        let mut resp = match matsu!(req) {
+            Ok(resp) => resp,
+            Err(err) => match matsu!(matsu!(err)) {
+                Ok(resp) => resp,
+                Err(_) => return Err(TwitterError::PostUploadSendError)
+            }
+        }.map_err(|_| TwitterError::PostUploadSendError)?;
+
+        if !resp.is_success() {
+            return Err(TwitterError::PostUploadServerReject)
+        }
+
+        //Response contains neither author nor time of creation
+        match matsu!(resp.json::<data::TweetResponseV2>()) {
+            Ok(data) => Ok(crate::data::PublishedPost {
+                url: format!("https://twitter.com/i/web/status/{}", data.data.id),
+                id: data.data.id.into(),
+                created_at: None,
+            }),
             Err(_) => Err(TwitterError::PostUploadInvalidResponse),
         }
     }
+
+    ///Performs GET request to read data from `url` with query `params`.
+    async fn read<T: serde::de::DeserializeOwned>(&self, url: &str, params: &[(&str, &str)]) -> Result<T, TwitterError> {
+        //Query is part of signature
+        let auth_header = match self.auth {
+            Auth::Oauth1(ref oauth) => oauth.gen_auth(&http::Method::GET, url, params.iter().cloned().collect()),
+            Auth::Oauth2(ref token) => format!("Bearer {}", token),
+        };
+
+        let req = Request::get(url).map_err(|_| TwitterError::ReadSendError)?;
+        let req = match params.len() {
+            0 => req,
+            _ => req.query(&params),
+        };
+        let req = req.set_header(http::header::AUTHORIZATION, auth_header)
+                     .empty()
+                     .global()
+                     .send();
+
+        let mut resp = match matsu!(req) {
+            Ok(Ok(resp)) => resp,
+            _ => return Err(TwitterError::ReadSendError),
+        };
+
+        if !resp.is_success() {
+            return Err(TwitterError::ReadServerReject)
+        }
+
+        matsu!(resp.json::<T>()).map_err(|_| TwitterError::ReadInvalidResponse)
+    }
+
+    ///Reads timeline of API v1.1 at `path`
+    async fn read_v1(&self, path: &str, limit: usize) -> Result<Vec<crate::data::Status>, TwitterError> {
+        let url = format!("{}/{}", V1_URL, path);
+        let count = limit.to_string();
+        let tweets = matsu!(self.read::<Vec<data::TweetResponse>>(&url, &[("count", &count), ("tweet_mode", "extended")]))?;
+
+        Ok(tweets.into_iter().map(|tweet| tweet.into_status()).collect())
+    }
+
+    ///Reads authorized user in API v2
+    async fn me(&self) -> Result<data::UserV2, TwitterError> {
+        matsu!(self.read::<data::UserResponseV2>(&format!("{}/users/me", V2_URL), &[])).map(|user| user.data)
+    }
+
+    ///Reads user's timeline of API v2 at `path`
+    async fn read_v2(&self, path: &str, limit: usize) -> Result<Vec<crate::data::Status>, TwitterError> {
+        let user = matsu!(self.me())?;
+
+        let url = format!("{}/users/{}/{}", V2_URL, user.id, path);
+        //API returns between 5 and 100 tweets
+        let max_results = limit.max(5).min(100).to_string();
+        let timeline = matsu!(self.read::<data::TimelineResponseV2>(&url, &[("max_results", &max_results), ("tweet.fields", "created_at,author_id"), ("expansions", "author_id"), ("user.fields", "username")]))?;
+
+        let mut statuses = timeline.into_statuses();
+        statuses.truncate(limit);
+        Ok(statuses)
+    }
+
+    ///Reads home timeline, newest first.
+    pub async fn home_timeline(&self, limit: usize) -> Result<Vec<crate::data::Status>, TwitterError> {
+        match self.api_version {
+            1 => matsu!(self.read_v1("statuses/home_timeline.json", limit)),
+            _ => matsu!(self.read_v2("timelines/reverse_chronological", limit)),
+        }
+    }
+
+    ///Reads own tweets, newest first.
+    pub async fn own_posts(&self, limit: usize) -> Result<Vec<crate::data::Status>, TwitterError> {
+        match self.api_version {
+            1 => matsu!(self.read_v1("statuses/user_timeline.json", limit)),
+            _ => matsu!(self.read_v2("tweets", limit)),
+        }
+    }
+
+    ///Reads tweets that mention user, newest first.
+    pub async fn mentions(&self, limit: usize) -> Result<Vec<crate::data::Status>, TwitterError> {
+        match self.api_version {
+            1 => matsu!(self.read_v1("statuses/mentions_timeline.json", limit)),
+            _ => matsu!(self.read_v2("mentions", limit)),
+        }
+    }
+
+    ///Reads engagement statistics of tweet with `id`.
+    pub async fn stats(&self, id: &str) -> Result<crate::data::PostStats, TwitterError> {
+        let stats = match self.api_version {
+            1 => matsu!(self.read::<data::TweetResponse>(&format!("{}/statuses/show.json", V1_URL), &[("id", id)])).map(|tweet| tweet.stats()),
+            _ => matsu!(self.read::<data::MetricsResponseV2>(&format!("{}/tweets/{}", V2_URL, id), &[("tweet.fields", "public_metrics")])).map(|tweet| tweet.stats()),
+        };
+
+        stats.map_err(|error| match error {
+            TwitterError::ReadSendError => TwitterError::StatsSendError,
+            TwitterError::ReadServerReject => TwitterError::StatsServerReject,
+            _ => TwitterError::StatsInvalidResponse,
+        })
+    }
+
+    ///Performs POST request to interact with tweet, sending form `params` in API v1.1 or JSON `target` in API v2.
+    async fn interact(&self, url: &str, params: &[(&str, &str)], target: Option<&data::TargetTweet<'_>>) -> Result<(), TwitterError> {
+        //JSON body is not part of signature
+        let auth_header = match self.auth {
+            Auth::Oauth1(ref oauth) => oauth.gen_auth(&http::Method::POST, url, params.iter().cloned().collect()),
+            Auth::Oauth2(ref token) => format!("Bearer {}", token),
+        };
+
+        let req = Request::post(url).map_err(|_| TwitterError::InteractionSendError)?
+                                    .set_header(http::header::AUTHORIZATION, auth_header);
+        let req = match target {
+            Some(target) => req.json(target).expect("To serialize json"),
+            None => req.form(&params).expect("To create form"),
+        };
+
+        let resp = match matsu!(req.global().send()) {
+            Ok(Ok(resp)) => resp,
+            _ => return Err(TwitterError::InteractionSendError),
+        };
+
+        match resp.is_success() {
+            true => Ok(()),
+            false => Err(TwitterError::InteractionServerReject),
+        }
+    }
+
+    ///Reads ID of authorized user, which is needed to interact in API v2
+    async fn user_id(&self) -> Result<String, TwitterError> {
+        match matsu!(self.me()) {
+            Ok(user) => Ok(user.id),
+            Err(TwitterError::ReadServerReject) => Err(TwitterError::InteractionServerReject),
+            Err(_) => Err(TwitterError::InteractionSendError),
+        }
+    }
+
+    ///Retweets tweet with `id`.
+    pub async fn boost(&self, id: &str) -> Result<(), TwitterError> {
+        match self.api_version {
+            1 => matsu!(self.interact(&format!("{}/statuses/retweet/{}.json", V1_URL, id), &[], None)),
+            _ => {
+                let user_id = matsu!(self.user_id())?;
+                matsu!(self.interact(&format!("{}/users/{}/retweets", V2_URL, user_id), &[], Some(&data::TargetTweet::new(id))))
+            },
+        }
+    }
+
+    ///Likes tweet with `id`.
+    pub async fn like(&self, id: &str) -> Result<(), TwitterError> {
+        match self.api_version {
+            1 => matsu!(self.interact(&format!("{}/favorites/create.json", V1_URL), &[("id", id)], None)),
+            _ => {
+                let user_id = matsu!(self.user_id())?;
+                matsu!(self.interact(&format!("{}/users/{}/likes", V2_URL, user_id), &[], Some(&data::TargetTweet::new(id))))
+            },
+        }
+    }
 }
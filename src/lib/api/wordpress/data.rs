@@ -0,0 +1,73 @@
+//! WordPress API data types
+
+use serde_derive::{Serialize, Deserialize};
+
+///Uploaded media.
+#[derive(Deserialize, Debug)]
+pub struct Media {
+    ///Identifier
+    pub id: u64,
+    ///URL of original file
+    pub source_url: String,
+}
+
+///Tag's entity
+#[derive(Deserialize, Debug)]
+pub struct Tag {
+    ///Identifier
+    pub id: u64,
+    ///Name as it is displayed
+    pub name: String,
+}
+
+///Payload to create tag.
+#[derive(Serialize, Debug)]
+pub struct NewTag<'a> {
+    name: &'a str,
+}
+
+impl<'a> NewTag<'a> {
+    ///Creates new instance
+    pub fn new(name: &'a str) -> Self {
+        Self {
+            name
+        }
+    }
+}
+
+///Payload of post entity.
+#[derive(Deserialize, Debug)]
+pub struct Post {
+    ///Identifier
+    pub id: u64,
+    ///Permalink
+    pub link: String,
+    ///Time of publication in site's timezone
+    #[serde(default)]
+    pub date: Option<String>,
+}
+
+///Payload to create post.
+#[derive(Serialize, Debug)]
+pub struct NewPost<'a> {
+    title: &'a str,
+    content: &'a str,
+    status: &'a str,
+    #[serde(skip_serializing_if = "<[u64]>::is_empty")]
+    tags: &'a [u64],
+    #[serde(skip_serializing_if = "Option::is_none")]
+    featured_media: Option<u64>,
+}
+
+impl<'a> NewPost<'a> {
+    ///Creates new instance
+    pub fn new(title: &'a str, content: &'a str, status: &'a str, tags: &'a [u64], featured_media: Option<u64>) -> Self {
+        Self {
+            title,
+            content,
+            status,
+            tags,
+            featured_media,
+        }
+    }
+}
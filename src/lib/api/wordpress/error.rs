@@ -0,0 +1,73 @@
+use std::error::Error;
+use std::fmt;
+
+#[repr(u8)]
+#[derive(Debug)]
+///WordPress errors
+pub enum WordPressError {
+    ///Provided HOST URI is not valid URI.
+    InvalidHostUri,
+    ///Username or application password is empty.
+    InvalidCredentials,
+    ///Failed to send request to upload image.
+    ImageUploadSendError,
+    ///Server rejected image upload.
+    ImageUploadServerReject,
+    ///Server responded with invalid data.
+    ///
+    ///Should contain `id`
+    ImageUploadInvalidResponse,
+    ///Failed to find or create tag.
+    TagUploadFailed,
+    ///Failed to send request to perform text post.
+    PostUploadSendError,
+    ///Server rejected posting.
+    PostUploadServerReject,
+    ///Server responded with invalid data
+    ///
+    ///Should contain `link`
+    PostUploadInvalidResponse,
+    ///Platform cannot attach poll to post.
+    PollUnsupported,
+}
+
+impl WordPressError {
+    ///Returns short identifier of error's kind.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            &WordPressError::InvalidHostUri => "invalid_config",
+            &WordPressError::InvalidCredentials => "invalid_config",
+            &WordPressError::ImageUploadSendError => "image_upload_send",
+            &WordPressError::ImageUploadServerReject => "image_upload_rejected",
+            &WordPressError::ImageUploadInvalidResponse => "image_upload_invalid_response",
+            &WordPressError::TagUploadFailed => "tag_rejected",
+            &WordPressError::PostUploadSendError => "post_send",
+            &WordPressError::PostUploadServerReject => "post_rejected",
+            &WordPressError::PostUploadInvalidResponse => "post_invalid_response",
+            &WordPressError::PollUnsupported => "unsupported",
+        }
+    }
+}
+
+impl fmt::Display for WordPressError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl Error for WordPressError {
+    fn description(&self) -> &str {
+        match self {
+            &WordPressError::InvalidHostUri => "Provided Host URI is not valid URI",
+            &WordPressError::InvalidCredentials => "Username or application password is empty",
+            &WordPressError::ImageUploadSendError => "Failed to send request to upload image",
+            &WordPressError::ImageUploadServerReject => "Server rejected upload of image",
+            &WordPressError::ImageUploadInvalidResponse => "Server sent invalid response. Doesn't contain field id",
+            &WordPressError::TagUploadFailed => "Failed to find or create tag",
+            &WordPressError::PostUploadSendError => "Failed to send request to perform text post",
+            &WordPressError::PostUploadServerReject => "Server rejected posting",
+            &WordPressError::PostUploadInvalidResponse => "Server sent invalid response. Doesn't contain field link",
+            &WordPressError::PollUnsupported => "Polls are not supported",
+        }
+    }
+}
@@ -0,0 +1,288 @@
+//!WordPress REST API
+
+use super::http::{self, header, Uri, GlobalRequest, Mime, Request, matsu};
+use yukikaze::client::Response;
+
+pub mod data;
+mod error;
+
+pub use error::WordPressError;
+
+///Status of published posts, unless configured otherwise.
+pub const DEFAULT_STATUS: &str = "publish";
+
+///Splits first line of text to be used as title.
+fn split_title(text: &str) -> (&str, &str) {
+    let text = text.trim();
+    match text.find('\n') {
+        Some(idx) => (text[..idx].trim(), text[idx + 1..].trim()),
+        None => (text, ""),
+    }
+}
+
+///WordPress API
+pub struct WordPress {
+    config: crate::config::WordPress,
+    base_url: String,
+    plain_http: bool,
+}
+
+impl WordPress {
+    ///Verifies configuration and creates new instances.
+    pub fn new(config: crate::config::WordPress) -> Result<Self, WordPressError> {
+        //Host can contain path and scheme, which is HTTPS unless specified
+        let base_url = match config.host.contains("://") {
+            true => config.host.trim_end_matches('/').to_owned(),
+            false => format!("https://{}", config.host.trim_end_matches('/')),
+        };
+
+        match base_url.parse::<Uri>() {
+            Ok(_) => (),
+            Err(_) => {
+                return Err(WordPressError::InvalidHostUri);
+            }
+        }
+
+        if config.username.len() == 0 || config.password.len() == 0 {
+            return Err(WordPressError::InvalidCredentials);
+        }
+
+        Ok(Self {
+            plain_http: base_url.starts_with("http://"),
+            config,
+            base_url,
+        })
+    }
+
+    fn url(&self, endpoint: &str) -> String {
+        format!("{}/wp-json/wp/v2/{}", self.base_url, endpoint)
+    }
+
+    ///Sends request, waiting twice of timeout at most.
+    ///
+    ///Only host configured with plain HTTP is sent through client that allows it.
+    async fn send(&self, req: Request) -> Option<Response> {
+        let resp = match self.plain_http {
+            true => {
+                let req = http::plain::GlobalRequest::global(req).send();
+                match matsu!(req) {
+                    Ok(resp) => resp,
+                    Err(err) => matsu!(matsu!(err)).ok()?,
+                }
+            },
+            false => {
+                let req = req.global().send();
+                match matsu!(req) {
+                    Ok(resp) => resp,
+                    Err(err) => matsu!(matsu!(err)).ok()?,
+                }
+            },
+        };
+
+        resp.ok()
+    }
+
+    ///Prepares image upload request.
+    ///
+    ///Result contains uploaded `Media`
+    pub async fn upload_image(&self, name: &str, mime: &Mime, data: &[u8]) -> Result<data::Media, WordPressError> {
+        let req = Request::post(self.url("media")).expect("To create request")
+                                                  .basic_auth(&self.config.username, Some(&self.config.password))
+                                                  .set_header(header::CONTENT_TYPE, mime.as_ref())
+                                                  .set_header(header::CONTENT_DISPOSITION, format!("attachment; filename=\"{}\"", name.replace('"', "")))
+                                                  .body(Some(data.to_vec()));
+
+        let mut resp = matsu!(self.send(req)).ok_or(WordPressError::ImageUploadSendError)?;
+
+        if !resp.is_success() {
+            return Err(WordPressError::ImageUploadServerReject)
+        }
+
+        match matsu!(resp.json::<data::Media>()) {
+            Ok(media) => Ok(media),
+            Err(_) => Err(WordPressError::ImageUploadInvalidResponse),
+        }
+    }
+
+    ///Finds existing tag by its name or creates new one, returning its ID.
+    pub async fn tag_id(&self, name: &str) -> Result<u64, WordPressError> {
+        let req = Request::get(self.url("tags")).expect("To create request")
+                                                .basic_auth(&self.config.username, Some(&self.config.password))
+                                                .query(&[("search", name)])
+                                                .empty();
+
+        let mut resp = matsu!(self.send(req)).ok_or(WordPressError::TagUploadFailed)?;
+
+        if !resp.is_success() {
+            return Err(WordPressError::TagUploadFailed);
+        }
+
+        //Search also matches tags that only contain name
+        let tags = matsu!(resp.json::<Vec<data::Tag>>()).map_err(|_| WordPressError::TagUploadFailed)?;
+        if let Some(tag) = tags.iter().find(|tag| tag.name.to_lowercase() == name.to_lowercase()) {
+            return Ok(tag.id);
+        }
+
+        let req = Request::post(self.url("tags")).expect("To create request")
+                                                 .basic_auth(&self.config.username, Some(&self.config.password))
+                                                 .json(&data::NewTag::new(name))
+                                                 .expect("To serialize tag data");
+
+        let mut resp = matsu!(self.send(req)).ok_or(WordPressError::TagUploadFailed)?;
+
+        if !resp.is_success() {
+            return Err(WordPressError::TagUploadFailed);
+        }
+
+        match matsu!(resp.json::<data::Tag>()) {
+            Ok(tag) => Ok(tag.id),
+            Err(_) => Err(WordPressError::TagUploadFailed),
+        }
+    }
+
+    ///Prepares post upload request.
+    ///
+    ///Without `title`, first line of message is used as one.
+    ///First image becomes featured one, while the rest are added at the end of content.
+    pub async fn post(&self, title: Option<&str>, message: &str, media: &[data::Media], tags: &[u64]) -> Result<crate::data::PublishedPost, WordPressError> {
+        let (title, message) = match title {
+            Some(title) => (title, message),
+            None => split_title(message),
+        };
+
+        let mut content = message.to_owned();
+        for media in media.iter().skip(1) {
+            content.push_str(&format!("\n\n<img src=\"{}\" />", media.source_url));
+        }
+
+        let status = self.config.status.as_ref().map(|status| status.as_str()).unwrap_or(DEFAULT_STATUS);
+        let featured_media = media.first().map(|media| media.id);
+
+        let req = Request::post(self.url("posts")).expect("To create request")
+                                                  .basic_auth(&self.config.username, Some(&self.config.password))
+                                                  .json(&data::NewPost::new(title, &content, status, tags, featured_media))
+                                                  .expect("To serialzie post data");
+
+        let mut resp = matsu!(self.send(req)).ok_or(WordPressError::PostUploadSendError)?;
+
+        if !resp.is_success() {
+            return Err(WordPressError::PostUploadServerReject)
+        }
+
+        //Link is used as ID, as it is how posts are referred to outside of admin panel
+        match matsu!(resp.json::<data::Post>()) {
+            Ok(post) => Ok(crate::data::PublishedPost {
+                id: post.link.clone().into(),
+                url: post.link,
+                created_at: post.date,
+            }),
+            Err(_) => Err(WordPressError::PostUploadInvalidResponse),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+
+    ///Serves `responses` in order over plain HTTP, returning each received request line and body.
+    fn serve(listener: TcpListener, responses: &'static [&'static str]) -> std::thread::JoinHandle<Vec<(String, String)>> {
+        std::thread::spawn(move || {
+            let mut requests = Vec::new();
+
+            for response in responses {
+                let (stream, _) = listener.accept().expect("To accept connection");
+                let mut reader = BufReader::new(stream);
+
+                let mut request = String::new();
+                reader.read_line(&mut request).expect("To read request line");
+
+                let mut len = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).expect("To read header");
+                    let line = line.trim_end();
+                    if line.len() == 0 {
+                        break;
+                    } else if let Some(value) = line.to_lowercase().strip_prefix("content-length:") {
+                        len = value.trim().parse().expect("To parse length");
+                    }
+                }
+
+                let mut body = vec![0; len];
+                reader.read_exact(&mut body).expect("To read body");
+                requests.push((request.trim_end().to_owned(), String::from_utf8_lossy(&body).into_owned()));
+
+                let response = format!("HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}", response.len(), response);
+                reader.get_mut().write_all(response.as_bytes()).expect("To write response");
+            }
+
+            requests
+        })
+    }
+
+    #[test]
+    fn should_use_https_by_default() {
+        let config = crate::config::WordPress {
+            host: "example.com/".to_owned(),
+            username: "alice".to_owned(),
+            password: "secret".to_owned(),
+            status: None,
+        };
+
+        let wordpress = WordPress::new(config.clone()).expect("To create WordPress");
+        assert_eq!(wordpress.url("posts"), "https://example.com/wp-json/wp/v2/posts");
+        assert!(!wordpress.plain_http);
+
+        let wordpress = WordPress::new(crate::config::WordPress { host: "http://localhost:8080".to_owned(), ..config }).expect("To create WordPress");
+        assert_eq!(wordpress.url("posts"), "http://localhost:8080/wp-json/wp/v2/posts");
+        assert!(wordpress.plain_http);
+    }
+
+    #[tokio::test]
+    async fn should_publish_post_over_http() {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("To bind listener");
+        let host = format!("http://{}/blog/", listener.local_addr().expect("To get address"));
+        let server = serve(listener, &[
+            r#"{"id":7,"source_url":"http://localhost/image.png"}"#,
+            r#"[]"#,
+            r#"{"id":3,"name":"rust"}"#,
+            r#"[{"id":4,"name":"fiesta"},{"id":5,"name":"fie"}]"#,
+            r#"{"id":1,"link":"http://localhost/blog/hello","date":"2026-10-19T10:00:00"}"#,
+        ]);
+
+        let wordpress = WordPress::new(crate::config::WordPress {
+            host,
+            username: "alice".to_owned(),
+            password: "secret".to_owned(),
+            status: None,
+        }).expect("To create WordPress");
+
+        let mime = "image/png".parse::<Mime>().expect("To parse mime");
+        let media = wordpress.upload_image("image.png", &mime, b"png").await.expect("To upload image");
+        assert_eq!(media.id, 7);
+
+        let rust = wordpress.tag_id("rust").await.expect("To create tag");
+        let fie = wordpress.tag_id("Fie").await.expect("To find tag");
+        assert_eq!([rust, fie], [3, 5]);
+
+        let post = wordpress.post(None, "Hello\nWorld", &[media], &[rust, fie]).await.expect("To publish post");
+        assert_eq!(post.url, "http://localhost/blog/hello");
+
+        let requests = server.join().expect("To finish server");
+        let lines = requests.iter().map(|(line, _)| line.as_str()).collect::<Vec<_>>();
+        assert_eq!(lines, [
+            "POST /blog/wp-json/wp/v2/media HTTP/1.1",
+            "GET /blog/wp-json/wp/v2/tags?search=rust HTTP/1.1",
+            "POST /blog/wp-json/wp/v2/tags HTTP/1.1",
+            "GET /blog/wp-json/wp/v2/tags?search=Fie HTTP/1.1",
+            "POST /blog/wp-json/wp/v2/posts HTTP/1.1",
+        ]);
+        assert_eq!(requests[0].1, "png");
+        assert_eq!(requests[2].1, r#"{"name":"rust"}"#);
+        assert_eq!(requests[4].1, r#"{"title":"Hello","content":"World","status":"publish","tags":[3,5],"featured_media":7}"#);
+    }
+}
@@ -1,9 +1,13 @@
 //! Configuration module
 use serde_derive::{Serialize, Deserialize};
 
+use crate::data::TagPlacement;
+
+use std::collections::HashMap;
+
 ///Describes which social platforms are enabled
 ///
-///By default, if all platforms are not specified, then all are enabled.
+///By default, if all platforms are not specified, then Twitter, Gab, Mastodon and Minds are enabled.
 ///Otherwise, at least one is specified, each platform is assumed to be disabled
 #[derive(Serialize, Deserialize, Copy, Clone, Debug)]
 #[serde(default)]
@@ -20,10 +24,41 @@ pub struct Platforms {
     ///Whether Minds is enabled
     #[serde(default)]
     pub minds: bool,
+    ///Whether Bluesky is enabled
+    #[serde(default)]
+    pub bluesky: bool,
+    ///Whether Pleroma is enabled
+    #[serde(default)]
+    pub pleroma: bool,
+    ///Whether Misskey is enabled
+    #[serde(default)]
+    pub misskey: bool,
+    ///Whether WordPress is enabled
+    #[serde(default)]
+    pub wordpress: bool,
+    ///Whether Matrix is enabled
+    #[serde(default)]
+    pub matrix: bool,
+    ///Whether Telegram is enabled
+    #[serde(default)]
+    pub telegram: bool,
+    ///Whether Discord is enabled
+    #[serde(default)]
+    pub discord: bool,
+    ///Whether Slack is enabled
+    #[serde(default)]
+    pub slack: bool,
+    ///Whether LinkedIn is enabled
+    #[serde(default)]
+    pub linkedin: bool,
+    ///Whether Facebook is enabled
+    #[serde(default)]
+    pub facebook: bool,
 }
 
 // If the whole section on Platforms is missing then we assume
-// that all platforms are used.
+// that all original platforms are used.
+// Platforms added later require to be enabled explicitly.
 // If section is present though, missing field means that user doesn't want platform.
 impl Default for Platforms {
     fn default() -> Self {
@@ -31,7 +66,17 @@ impl Default for Platforms {
             mastodon: true,
             gab: true,
             twitter: true,
-            minds: true
+            minds: true,
+            bluesky: false,
+            pleroma: false,
+            misskey: false,
+            wordpress: false,
+            matrix: false,
+            telegram: false,
+            discord: false,
+            slack: false,
+            linkedin: false,
+            facebook: false,
         }
     }
 }
@@ -44,6 +89,32 @@ pub struct Token {
     ///Secret
     pub secret: String,
 }
+/// Twitter's OAuth 2.0 configuration, which can be used with API v2 instead of access tokens.
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct TwitterOauth2 {
+    ///App's client ID, used by `fie auth twitter --oauth2`
+    #[serde(default)]
+    pub client_id: String,
+    ///App's client secret, used by `fie auth twitter --oauth2`
+    ///
+    ///Only confidential clients have it.
+    #[serde(default)]
+    pub client_secret: String,
+    ///Redirect URL registered for app, used by `fie auth twitter --oauth2`
+    ///
+    ///By default `https://localhost/`
+    #[serde(default)]
+    pub redirect_uri: Option<String>,
+    ///User's access token.
+    ///
+    ///Available through `fie auth twitter --oauth2`
+    #[serde(default)]
+    pub access_token: String,
+    ///Token to get new access token once it expires, used by `fie auth twitter --oauth2 --refresh`
+    #[serde(default)]
+    pub refresh_token: String,
+}
+
 /// Twitter configuration
 #[derive(Serialize, Deserialize, Debug, Default, Clone)]
 pub struct Twitter {
@@ -53,6 +124,14 @@ pub struct Twitter {
     #[serde(default)]
     ///Access tokens, granted per user.
     pub access: Token,
+    #[serde(default)]
+    ///Version of API to post with, either 1 (v1.1) or 2.
+    ///
+    ///By default 1.
+    pub api_version: Option<u8>,
+    #[serde(default)]
+    ///OAuth 2.0 tokens, used by API v2 when access tokens are not specified.
+    pub oauth2: TwitterOauth2,
 }
 
 /// Gab configuration.
@@ -89,6 +168,272 @@ pub struct Minds {
     pub password: String,
 }
 
+/// Bluesky configuration.
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct Bluesky {
+    ///Hostname of account's server.
+    ///
+    ///By default `bsky.social`
+    #[serde(default)]
+    pub host: String,
+    ///Handle of account, e.g. `alice.bsky.social`
+    #[serde(default)]
+    pub handle: String,
+    ///App password
+    ///
+    ///Available through creating it in settings
+    #[serde(default)]
+    pub password: String,
+}
+
+/// Pleroma or Akkoma configuration.
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct Pleroma {
+    ///Hostname to connect
+    #[serde(default)]
+    pub host: String,
+    ///API's access token.
+    ///
+    ///Available through creating app on developer page
+    #[serde(default)]
+    pub access_token: String,
+    ///Format of status: `text/plain`, `text/markdown`, `text/html` or `text/bbcode`
+    ///
+    ///By default, instance's default is used.
+    #[serde(default)]
+    pub content_type: Option<String>,
+}
+
+/// Misskey configuration.
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct Misskey {
+    ///Hostname to connect
+    #[serde(default)]
+    pub host: String,
+    ///API's access token.
+    ///
+    ///Available through creating access token in settings
+    #[serde(default)]
+    pub access_token: String,
+}
+
+/// WordPress configuration.
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct WordPress {
+    ///Hostname of site, including path to WordPress if it is not installed at root (e.g. `example.com/blog`)
+    ///
+    ///HTTPS is used, unless scheme is specified (e.g. `http://localhost:8080`).
+    ///Plain HTTP sends credentials unencrypted, so it is only suitable for local sites.
+    #[serde(default)]
+    pub host: String,
+    ///Username for authorization
+    #[serde(default)]
+    pub username: String,
+    ///Application password
+    ///
+    ///Available through creating it in user's profile
+    #[serde(default)]
+    pub password: String,
+    ///Status of created posts, e.g. `draft` to review them before publishing.
+    ///
+    ///By default `publish`
+    #[serde(default)]
+    pub status: Option<String>,
+}
+
+/// Matrix configuration.
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct Matrix {
+    ///Hostname of homeserver to connect
+    #[serde(default)]
+    pub host: String,
+    ///API's access token.
+    ///
+    ///Available in client's settings
+    #[serde(default)]
+    pub access_token: String,
+    ///ID of room to post into, e.g. `!abcdef:matrix.org`
+    #[serde(default)]
+    pub room: String,
+}
+
+/// Telegram configuration.
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct Telegram {
+    ///Bot's token.
+    ///
+    ///Available through creating bot with @BotFather
+    #[serde(default)]
+    pub bot_token: String,
+    ///Channel's username (e.g. `@channel`) or ID of chat to post into.
+    ///
+    ///Bot must be administrator of channel.
+    #[serde(default)]
+    pub chat: String,
+}
+
+/// Discord configuration.
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct Discord {
+    ///Webhook's URL.
+    ///
+    ///Available through creating webhook in channel's settings
+    #[serde(default)]
+    pub webhook: String,
+}
+
+/// Slack configuration.
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct Slack {
+    ///Incoming webhook's URL.
+    ///
+    ///Available through adding incoming webhook to Slack app
+    #[serde(default)]
+    pub webhook: String,
+}
+
+/// LinkedIn configuration.
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct LinkedIn {
+    ///App's client ID, used by `fie auth linkedin`
+    #[serde(default)]
+    pub client_id: String,
+    ///App's client secret, used by `fie auth linkedin`
+    #[serde(default)]
+    pub client_secret: String,
+    ///Redirect URL registered for app, used by `fie auth linkedin`
+    ///
+    ///By default `https://localhost/`
+    #[serde(default)]
+    pub redirect_uri: Option<String>,
+    ///API's access token.
+    ///
+    ///Available through `fie auth linkedin`
+    #[serde(default)]
+    pub access_token: String,
+    ///URN of author, e.g. `urn:li:person:abc` or `urn:li:organization:123` for company page.
+    #[serde(default)]
+    pub author: String,
+}
+
+/// Facebook configuration.
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct Facebook {
+    ///App's ID, used by `fie auth facebook`
+    #[serde(default)]
+    pub app_id: String,
+    ///App's secret, used by `fie auth facebook`
+    #[serde(default)]
+    pub app_secret: String,
+    ///Redirect URL registered for app, used by `fie auth facebook`
+    ///
+    ///By default `https://localhost/`
+    #[serde(default)]
+    pub redirect_uri: Option<String>,
+    ///ID of page to post into
+    #[serde(default)]
+    pub page_id: String,
+    ///Page's access token.
+    ///
+    ///Available through `fie auth facebook`
+    #[serde(default)]
+    pub access_token: String,
+}
+
+///Describes where to put hashtags for each platform
+///
+///By default hashtags are appended as last line of post, except on Minds.
+#[derive(Serialize, Deserialize, Copy, Clone, Debug)]
+#[serde(default)]
+pub struct Tags {
+    ///Twitter's placement
+    pub twitter: TagPlacement,
+    ///Gab's placement
+    pub gab: TagPlacement,
+    ///Mastodon's placement
+    pub mastodon: TagPlacement,
+    ///Minds's placement
+    ///
+    ///Minds always receives hashtags in its own field, so by default they are omitted from text.
+    pub minds: TagPlacement,
+    ///Bluesky's placement
+    pub bluesky: TagPlacement,
+    ///Pleroma's placement
+    pub pleroma: TagPlacement,
+    ///Misskey's placement
+    pub misskey: TagPlacement,
+    ///WordPress's placement
+    ///
+    ///WordPress always receives hashtags as post's tags too.
+    pub wordpress: TagPlacement,
+    ///Matrix's placement
+    pub matrix: TagPlacement,
+    ///Telegram's placement
+    pub telegram: TagPlacement,
+    ///Discord's placement
+    pub discord: TagPlacement,
+    ///Slack's placement
+    pub slack: TagPlacement,
+    ///LinkedIn's placement
+    pub linkedin: TagPlacement,
+    ///Facebook's placement
+    pub facebook: TagPlacement,
+}
+
+impl Default for Tags {
+    fn default() -> Self {
+        Self {
+            twitter: TagPlacement::default(),
+            gab: TagPlacement::default(),
+            mastodon: TagPlacement::default(),
+            minds: TagPlacement::Omit,
+            bluesky: TagPlacement::default(),
+            pleroma: TagPlacement::default(),
+            misskey: TagPlacement::default(),
+            wordpress: TagPlacement::default(),
+            matrix: TagPlacement::default(),
+            telegram: TagPlacement::default(),
+            discord: TagPlacement::default(),
+            slack: TagPlacement::default(),
+            linkedin: TagPlacement::default(),
+            facebook: TagPlacement::default(),
+        }
+    }
+}
+
+///Person's accounts on each platform, used to expand mentions.
+///
+///Handles can be written with or without leading `@`.
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+#[serde(default)]
+pub struct Person {
+    ///Name to write on platforms where person has no account.
+    ///
+    ///By default, person's key is used.
+    pub name: Option<String>,
+    ///Twitter's handle
+    pub twitter: Option<String>,
+    ///Gab's handle
+    pub gab: Option<String>,
+    ///Mastodon's handle, including instance for remote accounts (e.g. `alice@mastodon.social`)
+    pub mastodon: Option<String>,
+    ///Minds's handle
+    pub minds: Option<String>,
+    ///Bluesky's handle (e.g. `alice.bsky.social`)
+    pub bluesky: Option<String>,
+    ///Pleroma's handle, including instance for remote accounts
+    pub pleroma: Option<String>,
+    ///Misskey's handle, including instance for remote accounts (e.g. `alice@misskey.io`)
+    pub misskey: Option<String>,
+    ///Matrix's user ID (e.g. `@alice:matrix.org`)
+    pub matrix: Option<String>,
+    ///Telegram's username
+    pub telegram: Option<String>,
+}
+
+///Address book, mapping person's key to accounts.
+pub type People = HashMap<String, Person>;
+
 fn default_timeout() -> u64 {
     5
 }
@@ -101,11 +446,14 @@ pub struct Settings {
     ///
     /// By default is 5.
     pub timeout: u64,
+    #[serde(default)]
+    /// Placement of hashtags within post
+    pub tags: Tags,
 }
 
 impl Default for Settings {
     fn default() -> Self {
-        Self { timeout: 5 }
+        Self { timeout: 5, tags: Tags::default() }
     }
 }
 
@@ -120,6 +468,36 @@ pub struct ApiConfig {
     pub mastodon: Mastodon,
     ///Minds information
     pub minds: Minds,
+    ///Bluesky information
+    #[serde(default)]
+    pub bluesky: Bluesky,
+    ///Pleroma information
+    #[serde(default)]
+    pub pleroma: Pleroma,
+    ///Misskey information
+    #[serde(default)]
+    pub misskey: Misskey,
+    ///WordPress information
+    #[serde(default)]
+    pub wordpress: WordPress,
+    ///Matrix information
+    #[serde(default)]
+    pub matrix: Matrix,
+    ///Telegram information
+    #[serde(default)]
+    pub telegram: Telegram,
+    ///Discord information
+    #[serde(default)]
+    pub discord: Discord,
+    ///Slack information
+    #[serde(default)]
+    pub slack: Slack,
+    ///LinkedIn information
+    #[serde(default)]
+    pub linkedin: LinkedIn,
+    ///Facebook information
+    #[serde(default)]
+    pub facebook: Facebook,
 }
 
 ///Fie's configuration
@@ -133,4 +511,7 @@ pub struct Config {
     ///Fie settings
     #[serde(default)]
     pub settings: Settings,
+    ///Address book for mentions
+    #[serde(default)]
+    pub people: People,
 }
@@ -0,0 +1,42 @@
+//!HTML utilities
+
+///Converts HTML of post into plain text.
+///
+///Line breaks and paragraphs become new lines, other tags are removed and basic entities are decoded.
+pub fn to_text(html: &str) -> String {
+    let mut result = String::with_capacity(html.len());
+    let mut rest = html;
+
+    while let Some(start) = rest.find('<') {
+        let end = match rest[start..].find('>') {
+            Some(end) => start + end,
+            None => break,
+        };
+
+        result.push_str(&rest[..start]);
+
+        let tag = &rest[start + 1..end];
+        let is_closing = tag.starts_with('/');
+        let name = tag.trim_start_matches('/').split(|ch: char| ch.is_whitespace() || ch == '/').next().unwrap_or("").to_ascii_lowercase();
+        match (is_closing, name.as_str()) {
+            (_, "br") => result.push('\n'),
+            (true, "p") => result.push_str("\n\n"),
+            _ => (),
+        }
+
+        rest = &rest[end + 1..];
+    }
+
+    result.push_str(rest);
+
+    //Ampersand goes last, so that escaped entities are not decoded twice
+    let result = result.replace("&lt;", "<")
+                       .replace("&gt;", ">")
+                       .replace("&quot;", "\"")
+                       .replace("&#39;", "'")
+                       .replace("&apos;", "'")
+                       .replace("&nbsp;", " ")
+                       .replace("&amp;", "&");
+
+    result.trim().to_owned()
+}
@@ -0,0 +1,68 @@
+//!Mention utilities
+
+fn is_valid_key(key: &str) -> bool {
+    key.len() > 0 && key.chars().all(|ch| ch.is_alphanumeric() || ch == '_' || ch == '-' || ch == '.')
+}
+
+///Replaces mentions in form `{@key}` with text returned by `lookup`.
+///
+///Returns first key that `lookup` doesn't know as error.
+pub fn replace_mentions<F: FnMut(&str) -> Option<String>>(text: &str, mut lookup: F) -> Result<String, String> {
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find("{@") {
+        let after = &rest[start + 2..];
+
+        match after.find('}') {
+            Some(end) if is_valid_key(&after[..end]) => {
+                let key = &after[..end];
+                result.push_str(&rest[..start]);
+
+                match lookup(key) {
+                    Some(mention) => result.push_str(&mention),
+                    None => return Err(key.to_owned()),
+                }
+
+                rest = &after[end + 1..];
+            },
+            _ => {
+                result.push_str(&rest[..start + 2]);
+                rest = after;
+            }
+        }
+    }
+
+    result.push_str(rest);
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lookup(key: &str) -> Option<String> {
+        match key {
+            "fie" => Some("@fie@example.com".to_owned()),
+            "doc.u-ment_1" => Some("@doc".to_owned()),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn should_replace_mentions() {
+        assert_eq!(replace_mentions("Hi {@fie} and {@doc.u-ment_1}!", lookup).unwrap(), "Hi @fie@example.com and @doc!");
+        assert_eq!(replace_mentions("No mentions", lookup).unwrap(), "No mentions");
+    }
+
+    #[test]
+    fn should_keep_invalid_mentions() {
+        assert_eq!(replace_mentions("{@} {@a b} {@fie", lookup).unwrap(), "{@} {@a b} {@fie");
+        assert_eq!(replace_mentions("{@{@fie}}", lookup).unwrap(), "{@@fie@example.com}");
+    }
+
+    #[test]
+    fn should_return_unknown_key() {
+        assert_eq!(replace_mentions("Hi {@fie} and {@unknown}", lookup), Err("unknown".to_owned()));
+    }
+}
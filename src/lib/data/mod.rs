@@ -1,12 +1,72 @@
 //!Data module
 
 pub mod image;
+pub mod tags;
+pub mod url;
+pub mod mentions;
+pub mod time;
+pub mod html;
 
 pub use image::Image;
+pub use tags::{join_hash_tags, normalize_hash_tags, place_hash_tags, TagPlacement};
+pub use mentions::replace_mentions;
 
 use serde_derive::{Deserialize};
 
 use std::fmt;
+use std::str::FromStr;
+
+///Visibility of post
+#[derive(Deserialize, Copy, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Visibility {
+    ///Visible to everyone and shown on public timelines.
+    Public,
+    ///Visible to everyone, but not shown on public timelines.
+    Unlisted,
+    ///Visible only to followers.
+    Private,
+    ///Visible only to mentioned users.
+    Direct,
+}
+
+impl Visibility {
+    ///Returns textual representation, as used by Mastodon API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Visibility::Public => "public",
+            Visibility::Unlisted => "unlisted",
+            Visibility::Private => "private",
+            Visibility::Direct => "direct",
+        }
+    }
+}
+
+impl Default for Visibility {
+    fn default() -> Self {
+        Visibility::Public
+    }
+}
+
+impl fmt::Display for Visibility {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Visibility {
+    type Err = String;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        match text {
+            "public" => Ok(Visibility::Public),
+            "unlisted" => Ok(Visibility::Unlisted),
+            "private" => Ok(Visibility::Private),
+            "direct" => Ok(Visibility::Direct),
+            other => Err(format!("Invalid visibility '{}'. Allowed values: public, unlisted, private, direct", other)),
+        }
+    }
+}
 
 ///Flags for text posts
 #[derive(Deserialize, Default, Debug, Clone)]
@@ -14,13 +74,71 @@ pub struct PostFlags {
     /// Whether post is safe for work or not.
     #[serde(default)]
     pub nsfw: bool,
+    /// Content warning(spoiler text) to show in place of post's content.
+    ///
+    /// Only used by platforms that support it natively (Mastodon, Gab, Pleroma and Misskey).
+    #[serde(default)]
+    pub cw: Option<String>,
+    /// Who can see post.
+    ///
+    /// Twitter has no such concept and always posts publicly.
+    /// With API v2 private and direct posts only limit replies to followed and mentioned accounts respectively.
+    #[serde(default)]
+    pub visibility: Visibility,
+    /// Whether to skip fetching of link preview.
+    ///
+    /// Preview is used by Minds and Bluesky to create rich post out of the first link in message,
+    /// while Telegram shows it below message.
+    #[serde(default)]
+    pub no_preview: bool,
+}
+
+fn default_poll_duration() -> u64 {
+    //One day
+    86400
+}
+
+///Poll to attach to post
+#[derive(Deserialize, Debug, Clone)]
+pub struct Poll {
+    ///Choices to vote for
+    pub options: Vec<String>,
+    ///Number of seconds during which poll accepts votes.
+    ///
+    ///By default is one day.
+    #[serde(default = "default_poll_duration")]
+    pub duration: u64,
+    ///Whether multiple choices can be selected
+    #[serde(default)]
+    pub multiple: bool,
+}
+
+///Platform specific replacement of post's content.
+///
+///Each specified field is used instead of post's own.
+#[derive(Deserialize, Default, Debug, Clone)]
+pub struct PostOverride {
+    ///Post's content
+    #[serde(default)]
+    pub message: Option<String>,
+    ///Hashtags to add
+    #[serde(default)]
+    pub tags: Option<Vec<String>>,
+    ///Attachments
+    #[serde(default)]
+    pub images: Option<Vec<String>>,
 }
 
 ///Describes text post
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Default, Debug)]
 pub struct Post {
     ///Post's content
     pub message: String,
+    #[serde(default)]
+    ///Title, used by platforms that support it (WordPress).
+    ///
+    ///By default, first line of message is used as title.
+    pub title: Option<String>,
     ///Hashtags to add
     pub tags: Vec<String>,
     ///Attachments
@@ -28,6 +146,176 @@ pub struct Post {
     #[serde(default)]
     ///Flags
     pub flags: PostFlags,
+    #[serde(default)]
+    ///Poll, if any.
+    ///
+    ///Only Mastodon, Gab, Pleroma, Misskey and Twitter with API v2 support polls.
+    pub poll: Option<Poll>,
+    #[serde(default)]
+    ///Tweet to quote, given as its ID or URL.
+    ///
+    ///Only Twitter with API v2 supports quotes.
+    pub quote: Option<String>,
+    #[serde(default)]
+    ///Twitter's overrides
+    pub twitter: PostOverride,
+    #[serde(default)]
+    ///Gab's overrides
+    pub gab: PostOverride,
+    #[serde(default)]
+    ///Mastodon's overrides
+    pub mastodon: PostOverride,
+    #[serde(default)]
+    ///Minds's overrides
+    pub minds: PostOverride,
+    #[serde(default)]
+    ///Bluesky's overrides
+    pub bluesky: PostOverride,
+    #[serde(default)]
+    ///Pleroma's overrides
+    pub pleroma: PostOverride,
+    #[serde(default)]
+    ///Misskey's overrides
+    pub misskey: PostOverride,
+    #[serde(default)]
+    ///WordPress's overrides
+    pub wordpress: PostOverride,
+    #[serde(default)]
+    ///Matrix's overrides
+    pub matrix: PostOverride,
+    #[serde(default)]
+    ///Telegram's overrides
+    pub telegram: PostOverride,
+    #[serde(default)]
+    ///Discord's overrides
+    pub discord: PostOverride,
+    #[serde(default)]
+    ///Slack's overrides
+    ///
+    ///Slack can only show images given as URLs.
+    pub slack: PostOverride,
+    #[serde(default)]
+    ///LinkedIn's overrides
+    pub linkedin: PostOverride,
+    #[serde(default)]
+    ///Facebook's overrides
+    pub facebook: PostOverride,
+}
+
+impl Post {
+    ///Returns text of post, using overrides if any.
+    pub fn message_for<'a>(&'a self, overrides: &'a PostOverride) -> &'a str {
+        overrides.message.as_ref().unwrap_or(&self.message)
+    }
+
+    ///Returns hashtags, using overrides if any.
+    pub fn tags_for<'a>(&'a self, overrides: &'a PostOverride) -> &'a [String] {
+        overrides.tags.as_ref().unwrap_or(&self.tags)
+    }
+
+    ///Returns attachments, using overrides if any.
+    pub fn images_for<'a>(&'a self, overrides: &'a PostOverride) -> &'a [String] {
+        overrides.images.as_ref().unwrap_or(&self.images)
+    }
+
+    ///Returns overrides of every platform.
+    pub fn all_overrides_mut(&mut self) -> [&mut PostOverride; 14] {
+        [
+            &mut self.twitter,
+            &mut self.gab,
+            &mut self.mastodon,
+            &mut self.minds,
+            &mut self.bluesky,
+            &mut self.pleroma,
+            &mut self.misskey,
+            &mut self.wordpress,
+            &mut self.matrix,
+            &mut self.telegram,
+            &mut self.discord,
+            &mut self.slack,
+            &mut self.linkedin,
+            &mut self.facebook,
+        ]
+    }
+
+    ///Returns overrides of platform, given by its name in configuration.
+    pub fn overrides_mut(&mut self, platform: &str) -> Option<&mut PostOverride> {
+        match platform {
+            "twitter" => Some(&mut self.twitter),
+            "gab" => Some(&mut self.gab),
+            "mastodon" => Some(&mut self.mastodon),
+            "minds" => Some(&mut self.minds),
+            "bluesky" => Some(&mut self.bluesky),
+            "pleroma" => Some(&mut self.pleroma),
+            "misskey" => Some(&mut self.misskey),
+            "wordpress" => Some(&mut self.wordpress),
+            "matrix" => Some(&mut self.matrix),
+            "telegram" => Some(&mut self.telegram),
+            "discord" => Some(&mut self.discord),
+            "slack" => Some(&mut self.slack),
+            "linkedin" => Some(&mut self.linkedin),
+            "facebook" => Some(&mut self.facebook),
+            _ => None,
+        }
+    }
+}
+
+///Information about published post.
+#[derive(Clone, Debug)]
+pub struct PublishedPost {
+    ///Post's ID
+    pub id: PostId,
+    ///Canonical URL of post
+    pub url: String,
+    ///Time of creation, as reported by platform.
+    ///
+    ///Format is platform specific.
+    pub created_at: Option<String>,
+}
+
+///Post, read from platform's timeline.
+#[derive(Clone, Debug)]
+pub struct Status {
+    ///Post's ID
+    pub id: PostId,
+    ///Canonical URL of post
+    pub url: String,
+    ///Author's handle
+    pub author: String,
+    ///Text of post without markup
+    pub text: String,
+    ///Time of creation as UNIX timestamp, if platform reports it.
+    pub timestamp: Option<u64>,
+}
+
+///IDs of the same post on each platform, as returned in `PublishedPost`.
+#[derive(Clone, Debug, Default)]
+pub struct PostIds {
+    ///Twitter's ID
+    pub twitter: Option<String>,
+    ///Gab's ID
+    pub gab: Option<String>,
+    ///Mastodon's ID
+    pub mastodon: Option<String>,
+    ///Minds's ID
+    pub minds: Option<String>,
+    ///Bluesky's AT URI
+    pub bluesky: Option<String>,
+    ///Pleroma's ID
+    pub pleroma: Option<String>,
+    ///Misskey's ID
+    pub misskey: Option<String>,
+}
+
+///Engagement statistics of post.
+#[derive(Clone, Debug, Default)]
+pub struct PostStats {
+    ///Number of likes, favourites or reactions.
+    pub likes: u64,
+    ///Number of reposts, including quotes when platform counts them together.
+    pub reposts: u64,
+    ///Number of replies, if platform reports it.
+    pub replies: Option<u64>,
 }
 
 ///Generic Post ID.
@@ -71,16 +359,3 @@ impl Into<PostId> for &'static str {
         PostId::Str(self)
     }
 }
-///Creates string of multiple hashtags
-pub fn join_hash_tags<'a, I: AsRef<str> + 'a, T: IntoIterator<Item = I>>(tags: T) -> String {
-    let mut result = String::new();
-
-    for tag in tags {
-        result.push_str(&format!("#{} ", tag.as_ref()));
-    }
-
-    // remove last white space
-    let _ = result.pop();
-
-    result
-}
@@ -0,0 +1,127 @@
+//!Hashtag utilities
+
+use serde_derive::{Serialize, Deserialize};
+
+///Describes where to put hashtags within text of post
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum TagPlacement {
+    ///Hashtags are added as new last line.
+    Append,
+    ///Hashtags are added at the end of last line.
+    Inline,
+    ///Hashtags are not added to text.
+    ///
+    ///Useful for platforms with own field for tags, like Minds.
+    Omit,
+}
+
+impl Default for TagPlacement {
+    fn default() -> Self {
+        TagPlacement::Append
+    }
+}
+
+///Checks whether hashtag consists of letters, digits and `_` only, with at least one non-digit.
+///
+///Each platform decides which hashtags it accepts, and many of them follow this rule.
+pub fn is_word_hash_tag(tag: &str) -> bool {
+    tag.chars().all(|ch| ch.is_alphanumeric() || ch == '_') && !tag.chars().all(|ch| ch.is_numeric())
+}
+
+///Normalizes list of hashtags.
+///
+///- Leading `#` is removed;
+///- Tag with multiple words is turned into single CamelCased word;
+///- Empty and duplicate tags, ignoring case, are removed.
+///
+///Whether resulting hashtag is accepted is up to platform.
+pub fn normalize_hash_tags<I: AsRef<str>, T: IntoIterator<Item = I>>(tags: T) -> Vec<String> {
+    let mut result: Vec<String> = Vec::new();
+
+    for tag in tags {
+        let tag = tag.as_ref().trim().trim_start_matches('#');
+
+        let tag = match tag.contains(char::is_whitespace) {
+            true => tag.split_whitespace().map(|word| {
+                let mut chars = word.chars();
+                match chars.next() {
+                    Some(first) => first.to_uppercase().chain(chars).collect::<String>(),
+                    None => String::new(),
+                }
+            }).collect::<String>(),
+            false => tag.to_owned(),
+        };
+
+        if tag.len() == 0 {
+            continue;
+        }
+
+        let lowercase = tag.to_lowercase();
+        if result.iter().any(|old| old.to_lowercase() == lowercase) {
+            continue;
+        }
+
+        result.push(tag);
+    }
+
+    result
+}
+
+///Creates string of multiple hashtags
+pub fn join_hash_tags<'a, I: AsRef<str> + 'a, T: IntoIterator<Item = I>>(tags: T) -> String {
+    let mut result = String::new();
+
+    for tag in tags {
+        result.push_str(&format!("#{} ", tag.as_ref().trim_start_matches('#')));
+    }
+
+    // remove last white space
+    let _ = result.pop();
+
+    result
+}
+
+///Adds hashtags to message according to placement.
+pub fn place_hash_tags<I: AsRef<str>>(message: &str, tags: &[I], placement: TagPlacement) -> String {
+    if tags.len() == 0 {
+        return message.to_owned();
+    }
+
+    match (message, placement) {
+        (message, TagPlacement::Omit) => message.to_owned(),
+        ("", _) => join_hash_tags(tags.iter().map(|tag| tag.as_ref())),
+        (message, TagPlacement::Append) => format!("{}\n{}", message, join_hash_tags(tags.iter().map(|tag| tag.as_ref()))),
+        (message, TagPlacement::Inline) => format!("{} {}", message.trim_end(), join_hash_tags(tags.iter().map(|tag| tag.as_ref()))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_normalize_hash_tags() {
+        let tags = normalize_hash_tags(&["#rust", "open source", "", "  ", "Rust", "c++"]);
+        assert_eq!(tags, ["rust", "OpenSource", "c++"]);
+    }
+
+    #[test]
+    fn should_check_word_hash_tag() {
+        assert!(is_word_hash_tag("rust_2018"));
+        assert!(is_word_hash_tag("日本"));
+        assert!(!is_word_hash_tag("2018"));
+        assert!(!is_word_hash_tag("c++"));
+    }
+
+    #[test]
+    fn should_place_hash_tags() {
+        let tags = ["rust", "fie"];
+
+        assert_eq!(place_hash_tags("Hello\nworld ", &tags, TagPlacement::Append), "Hello\nworld \n#rust #fie");
+        assert_eq!(place_hash_tags("Hello\nworld ", &tags, TagPlacement::Inline), "Hello\nworld #rust #fie");
+        assert_eq!(place_hash_tags("Hello", &tags, TagPlacement::Omit), "Hello");
+        assert_eq!(place_hash_tags("", &tags, TagPlacement::Append), "#rust #fie");
+        assert_eq!(place_hash_tags::<&str>("Hello", &[], TagPlacement::Append), "Hello");
+    }
+}
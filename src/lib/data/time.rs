@@ -0,0 +1,228 @@
+//!Time utilities
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+///Converts number of days since UNIX epoch into year, month and day.
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    //http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    let z = days + 719468;
+    let era = z / 146097;
+    let doe = z - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    (year, month, day)
+}
+
+///Converts year, month and day into number of days since UNIX epoch.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    //http://howardhinnant.github.io/date_algorithms.html#days_from_civil
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = if year >= 0 { year } else { year - 399 } / 400;
+    let yoe = year - era * 400;
+    let doy = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+
+    era * 146097 + doe - 719468
+}
+
+///Parses `HH:MM:SS` into number of seconds since midnight.
+fn parse_time(text: &str) -> Option<i64> {
+    let mut parts = text.splitn(3, ':').map(|part| part.parse::<i64>());
+    match (parts.next(), parts.next(), parts.next()) {
+        (Some(Ok(hours)), Some(Ok(minutes)), Some(Ok(seconds))) => Some(hours * 3600 + minutes * 60 + seconds),
+        _ => None,
+    }
+}
+
+///Parses offset from UTC, either `Z`, `+HH:MM` or `+HHMM`, into number of seconds.
+fn parse_offset(text: &str) -> Option<i64> {
+    let sign = match text.chars().next()? {
+        'Z' | 'z' if text.len() == 1 => return Some(0),
+        '+' => 1,
+        '-' => -1,
+        _ => return None,
+    };
+    let digits = text[1..].replace(':', "");
+    if digits.len() != 4 || !digits.bytes().all(|byte| byte.is_ascii_digit()) {
+        return None;
+    }
+
+    let hours = digits[..2].parse::<i64>().ok()?;
+    let minutes = digits[2..].parse::<i64>().ok()?;
+    Some(sign * (hours * 3600 + minutes * 60))
+}
+
+///Parses zone of RFC 2822, which is either offset or one of obsolete names, into number of seconds.
+fn parse_zone(text: &str) -> Option<i64> {
+    match text {
+        "GMT" | "UT" | "UTC" => Some(0),
+        "EDT" => Some(-4 * 3600),
+        "EST" | "CDT" => Some(-5 * 3600),
+        "CST" | "MDT" => Some(-6 * 3600),
+        "MST" | "PDT" => Some(-7 * 3600),
+        "PST" => Some(-8 * 3600),
+        text => parse_offset(text),
+    }
+}
+
+///Parses time in RFC 3339 format (e.g. `2020-01-01T00:00:00.000Z`), in format of Twitter API v1.1 (e.g. `Wed Oct 10 20:19:24 +0000 2018`)
+///or in RFC 2822 format of RSS (e.g. `Wed, 10 Oct 2018 20:19:24 GMT`) into UNIX timestamp.
+pub fn parse(text: &str) -> Option<u64> {
+    const MONTHS: [&str; 12] = ["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec"];
+    let month = |name: &str| MONTHS.iter().position(|month| *month == name).map(|month| month as i64 + 1);
+
+    let text = text.trim();
+    let (days, time, offset) = match text.get(4..5) {
+        Some("-") => {
+            let mut date = text.get(..10)?.split('-').map(|part| part.parse::<i64>());
+            let days = match (date.next(), date.next(), date.next()) {
+                (Some(Ok(year)), Some(Ok(month)), Some(Ok(day))) => days_from_civil(year, month, day),
+                _ => return None,
+            };
+            let time = parse_time(text.get(11..19)?)?;
+            //Fraction of second is ignored
+            let offset = text.get(19..)?.trim_start_matches(|ch: char| ch == '.' || ch.is_ascii_digit());
+
+            (days, time, parse_offset(offset)?)
+        },
+        _ => {
+            let parts = text.split_whitespace().collect::<Vec<_>>();
+
+            match parts.get(1).and_then(|name| month(name)) {
+                Some(month) if parts.len() == 6 => {
+                    let day = parts[2].parse::<i64>().ok()?;
+                    let year = parts[5].parse::<i64>().ok()?;
+
+                    (days_from_civil(year, month, day), parse_time(parts[3])?, parse_offset(parts[4])?)
+                },
+                _ => {
+                    //Day of week is optional
+                    let parts = match parts.first() {
+                        Some(day) if day.ends_with(',') => &parts[1..],
+                        _ => &parts[..],
+                    };
+                    if parts.len() != 5 {
+                        return None;
+                    }
+
+                    let day = parts[0].parse::<i64>().ok()?;
+                    let month = month(parts[1])?;
+                    let year = parts[2].parse::<i64>().ok()?;
+                    //Seconds are optional
+                    let time = match parts[3].len() {
+                        5 => parse_time(&format!("{}:00", parts[3]))?,
+                        _ => parse_time(parts[3])?,
+                    };
+
+                    (days_from_civil(year, month, day), time, parse_zone(parts[4])?)
+                },
+            }
+        },
+    };
+
+    let timestamp = days * 86400 + time - offset;
+    match timestamp >= 0 {
+        true => Some(timestamp as u64),
+        false => None,
+    }
+}
+
+fn since_epoch() -> std::time::Duration {
+    match SystemTime::now().duration_since(UNIX_EPOCH) {
+        Ok(dur) => dur,
+        Err(err) => err.duration(),
+    }
+}
+
+///Returns current UTC date in format `YYYY-MM-DD`
+pub fn today() -> String {
+    let (year, month, day) = civil_from_days((since_epoch().as_secs() / 86400) as i64);
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+///Returns current UTC time in RFC 3339 format with milliseconds, e.g. `2020-01-01T00:00:00.000Z`
+pub fn now() -> String {
+    let now = since_epoch();
+    let secs = now.as_secs();
+    let (year, month, day) = civil_from_days((secs / 86400) as i64);
+    let time = secs % 86400;
+
+    format!("{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:03}Z", year, month, day, time / 3600, time % 3600 / 60, time % 60, now.subsec_millis())
+}
+
+///Returns UTC time of UNIX timestamp in RFC 3339 format, e.g. `2020-01-01T00:00:00Z`
+pub fn from_timestamp(secs: u64) -> String {
+    let (year, month, day) = civil_from_days((secs / 86400) as i64);
+    let time = secs % 86400;
+
+    format!("{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z", year, month, day, time / 3600, time % 3600 / 60, time % 60)
+}
+
+///Returns number of milliseconds since UNIX epoch.
+pub fn timestamp_millis() -> u64 {
+    let now = since_epoch();
+    now.as_secs() * 1000 + now.subsec_millis() as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_parse_offset() {
+        assert_eq!(parse_offset("Z"), Some(0));
+        assert_eq!(parse_offset("+03:00"), Some(3 * 3600));
+        assert_eq!(parse_offset("-0130"), Some(-5400));
+        assert_eq!(parse_offset("+1é1"), None);
+        assert_eq!(parse_offset("+3:00"), None);
+        assert_eq!(parse_offset("Zulu"), None);
+        assert_eq!(parse_offset(""), None);
+    }
+
+    #[test]
+    fn should_parse_rfc3339() {
+        assert_eq!(parse("2020-01-01T00:00:00Z"), Some(1577836800));
+        assert_eq!(parse("2020-01-01T00:00:00.123456Z"), Some(1577836800));
+        assert_eq!(parse("2020-01-01T03:00:00.5+03:00"), Some(1577836800));
+        assert_eq!(parse("2019-12-31T19:00:00-0500"), Some(1577836800));
+        assert_eq!(parse("2020-01-01T00:00:00"), None);
+        assert_eq!(parse("2020-01-01"), None);
+    }
+
+    #[test]
+    fn should_not_parse_time_before_epoch() {
+        assert_eq!(parse("1970-01-01T00:00:00Z"), Some(0));
+        assert_eq!(parse("1969-12-31T23:59:59Z"), None);
+        assert_eq!(parse("1970-01-01T00:00:00+01:00"), None);
+    }
+
+    #[test]
+    fn should_parse_twitter_v1() {
+        assert_eq!(parse("Wed Oct 10 20:19:24 +0000 2018"), Some(1539202764));
+        assert_eq!(parse("Wed Oct 10 23:19:24 +0300 2018"), Some(1539202764));
+        assert_eq!(parse("Wed Foo 10 20:19:24 +0000 2018"), None);
+        assert_eq!(parse("Wed Oct 10 20:19:24 2018"), None);
+    }
+
+    #[test]
+    fn should_parse_rfc2822() {
+        assert_eq!(parse("Wed, 10 Oct 2018 20:19:24 GMT"), Some(1539202764));
+        assert_eq!(parse("Wed, 10 Oct 2018 23:19:24 +0300"), Some(1539202764));
+        assert_eq!(parse("10 Oct 2018 16:19:24 EDT"), Some(1539202764));
+        assert_eq!(parse("Wed, 10 Oct 2018 20:19 GMT"), Some(1539202740));
+        assert_eq!(parse("Wed, 10 Foo 2018 20:19:24 GMT"), None);
+        assert_eq!(parse("Wed, 10 Oct 2018 20:19:24 XYZ"), None);
+    }
+
+    #[test]
+    fn should_format_timestamp() {
+        assert_eq!(from_timestamp(0), "1970-01-01T00:00:00Z");
+        assert_eq!(from_timestamp(1539202764), "2018-10-10T20:19:24Z");
+        assert_eq!(civil_from_days(days_from_civil(1960, 2, 29)), (1960, 2, 29));
+    }
+}
@@ -0,0 +1,62 @@
+//!URL utilities
+
+const SCHEMES: [&str; 2] = ["https://", "http://"];
+
+///Removes trailing punctuation of URL.
+///
+///Closing parenthesis is kept if it has pair within URL, e.g. `https://en.wikipedia.org/wiki/Rust_(programming_language)`.
+fn trim_punctuation(mut url: &str) -> &str {
+    loop {
+        url = url.trim_end_matches(|ch| ch == '.' || ch == ',' || ch == ';' || ch == ':' || ch == '!' || ch == '?' || ch == '"' || ch == '\'');
+
+        match url.ends_with(')') && url.matches(')').count() > url.matches('(').count() {
+            true => url = &url[..url.len() - 1],
+            false => break url,
+        }
+    }
+}
+
+///Finds all URLs within text.
+///
+///Only URLs with `http` or `https` scheme are recognized.
+pub fn find_urls(text: &str) -> Vec<&str> {
+    text.split_whitespace()
+        .filter(|word| SCHEMES.iter().any(|scheme| word.starts_with(scheme) && word.len() > scheme.len()))
+        .map(trim_punctuation)
+        .collect()
+}
+
+///Calculates length of text in characters, counting each URL as `url_len` characters.
+///
+///Used by platforms which shorten links, like Twitter.
+pub fn text_len(text: &str, url_len: usize) -> usize {
+    let urls = find_urls(text);
+    let urls_len = urls.iter().fold(0, |acc, url| acc + url.chars().count());
+
+    text.chars().count() - urls_len + urls.len() * url_len
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_find_urls() {
+        assert_eq!(find_urls("See https://example.com/a?b=c, and http://example.org."), ["https://example.com/a?b=c", "http://example.org"]);
+        assert_eq!(find_urls("https:// ftp://example.com example.com"), Vec::<&str>::new());
+    }
+
+    #[test]
+    fn should_trim_unbalanced_parenthesis() {
+        assert_eq!(find_urls("(see https://example.com)"), ["https://example.com"]);
+        assert_eq!(find_urls("(see https://example.com/a_(b)), or not"), ["https://example.com/a_(b)"]);
+        assert_eq!(find_urls("https://en.wikipedia.org/wiki/Rust_(programming_language)."), ["https://en.wikipedia.org/wiki/Rust_(programming_language)"]);
+    }
+
+    #[test]
+    fn should_count_urls_as_fixed_length() {
+        assert_eq!(text_len("Привет", 23), 6);
+        assert_eq!(text_len("Look https://example.com/very/long/path!", 23), 5 + 23 + 1);
+        assert_eq!(text_len("https://a.b https://c.d", 10), 21);
+    }
+}